@@ -0,0 +1,226 @@
+//! The `accessibility` module detects the keyboard accessibility features enabled by
+//! the user and provides a way to synthesize inputs that still work when they are
+//! active.
+//!
+//! When *StickyKeys* or *FilterKeys* are turned on, the system does not interpret a
+//! burst of synthesized keystrokes the same way it interprets a regular burst: keys
+//! that are not held long enough are ignored and modifier keys may get latched. The
+//! [`send_inputs_accessible`] function spaces the events so that they are recognized.
+//!
+//! ## Examples
+//!
+//! ```rust, ignore
+//! use winput::{Action, Input, Vk};
+//! use winput::accessibility::{self, KeyboardAccessibility};
+//!
+//! let settings = KeyboardAccessibility::current().unwrap();
+//! println!("StickyKeys: {}", settings.sticky_keys);
+//!
+//! let inputs = [
+//!     Input::from_vk(Vk::Control, Action::Press),
+//!     Input::from_vk(Vk::C, Action::Press),
+//!     Input::from_vk(Vk::C, Action::Release),
+//!     Input::from_vk(Vk::Control, Action::Release),
+//! ];
+//!
+//! accessibility::send_inputs_accessible(&inputs).unwrap();
+//! ```
+//!
+//! [`send_inputs_accessible`]: fn.send_inputs_accessible.html
+
+use std::convert::TryFrom;
+use std::time::Duration;
+use std::{mem, thread};
+
+use winapi::shared::minwindef::{DWORD, UINT};
+use winapi::um::winuser;
+
+use crate::error::WindowsError;
+use crate::input::{try_send_inputs, Input};
+use crate::modifiers::Modifiers;
+use crate::vk::Vk;
+
+/// The `STICKYKEYS` structure, which is not exposed by `winapi`.
+#[repr(C)]
+struct StickyKeys {
+    cb_size: UINT,
+    dw_flags: DWORD,
+}
+
+/// The `FILTERKEYS` structure, which is not exposed by `winapi`.
+#[repr(C)]
+struct FilterKeys {
+    cb_size: UINT,
+    dw_flags: DWORD,
+    i_wait_msec: DWORD,
+    i_delay_msec: DWORD,
+    i_repeat_msec: DWORD,
+    i_bounce_msec: DWORD,
+}
+
+/// The StickyKeys feature is on.
+const SKF_STICKYKEYSON: DWORD = 0x0000_0001;
+/// The FilterKeys feature is on.
+const FKF_FILTERKEYSON: DWORD = 0x0000_0001;
+
+/// The delay inserted after a modifier key is pressed when StickyKeys is on.
+const STICKY_MODIFIER_DELAY: Duration = Duration::from_millis(30);
+
+/// Describes the settings of the *FilterKeys* accessibility feature.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FilterKeysSettings {
+    /// How long a key must be held down before it is accepted by the system.
+    pub acceptance_delay: Duration,
+    /// How long a key must be held down before it starts repeating.
+    pub repeat_delay: Duration,
+    /// The interval between two repetitions of a key.
+    pub repeat_rate: Duration,
+    /// How long the system waits after a key is released before accepting another
+    /// press of the same key.
+    pub bounce_time: Duration,
+}
+
+/// Describes the keyboard accessibility features enabled by the user.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct KeyboardAccessibility {
+    /// Whether *StickyKeys* is turned on. When it is, modifier keys can be latched by
+    /// pressing and releasing them.
+    pub sticky_keys: bool,
+    /// The settings of *FilterKeys*, if it is turned on.
+    pub filter_keys: Option<FilterKeysSettings>,
+}
+
+impl KeyboardAccessibility {
+    /// Retrieves the keyboard accessibility settings of the user using the native
+    /// `SystemParametersInfoW` function.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::accessibility::KeyboardAccessibility;
+    ///
+    /// let settings = KeyboardAccessibility::current().unwrap();
+    /// if settings.filter_keys.is_some() {
+    ///     println!("FilterKeys is on");
+    /// }
+    /// ```
+    pub fn current() -> Result<Self, WindowsError> {
+        unsafe {
+            let mut sticky: StickyKeys = mem::zeroed();
+            sticky.cb_size = mem::size_of::<StickyKeys>() as _;
+
+            // Calling C code
+            let result = winuser::SystemParametersInfoW(
+                winuser::SPI_GETSTICKYKEYS,
+                sticky.cb_size,
+                &mut sticky as *mut StickyKeys as _,
+                0,
+            );
+
+            if result == 0 {
+                return Err(WindowsError::from_last_error());
+            }
+
+            let mut filter: FilterKeys = mem::zeroed();
+            filter.cb_size = mem::size_of::<FilterKeys>() as _;
+
+            // Calling C code
+            let result = winuser::SystemParametersInfoW(
+                winuser::SPI_GETFILTERKEYS,
+                filter.cb_size,
+                &mut filter as *mut FilterKeys as _,
+                0,
+            );
+
+            if result == 0 {
+                return Err(WindowsError::from_last_error());
+            }
+
+            let filter_keys = if filter.dw_flags & FKF_FILTERKEYSON != 0 {
+                Some(FilterKeysSettings {
+                    acceptance_delay: Duration::from_millis(filter.i_wait_msec as _),
+                    repeat_delay: Duration::from_millis(filter.i_delay_msec as _),
+                    repeat_rate: Duration::from_millis(filter.i_repeat_msec as _),
+                    bounce_time: Duration::from_millis(filter.i_bounce_msec as _),
+                })
+            } else {
+                None
+            };
+
+            Ok(Self {
+                sticky_keys: sticky.dw_flags & SKF_STICKYKEYSON != 0,
+                filter_keys,
+            })
+        }
+    }
+
+    /// Checks whether any of the features that change how keystrokes are interpreted
+    /// is turned on.
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.sticky_keys || self.filter_keys.is_some()
+    }
+}
+
+/// Returns whether `vk` is one of the modifier keys StickyKeys is able to latch.
+fn is_sticky_modifier(vk: u16) -> bool {
+    Vk::try_from(vk).ok().and_then(Modifiers::from_vk).is_some()
+}
+
+/// Synthesizes the given inputs in a way that is recognized even when StickyKeys or
+/// FilterKeys are turned on.
+///
+/// When none of those features is active, this function behaves like
+/// [`try_send_inputs`].
+/// Otherwise, the inputs are sent one by one, in the order they were given:
+///
+/// * When FilterKeys is on, every key is held for at least the acceptance delay and
+///   the bounce time is respected after every release.
+/// * When StickyKeys is on, a short delay follows every modifier press so that the
+///   modifier is registered as held rather than latched.
+///
+/// ## Returns
+///
+/// The function returns the number of events that were successfully inserted into the
+/// input stream. If an event could not be inserted, the function stops and returns the
+/// error reported by the system.
+///
+/// [`try_send_inputs`]: ../fn.try_send_inputs.html
+pub fn send_inputs_accessible(inputs: impl AsRef<[Input]>) -> Result<u32, WindowsError> {
+    let inputs = inputs.as_ref();
+    let settings = KeyboardAccessibility::current()?;
+
+    if !settings.is_active() {
+        return try_send_inputs(inputs).map_err(WindowsError::from);
+    }
+
+    let mut count = 0;
+    for input in inputs {
+        count += try_send_inputs(std::slice::from_ref(input))?;
+
+        let (vk, is_release) = match input.keyboard_state() {
+            Some(state) => state,
+            None => continue,
+        };
+
+        let mut delay = Duration::from_millis(0);
+
+        if let Some(filter) = settings.filter_keys {
+            delay = if is_release {
+                filter.bounce_time
+            } else {
+                filter.acceptance_delay
+            };
+        }
+
+        if settings.sticky_keys && !is_release && is_sticky_modifier(vk) {
+            delay = delay.max(STICKY_MODIFIER_DELAY);
+        }
+
+        if delay > Duration::from_millis(0) {
+            thread::sleep(delay);
+        }
+    }
+
+    Ok(count)
+}
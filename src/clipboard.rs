@@ -0,0 +1,178 @@
+use crate::error::WindowsError;
+
+use std::ptr;
+use std::thread;
+use std::time::Duration;
+
+use winapi::shared::windef::HWND;
+use winapi::um::{winbase, winuser};
+
+/// An open clipboard. The clipboard is closed when this structure is dropped.
+///
+/// The clipboard is opened on behalf of a message-only window: `SetClipboardData` fails
+/// when the clipboard was emptied while it had no owner.
+pub(crate) struct Clipboard(HWND);
+
+impl Clipboard {
+    /// Opens the clipboard.
+    ///
+    /// Another application may be using the clipboard, in which case opening it is
+    /// attempted a few more times before giving up.
+    pub fn open() -> Result<Self, WindowsError> {
+        const ATTEMPTS: u32 = 10;
+        const RETRY_DELAY: Duration = Duration::from_millis(10);
+
+        let class_name: Vec<u16> = "STATIC\0".encode_utf16().collect();
+
+        // Calling C code
+        //
+        // The predefined `STATIC` class does not need to be registered.
+        let owner = unsafe {
+            winuser::CreateWindowExW(
+                0,
+                class_name.as_ptr(),
+                ptr::null(),
+                0,
+                0,
+                0,
+                0,
+                0,
+                winuser::HWND_MESSAGE,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+            )
+        };
+
+        if owner.is_null() {
+            return Err(WindowsError::from_last_error());
+        }
+
+        for i in 0..ATTEMPTS {
+            if i != 0 {
+                thread::sleep(RETRY_DELAY);
+            }
+
+            // Calling C code
+            if unsafe { winuser::OpenClipboard(owner) } != 0 {
+                return Ok(Self(owner));
+            }
+        }
+
+        let error = WindowsError::from_last_error();
+
+        // Calling C code
+        unsafe { winuser::DestroyWindow(owner) };
+
+        Err(error)
+    }
+
+    /// Retrieves the text stored in the clipboard, as UTF-16 code units without the
+    /// terminating null character. `None` is returned if the clipboard contains no text.
+    pub fn text(&self) -> Option<Vec<u16>> {
+        unsafe {
+            // Calling C code
+            if winuser::IsClipboardFormatAvailable(winuser::CF_UNICODETEXT) == 0 {
+                return None;
+            }
+
+            // Calling C code
+            let handle = winuser::GetClipboardData(winuser::CF_UNICODETEXT);
+            if handle.is_null() {
+                return None;
+            }
+
+            // Calling C code
+            let data = winbase::GlobalLock(handle) as *const u16;
+            if data.is_null() {
+                return None;
+            }
+
+            // The size of the memory block may be larger than the string, which is
+            // terminated by a null character.
+            let max_len = winbase::GlobalSize(handle) / 2;
+            let mut len = 0;
+            while len < max_len && *data.add(len) != 0 {
+                len += 1;
+            }
+
+            let text = std::slice::from_raw_parts(data, len).to_vec();
+
+            // Calling C code
+            winbase::GlobalUnlock(handle);
+
+            Some(text)
+        }
+    }
+
+    /// Replaces the content of the clipboard with the given UTF-16 text. The text must
+    /// not contain the terminating null character.
+    ///
+    /// The clipboard is only emptied once the text is ready to be stored, which means
+    /// that its content is left untouched if the memory block cannot be allocated.
+    pub fn set_text(&self, text: &[u16]) -> Result<(), WindowsError> {
+        unsafe {
+            let size = (text.len() + 1) * 2;
+
+            // Calling C code
+            let handle = winbase::GlobalAlloc(winbase::GMEM_MOVEABLE, size);
+            if handle.is_null() {
+                return Err(WindowsError::from_last_error());
+            }
+
+            // Calling C code
+            let data = winbase::GlobalLock(handle) as *mut u16;
+            if data.is_null() {
+                let error = WindowsError::from_last_error();
+                winbase::GlobalFree(handle);
+                return Err(error);
+            }
+
+            ptr::copy_nonoverlapping(text.as_ptr(), data, text.len());
+            *data.add(text.len()) = 0;
+
+            // Calling C code
+            winbase::GlobalUnlock(handle);
+
+            // Calling C code
+            if winuser::EmptyClipboard() == 0 {
+                let error = WindowsError::from_last_error();
+                winbase::GlobalFree(handle);
+                return Err(error);
+            }
+
+            // Calling C code
+            //
+            // On success, the system owns the memory block.
+            if winuser::SetClipboardData(winuser::CF_UNICODETEXT, handle).is_null() {
+                let error = WindowsError::from_last_error();
+                winbase::GlobalFree(handle);
+                return Err(error);
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Removes the content of the clipboard.
+    pub fn clear(&self) -> Result<(), WindowsError> {
+        // Calling C code
+        if unsafe { winuser::EmptyClipboard() } == 0 {
+            Err(WindowsError::from_last_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Drop for Clipboard {
+    fn drop(&mut self) {
+        // Calling C code
+        //
+        // The data placed in the clipboard outlives its owner.
+        unsafe {
+            winuser::CloseClipboard();
+            winuser::DestroyWindow(self.0);
+        }
+    }
+}
@@ -1,5 +1,13 @@
+use crate::error::WindowsError;
 use crate::vk::Vk;
 
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::thread;
+use std::time::Duration;
+
 use winapi::um::winuser;
 
 /// This structure is used by [`send_inputs`] to store information for synthesizing input
@@ -15,7 +23,7 @@ use winapi::um::winuser;
 /// ```
 ///
 /// [`send_inputs`]: fn.send_inputs.html
-#[derive(Clone)]
+#[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct Input(winuser::INPUT);
 
@@ -23,6 +31,9 @@ impl Input {
     /// Creates an [`Input`] that causes the given action to be taken on the given
     /// character. If the given character is above `0x0000ffff`, `None` is returned.
     ///
+    /// Characters above `0x0000ffff` are encoded as two UTF-16 code units and must be
+    /// sent using [`Input::from_char_utf16`].
+    ///
     /// ## Example
     ///
     /// ```rust, ignore
@@ -33,25 +44,61 @@ impl Input {
     /// ```
     ///
     /// [`Input`]: struct.Input.html
+    /// [`Input::from_char_utf16`]: struct.Input.html#method.from_char_utf16
     pub fn from_char(c: char, action: Action) -> Option<Input> {
         let c_n = c as u32;
         if c_n > 0x0000ffff {
             return None;
         }
 
+        Some(Self::from_utf16_unit(c as u16, action))
+    }
+
+    /// Creates the [`Input`]s that cause the given action to be taken on each UTF-16 code
+    /// unit of the given character.
+    ///
+    /// Characters up to `0x0000ffff` produce a single [`Input`]. Characters above that
+    /// (such as most emojis) produce two inputs, one for each half of their surrogate
+    /// pair. The receiver combines both halves into the original character.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Input, Action};
+    ///
+    /// let mut inputs: Vec<Input> = Input::from_char_utf16('🦀', Action::Press).collect();
+    /// inputs.extend(Input::from_char_utf16('🦀', Action::Release));
+    ///
+    /// winput::send_inputs(&inputs);
+    /// ```
+    ///
+    /// [`Input`]: struct.Input.html
+    pub fn from_char_utf16(c: char, action: Action) -> impl Iterator<Item = Input> {
+        let mut units = [0u16; 2];
+        let len = c.encode_utf16(&mut units).len();
+
+        (0..len).map(move |i| Self::from_utf16_unit(units[i], action))
+    }
+
+    /// Creates an [`Input`] that causes the given action to be taken on the given UTF-16
+    /// code unit.
+    ///
+    /// [`Input`]: struct.Input.html
+    fn from_utf16_unit(unit: u16, action: Action) -> Input {
         unsafe {
             let mut input: winuser::INPUT = std::mem::zeroed();
             input.type_ = winuser::INPUT_KEYBOARD;
             let ki = input.u.ki_mut();
             ki.wVk = 0; // must be 0 for a unicode event
-            ki.wScan = c as u16;
+            ki.wScan = unit;
             ki.dwFlags = match action {
                 Action::Release => winuser::KEYEVENTF_KEYUP | winuser::KEYEVENTF_UNICODE,
                 Action::Press => winuser::KEYEVENTF_UNICODE,
             };
             ki.time = 0; // let the system provide a time stamp
+            ki.dwExtraInfo = injection_tag() as usize;
 
-            Some(Self(input))
+            Self(input)
         }
     }
 
@@ -80,11 +127,146 @@ impl Input {
                 Action::Press => 0,
             };
             ki.time = 0; // let the system provide a time stamp
+            ki.dwExtraInfo = injection_tag() as usize;
+
+            // Some receivers only recognize those keys when their scan code is
+            // provided alongside the Virtual-Key Code.
+            if let Some((scan_code, extended)) = special_scan_code(vk) {
+                ki.wScan = scan_code;
+                if extended {
+                    ki.dwFlags |= winuser::KEYEVENTF_EXTENDEDKEY;
+                }
+            }
+
+            Self(input)
+        }
+    }
+
+    /// Creates the [`Input`]s of a keyboard shortcut: the modifiers are pressed in the
+    /// given order, the key is pressed and released, then the modifiers are released in
+    /// the reverse order.
+    ///
+    /// Sending the returned inputs in a single call to [`send_inputs`] ensures that no
+    /// other event is inserted in the middle of the shortcut.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::{Action, Input, Vk};
+    ///
+    /// let inputs = Input::combo(&[Vk::Control], Vk::C);
+    ///
+    /// assert_eq!(
+    ///     inputs,
+    ///     [
+    ///         Input::from_vk(Vk::Control, Action::Press),
+    ///         Input::from_vk(Vk::C, Action::Press),
+    ///         Input::from_vk(Vk::C, Action::Release),
+    ///         Input::from_vk(Vk::Control, Action::Release),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// [`Input`]: struct.Input.html
+    /// [`send_inputs`]: fn.send_inputs.html
+    pub fn combo(modifiers: &[Vk], key: Vk) -> Vec<Input> {
+        let mut inputs = Vec::with_capacity(modifiers.len() * 2 + 2);
+
+        for &m in modifiers {
+            inputs.push(Self::from_vk(m, Action::Press));
+        }
+
+        inputs.push(Self::from_vk(key, Action::Press));
+        inputs.push(Self::from_vk(key, Action::Release));
+
+        for &m in modifiers.iter().rev() {
+            inputs.push(Self::from_vk(m, Action::Release));
+        }
+
+        inputs
+    }
+
+    /// Creates an [`Input`] that causes the given action to be taken on the key with the
+    /// given hardware scan code.
+    ///
+    /// Extended scan codes must have their prefix in the high byte, as returned by
+    /// [`Vk::to_scan_code`]: for example `0xe01d` is the right CTRL key. The `E1 1D`
+    /// prefix of the Pause key is synthesized by the system from its `45` scan code.
+    ///
+    /// Unlike inputs created from a Virtual-Key Code, those inputs are recognized by
+    /// applications that read the keyboard through DirectInput or raw input.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Input, Action};
+    ///
+    /// let input = Input::from_scan_code(0x1e, Action::Press); // A (QWERTY)
+    /// winput::send_inputs(&[input]);
+    /// ```
+    ///
+    /// [`Input`]: struct.Input.html
+    /// [`Vk::to_scan_code`]: enum.Vk.html#method.to_scan_code
+    pub fn from_scan_code(scan_code: u16, action: Action) -> Input {
+        unsafe {
+            let mut input: winuser::INPUT = std::mem::zeroed();
+            input.type_ = winuser::INPUT_KEYBOARD;
+            let ki = input.u.ki_mut();
+            ki.wVk = 0; // ignored when using a scan code
+            ki.dwFlags = match action {
+                Action::Release => winuser::KEYEVENTF_KEYUP | winuser::KEYEVENTF_SCANCODE,
+                Action::Press => winuser::KEYEVENTF_SCANCODE,
+            };
+
+            match scan_code >> 8 {
+                0xe0 => {
+                    ki.wScan = scan_code & 0xff;
+                    ki.dwFlags |= winuser::KEYEVENTF_EXTENDEDKEY;
+                }
+                0xe1 => ki.wScan = 0x45,
+                _ => ki.wScan = scan_code & 0xff,
+            }
+
+            ki.time = 0; // let the system provide a time stamp
+            ki.dwExtraInfo = injection_tag() as usize;
 
             Self(input)
         }
     }
 
+    /// Creates a [`KeyInput`] builder for a keyboard event on the given Virtual-Key Code.
+    ///
+    /// The builder gives full control over the flags, the scan code and the time stamp
+    /// of the event. Unless modified, the built [`Input`] presses the key without any
+    /// flag.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Input, Vk};
+    ///
+    /// let input = Input::keyboard(Vk::RightControl)
+    ///     .scan_code(0x1d)
+    ///     .extended(true)
+    ///     .release()
+    ///     .build();
+    ///
+    /// winput::send_inputs(&[input]);
+    /// ```
+    ///
+    /// [`Input`]: struct.Input.html
+    /// [`KeyInput`]: struct.KeyInput.html
+    #[inline(always)]
+    pub fn keyboard(vk: Vk) -> KeyInput {
+        KeyInput {
+            vk: vk.into(),
+            scan_code: 0,
+            flags: 0,
+            time: 0,
+            extra_info: injection_tag() as usize,
+        }
+    }
+
     /// Creates an [`Input`] that causes the given action to be taken on the given mouse
     /// button.
     ///
@@ -130,7 +312,7 @@ impl Input {
             };
 
             mi.time = 0; // let the system provide a time stamp
-            mi.dwExtraInfo = 0; // no extra information
+            mi.dwExtraInfo = injection_tag() as usize;
 
             Self(input)
         }
@@ -184,10 +366,20 @@ impl Input {
 
                     mi.dwFlags |= winuser::MOUSEEVENTF_ABSOLUTE;
                 }
+                MouseMotion::AbsolutePixels { x, y, virtual_desk } => {
+                    if virtual_desk {
+                        mi.dwFlags |= winuser::MOUSEEVENTF_VIRTUALDESK;
+                    }
+
+                    let (left, top, width, height) = screen_bounds(virtual_desk);
+                    mi.dx = normalize_pixel(x - left, width);
+                    mi.dy = normalize_pixel(y - top, height);
+                    mi.dwFlags |= winuser::MOUSEEVENTF_ABSOLUTE;
+                }
             }
 
             mi.time = 0; // let the system provide a time stamp
-            mi.dwExtraInfo = 0; // no extra information
+            mi.dwExtraInfo = injection_tag() as usize;
 
             Self(input)
         }
@@ -223,7 +415,7 @@ impl Input {
             mi.dy = 0;
 
             const MOUSE_DELTA: f32 = 120.0;
-            mi.mouseData = (motion * MOUSE_DELTA) as i32 as u32;
+            mi.mouseData = (motion * MOUSE_DELTA).round() as i32 as u32;
 
             mi.dwFlags = match direction {
                 WheelDirection::Vertical => winuser::MOUSEEVENTF_WHEEL,
@@ -231,138 +423,1154 @@ impl Input {
             };
 
             mi.time = 0; // let the system provide a time stamp
-            mi.dwExtraInfo = 0; // no extra information
+            mi.dwExtraInfo = injection_tag() as usize;
 
             Self(input)
         }
     }
-}
 
-/// Synthesizes keystrokes, mouse motions, and button clicks.
-///
-/// ## Returns
-///
-/// This function returns the number of events that were successfully inserted onto the
-/// keyboard or mouse input stream.
-///
-/// If no events were successfully sent, the input stream was already blocked by another
-/// thread. You can use [`winput::WindowsError::from_last_error`] to retrieve additional
-/// information about this function failing to send events.
-///
-/// ## Example
-///
-/// ```rust, ignore
-/// use winput::{Vk, Input, Action};
-///
-/// let inputs = [
-///     Input::from_vk(Vk::Shift, Action::Press),
-///     Input::from_vk(Vk::A, Action::Press),
-///     Input::from_vk(Vk::A, Action::Release),
-///     Input::from_vk(Vk::Shift, Action::Release),
-/// ];
-///
-/// winput::send_inputs(&inputs);
-/// ```
-///
-/// [`winput::WindowsError::from_last_error`]: struct.WindowsError.html#method.from_last_error
-pub fn send_inputs(inputs: impl AsRef<[Input]>) -> u32 {
-    use std::mem;
+    /// Creates an [`Input`] that scrolls by the given number of lines (or characters
+    /// when the direction is horizontal), according to the wheel settings of the user.
+    ///
+    /// The number of lines scrolled by each notch of the wheel is retrieved using the
+    /// native `SystemParametersInfoW` function. Positive values scroll up (or right)
+    /// and negative values scroll down (or left).
+    ///
+    /// `None` is returned if the user disabled wheel scrolling or configured the wheel
+    /// to scroll one page per notch. In the latter case, [`Input::from_wheel_pages`]
+    /// should be used instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Input, WheelDirection};
+    ///
+    /// // Scroll down by 5 lines.
+    /// if let Some(input) = Input::from_wheel_lines(-5.0, WheelDirection::Vertical) {
+    ///     winput::send_inputs(&[input]);
+    /// }
+    /// ```
+    ///
+    /// [`Input`]: struct.Input.html
+    /// [`Input::from_wheel_pages`]: struct.Input.html#method.from_wheel_pages
+    pub fn from_wheel_lines(lines: f32, direction: WheelDirection) -> Option<Self> {
+        let per_notch = match direction {
+            WheelDirection::Vertical => wheel_setting(winuser::SPI_GETWHEELSCROLLLINES),
+            WheelDirection::Horizontal => wheel_setting(winuser::SPI_GETWHEELSCROLLCHARS),
+        };
 
-    // Calling C code
-    unsafe {
-        winuser::SendInput(
-            inputs.as_ref().len() as _,
-            inputs.as_ref().as_ptr() as _,
-            mem::size_of::<winuser::INPUT>() as _,
-        )
+        match per_notch {
+            0 | winuser::WHEEL_PAGESCROLL => None,
+            n => Some(Self::from_wheel(lines / n as f32, direction)),
+        }
     }
-}
 
-/// Represents an action that can be taken on a key or button.
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
-pub enum Action {
-    /// The action of pressing the key.
-    Press,
-    /// The action of releasing the key.
-    Release,
-}
+    /// Creates an [`Input`] that scrolls vertically by the given number of pages.
+    ///
+    /// Windows only defines a page-based scrolling when the user configured the wheel to
+    /// scroll one page per notch: `None` is returned otherwise. The `PageUp` and
+    /// `PageDown` keys can be used to scroll by pages regardless of the wheel settings.
+    ///
+    /// [`Input`]: struct.Input.html
+    pub fn from_wheel_pages(pages: f32) -> Option<Self> {
+        match wheel_setting(winuser::SPI_GETWHEELSCROLLLINES) {
+            winuser::WHEEL_PAGESCROLL => {
+                Some(Self::from_wheel(pages, WheelDirection::Vertical))
+            }
+            _ => None,
+        }
+    }
 
-impl Action {
-    /// Creates a new [`Action`] from the given `bool`.
+    /// Creates an [`Input`] that forwards a message generated by an input device other
+    /// than a keyboard or a mouse (`INPUT_HARDWARE`).
     ///
-    /// * If `is_press` is `true`, `Action::Press` is returned.
-    /// * If `is_press` is `false`, `Action::Release` is returned.
+    /// `low` and `high` are the low and high words of the `wParam` parameter of the
+    /// message.
     ///
     /// ## Example
     ///
-    /// ```rust
-    /// use winput::Action;
+    /// ```rust, ignore
+    /// use winput::Input;
     ///
-    /// assert_eq!(Action::from_press(true), Action::Press);
-    /// assert_eq!(Action::from_press(false), Action::Release);
+    /// let input = Input::from_hardware(msg, low, high);
+    /// winput::send_inputs(&[input]);
     /// ```
     ///
-    /// [`Action`]: enum.Action.html
-    pub fn from_press(is_press: bool) -> Self {
-        if is_press {
-            Self::Press
-        } else {
-            Self::Release
+    /// [`Input`]: struct.Input.html
+    pub fn from_hardware(msg: u32, low: u16, high: u16) -> Self {
+        unsafe {
+            let mut input: winuser::INPUT = std::mem::zeroed();
+            input.type_ = winuser::INPUT_HARDWARE;
+            let hi = input.u.hi_mut();
+            hi.uMsg = msg;
+            hi.wParamL = low;
+            hi.wParamH = high;
+
+            Self(input)
         }
     }
 
-    /// Creates a new [`Action`] from the given `bool`.
+    /// Creates a mouse [`Input`] from the raw fields of the native `MOUSEINPUT`
+    /// structure.
     ///
-    /// * If `is_release` is `true`, `Action::Release` is returned.
-    /// * If `is_release` is `false`, `Action::Press` is returned.
+    /// [`Input`]: struct.Input.html
+    #[cfg(feature = "serde")]
+    fn raw_mouse(dx: i32, dy: i32, mouse_data: u32, flags: u32) -> Self {
+        unsafe {
+            let mut input: winuser::INPUT = std::mem::zeroed();
+            input.type_ = winuser::INPUT_MOUSE;
+            let mi = input.u.mi_mut();
+            mi.dx = dx;
+            mi.dy = dy;
+            mi.mouseData = mouse_data;
+            mi.dwFlags = flags;
+            mi.time = 0; // let the system provide a time stamp
+            mi.dwExtraInfo = injection_tag() as usize;
+
+            Self(input)
+        }
+    }
+
+    /// Returns the message and the low and high words of the `wParam` parameter of this
+    /// hardware event.
+    ///
+    /// `None` is returned if this [`Input`] is not a hardware event.
+    ///
+    /// [`Input`]: struct.Input.html
+    #[inline]
+    pub fn hardware_message(&self) -> Option<(u32, u16, u16)> {
+        self.hi().map(|hi| (hi.uMsg, hi.wParamL, hi.wParamH))
+    }
+
+    /// Creates an [`Input`] from the native `INPUT` structure.
+    ///
+    /// `None` is returned if the `type_` field of the structure is not
+    /// `INPUT_KEYBOARD`, `INPUT_MOUSE` or `INPUT_HARDWARE`.
     ///
     /// ## Example
     ///
-    /// ```rust
-    /// use winput::Action;
+    /// ```rust, ignore
+    /// use winapi::um::winuser;
+    /// use winput::Input;
     ///
-    /// assert_eq!(Action::from_release(true), Action::Release);
-    /// assert_eq!(Action::from_release(false), Action::Press);
+    /// let mut raw: winuser::INPUT = unsafe { std::mem::zeroed() };
+    /// raw.type_ = winuser::INPUT_KEYBOARD;
+    /// unsafe { raw.u.ki_mut().wVk = winuser::VK_RETURN as u16 };
+    ///
+    /// winput::send_inputs(&[Input::from_raw(raw).unwrap()]);
     /// ```
     ///
-    /// [`Action`]: enum.Action.html
-    pub fn from_release(is_release: bool) -> Self {
-        if is_release {
-            Self::Release
+    /// [`Input`]: struct.Input.html
+    #[inline]
+    pub fn from_raw(raw: winuser::INPUT) -> Option<Self> {
+        match raw.type_ {
+            winuser::INPUT_KEYBOARD | winuser::INPUT_MOUSE | winuser::INPUT_HARDWARE => {
+                Some(Self(raw))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the native `INPUT` structure of this [`Input`].
+    ///
+    /// [`Input`]: struct.Input.html
+    #[inline(always)]
+    pub fn as_raw(&self) -> &winuser::INPUT {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the event stored in the native `INPUT` structure
+    /// of this [`Input`].
+    ///
+    /// Only the event can be modified: the kind of the input (the `type_` field of
+    /// the structure, see [`Input::kind`]) stays the same.
+    ///
+    /// [`Input`]: struct.Input.html
+    /// [`Input::kind`]: struct.Input.html#method.kind
+    #[inline(always)]
+    pub fn as_raw_mut(&mut self) -> &mut winuser::INPUT_u {
+        &mut self.0.u
+    }
+
+    /// Converts this [`Input`] into the native `INPUT` structure.
+    ///
+    /// [`Input`]: struct.Input.html
+    #[inline(always)]
+    pub fn into_raw(self) -> winuser::INPUT {
+        self.0
+    }
+
+    /// Returns the keyboard event of this [`Input`], if it is one.
+    ///
+    /// [`Input`]: struct.Input.html
+    fn ki(&self) -> Option<&winuser::KEYBDINPUT> {
+        if self.0.type_ == winuser::INPUT_KEYBOARD {
+            // SAFETY: The `type_` field states that the union contains a `KEYBDINPUT`.
+            Some(unsafe { self.0.u.ki() })
         } else {
-            Self::Press
+            None
         }
     }
-}
 
-/// A mouse button.
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum Button {
-    /// The left mouse button.
-    Left,
-    /// The right mouse button.
-    Right,
-    /// The middle mouse button.
-    Middle,
-    /// The X1 mouse button.
-    X1,
-    /// The X2 mouse button.
-    X2,
-}
+    /// Returns the mouse event of this [`Input`], if it is one.
+    ///
+    /// [`Input`]: struct.Input.html
+    fn mi(&self) -> Option<&winuser::MOUSEINPUT> {
+        if self.0.type_ == winuser::INPUT_MOUSE {
+            // SAFETY: The `type_` field states that the union contains a `MOUSEINPUT`.
+            Some(unsafe { self.0.u.mi() })
+        } else {
+            None
+        }
+    }
 
-/// Describes a mouse motion.
-#[derive(Clone, Copy, PartialEq, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum MouseMotion {
-    /// Describes a relative mouse motion, in pixels.
+    /// Returns the hardware event of this [`Input`], if it is one.
     ///
-    /// Relative mouse motion is subject to the effects of the mouse speed and the
-    /// two-mouse threshold values. A user sets these three values with the Pointer Speed
-    /// slider of the Control Panel's Mouse Properties sheet.
-    Relative {
-        /// The number of pixels the mouse should move, on the horizontal axis.
-        dx: i32,
+    /// [`Input`]: struct.Input.html
+    fn hi(&self) -> Option<&winuser::HARDWAREINPUT> {
+        if self.0.type_ == winuser::INPUT_HARDWARE {
+            // SAFETY: The `type_` field states that the union contains a
+            // `HARDWAREINPUT`.
+            Some(unsafe { self.0.u.hi() })
+        } else {
+            None
+        }
+    }
+
+    /// If this [`Input`] is a keyboard event, returns its Virtual-Key Code and whether
+    /// the key is being released.
+    ///
+    /// [`Input`]: struct.Input.html
+    pub(crate) fn keyboard_state(&self) -> Option<(u16, bool)> {
+        self.ki()
+            .map(|ki| (ki.wVk, ki.dwFlags & winuser::KEYEVENTF_KEYUP != 0))
+    }
+
+    /// If this [`Input`] is a keyboard event, makes it take the given action on its key.
+    /// Other events are returned unchanged.
+    ///
+    /// [`Input`]: struct.Input.html
+    #[cfg(not(feature = "minimal"))]
+    pub(crate) fn with_key_action(mut self, action: Action) -> Self {
+        if self.0.type_ == winuser::INPUT_KEYBOARD {
+            // SAFETY: The `type_` field states that the union contains a `KEYBDINPUT`.
+            let ki = unsafe { self.0.u.ki_mut() };
+            match action {
+                Action::Press => ki.dwFlags &= !winuser::KEYEVENTF_KEYUP,
+                Action::Release => ki.dwFlags |= winuser::KEYEVENTF_KEYUP,
+            }
+        }
+
+        self
+    }
+
+    /// Returns the kind of event described by this [`Input`].
+    ///
+    /// [`Input`]: struct.Input.html
+    #[inline]
+    pub fn kind(&self) -> InputKind {
+        match self.0.type_ {
+            winuser::INPUT_KEYBOARD => InputKind::Keyboard,
+            winuser::INPUT_HARDWARE => InputKind::Hardware,
+            _ => InputKind::Mouse,
+        }
+    }
+
+    /// Returns the Virtual-Key Code of this keyboard event.
+    ///
+    /// `None` is returned if this [`Input`] is not a keyboard event or if the event does
+    /// not use a Virtual-Key Code (for example when it was created with
+    /// [`Input::from_char`]).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::{Action, Input, Vk};
+    ///
+    /// let input = Input::from_vk(Vk::A, Action::Press);
+    /// assert_eq!(input.vk(), Some(Vk::A));
+    /// ```
+    ///
+    /// [`Input`]: struct.Input.html
+    /// [`Input::from_char`]: struct.Input.html#method.from_char
+    pub fn vk(&self) -> Option<Vk> {
+        self.ki().and_then(|ki| Vk::try_from(ki.wVk).ok())
+    }
+
+    /// Returns the scan code (the `wScan` field) of this keyboard event. For unicode
+    /// events, this is the UTF-16 code unit being sent.
+    ///
+    /// `None` is returned if this [`Input`] is not a keyboard event.
+    ///
+    /// [`Input`]: struct.Input.html
+    #[inline]
+    pub fn scan_code(&self) -> Option<u16> {
+        self.ki().map(|ki| ki.wScan)
+    }
+
+    /// Returns the action taken by this keyboard event.
+    ///
+    /// `None` is returned if this [`Input`] is not a keyboard event.
+    ///
+    /// [`Input`]: struct.Input.html
+    #[inline]
+    pub fn action(&self) -> Option<Action> {
+        self.keyboard_state()
+            .map(|(_, is_release)| Action::from_release(is_release))
+    }
+
+    /// Returns the raw flags (the `dwFlags` field) of this event.
+    #[inline]
+    pub fn flags(&self) -> u32 {
+        match (self.ki(), self.mi()) {
+            (Some(ki), _) => ki.dwFlags,
+            (_, Some(mi)) => mi.dwFlags,
+            _ => 0,
+        }
+    }
+
+    /// Returns the time stamp of this event, in milliseconds. A value of zero means that
+    /// the system provides its own time stamp.
+    #[inline]
+    pub fn time(&self) -> u32 {
+        match (self.ki(), self.mi()) {
+            (Some(ki), _) => ki.time,
+            (_, Some(mi)) => mi.time,
+            _ => 0,
+        }
+    }
+
+    /// Returns the motion (the `dx` and `dy` fields) of this mouse event.
+    ///
+    /// Whether the motion is relative or absolute depends on the flags of the event.
+    /// `None` is returned if this [`Input`] is not a mouse event.
+    ///
+    /// [`Input`]: struct.Input.html
+    #[inline]
+    pub fn mouse_motion(&self) -> Option<(i32, i32)> {
+        self.mi().map(|mi| (mi.dx, mi.dy))
+    }
+
+    /// Returns the additional data (the `mouseData` field) of this mouse event: the
+    /// amount of wheel motion or the X button involved in the event.
+    ///
+    /// `None` is returned if this [`Input`] is not a mouse event.
+    ///
+    /// [`Input`]: struct.Input.html
+    #[inline]
+    pub fn mouse_data(&self) -> Option<u32> {
+        self.mi().map(|mi| mi.mouseData)
+    }
+
+    /// Returns the additional information (the `dwExtraInfo` field) of this event.
+    ///
+    /// Unless modified, this is the injection tag that was set when the [`Input`] was
+    /// created (see [`set_injection_tag`]).
+    ///
+    /// [`Input`]: struct.Input.html
+    /// [`set_injection_tag`]: fn.set_injection_tag.html
+    #[inline]
+    pub fn extra_info(&self) -> usize {
+        match (self.ki(), self.mi()) {
+            (Some(ki), _) => ki.dwExtraInfo,
+            (_, Some(mi)) => mi.dwExtraInfo,
+            _ => 0,
+        }
+    }
+
+    /// Replaces the additional information (the `dwExtraInfo` field) of this event.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::{Action, Input, Vk};
+    ///
+    /// let input = Input::from_vk(Vk::A, Action::Press).with_extra_info(42);
+    /// assert_eq!(input.extra_info(), 42);
+    /// ```
+    pub fn with_extra_info(mut self, extra_info: usize) -> Self {
+        // SAFETY: The `type_` field states which member of the union is used.
+        unsafe {
+            match self.0.type_ {
+                winuser::INPUT_KEYBOARD => self.0.u.ki_mut().dwExtraInfo = extra_info,
+                winuser::INPUT_MOUSE => self.0.u.mi_mut().dwExtraInfo = extra_info,
+                _ => (),
+            }
+        }
+
+        self
+    }
+
+    /// Prevents the system from merging this mouse motion with other pending motions
+    /// (`MOUSEEVENTF_MOVE_NOCOALESCE`).
+    ///
+    /// By default, the system coalesces mouse motions that are not processed fast
+    /// enough: the receiver only sees the last position. Disabling coalescing is needed
+    /// when every intermediate position matters, for example when drawing.
+    ///
+    /// This function has no effect if this [`Input`] is not a mouse motion.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Input, MouseMotion};
+    ///
+    /// let input = Input::from_motion(MouseMotion::Relative { dx: 1, dy: 0 }).no_coalesce();
+    /// winput::send_inputs(&[input]);
+    /// ```
+    ///
+    /// [`Input`]: struct.Input.html
+    pub fn no_coalesce(mut self) -> Self {
+        if self.0.type_ == winuser::INPUT_MOUSE {
+            // SAFETY: The `type_` field states that the union contains a `MOUSEINPUT`.
+            let mi = unsafe { self.0.u.mi_mut() };
+            if mi.dwFlags & winuser::MOUSEEVENTF_MOVE != 0 {
+                mi.dwFlags |= winuser::MOUSEEVENTF_MOVE_NOCOALESCE;
+            }
+        }
+
+        self
+    }
+}
+
+impl PartialEq for Input {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.ki(), other.ki(), self.mi(), other.mi()) {
+            (Some(a), Some(b), _, _) => {
+                a.wVk == b.wVk
+                    && a.wScan == b.wScan
+                    && a.dwFlags == b.dwFlags
+                    && a.time == b.time
+                    && a.dwExtraInfo == b.dwExtraInfo
+            }
+            (_, _, Some(a), Some(b)) => {
+                a.dx == b.dx
+                    && a.dy == b.dy
+                    && a.mouseData == b.mouseData
+                    && a.dwFlags == b.dwFlags
+                    && a.time == b.time
+                    && a.dwExtraInfo == b.dwExtraInfo
+            }
+            _ => match (self.hi(), other.hi()) {
+                (Some(a), Some(b)) => {
+                    a.uMsg == b.uMsg && a.wParamL == b.wParamL && a.wParamH == b.wParamH
+                }
+                _ => false,
+            },
+        }
+    }
+}
+
+impl Eq for Input {}
+
+impl fmt::Debug for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Input");
+        s.field("kind", &self.kind());
+
+        if let Some(ki) = self.ki() {
+            s.field("vk", &format_args!("{:#04x}", ki.wVk))
+                .field("scan_code", &format_args!("{:#06x}", ki.wScan))
+                .field("flags", &format_args!("{:#x}", ki.dwFlags))
+                .field("time", &ki.time);
+        } else if let Some(mi) = self.mi() {
+            s.field("dx", &mi.dx)
+                .field("dy", &mi.dy)
+                .field("mouse_data", &format_args!("{:#x}", mi.mouseData))
+                .field("flags", &format_args!("{:#x}", mi.dwFlags))
+                .field("time", &mi.time);
+        } else if let Some(hi) = self.hi() {
+            s.field("message", &format_args!("{:#x}", hi.uMsg))
+                .field("param_low", &hi.wParamL)
+                .field("param_high", &hi.wParamH);
+        }
+
+        s.finish()
+    }
+}
+
+/// The kind of event described by an [`Input`].
+///
+/// [`Input`]: struct.Input.html
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum InputKind {
+    /// A keyboard event.
+    Keyboard,
+    /// A mouse event.
+    Mouse,
+    /// A message generated by an input device that is neither a keyboard nor a mouse.
+    Hardware,
+}
+
+/// A builder for keyboard [`Input`]s, created with [`Input::keyboard`].
+///
+/// [`Input`]: struct.Input.html
+/// [`Input::keyboard`]: struct.Input.html#method.keyboard
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct KeyInput {
+    vk: u16,
+    scan_code: u16,
+    flags: u32,
+    time: u32,
+    extra_info: usize,
+}
+
+impl KeyInput {
+    /// Sets or clears the given flag.
+    #[inline(always)]
+    fn with_flag(mut self, flag: u32, enabled: bool) -> Self {
+        if enabled {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+        self
+    }
+
+    /// Sets the Virtual-Key Code of the event.
+    #[inline(always)]
+    pub fn vk(mut self, vk: Vk) -> Self {
+        self.vk = vk.into();
+        self
+    }
+
+    /// Sets the hardware scan code of the event (the `wScan` field).
+    ///
+    /// The scan code is only used by the receiver unless [`KeyInput::use_scan_code`] or
+    /// [`KeyInput::unicode`] is set, in which case it replaces the Virtual-Key Code.
+    ///
+    /// [`KeyInput::use_scan_code`]: struct.KeyInput.html#method.use_scan_code
+    /// [`KeyInput::unicode`]: struct.KeyInput.html#method.unicode
+    #[inline(always)]
+    pub fn scan_code(mut self, scan_code: u16) -> Self {
+        self.scan_code = scan_code;
+        self
+    }
+
+    /// Sets whether the scan code is prefixed by `E0` (`KEYEVENTF_EXTENDEDKEY`).
+    #[inline(always)]
+    pub fn extended(self, extended: bool) -> Self {
+        self.with_flag(winuser::KEYEVENTF_EXTENDEDKEY, extended)
+    }
+
+    /// Sets whether the scan code identifies the key instead of the Virtual-Key Code
+    /// (`KEYEVENTF_SCANCODE`).
+    #[inline(always)]
+    pub fn use_scan_code(self, use_scan_code: bool) -> Self {
+        self.with_flag(winuser::KEYEVENTF_SCANCODE, use_scan_code)
+    }
+
+    /// Sets whether the scan code is a UTF-16 code unit rather than a key
+    /// (`KEYEVENTF_UNICODE`). The Virtual-Key Code of such events is always zero.
+    #[inline(always)]
+    pub fn unicode(self, unicode: bool) -> Self {
+        self.with_flag(winuser::KEYEVENTF_UNICODE, unicode)
+    }
+
+    /// Sets the action taken on the key (`KEYEVENTF_KEYUP`).
+    #[inline(always)]
+    pub fn action(self, action: Action) -> Self {
+        self.with_flag(winuser::KEYEVENTF_KEYUP, action == Action::Release)
+    }
+
+    /// Makes the event press the key. This is the default.
+    #[inline(always)]
+    pub fn press(self) -> Self {
+        self.action(Action::Press)
+    }
+
+    /// Makes the event release the key.
+    #[inline(always)]
+    pub fn release(self) -> Self {
+        self.action(Action::Release)
+    }
+
+    /// Sets the time stamp of the event, in milliseconds. When zero (the default), the
+    /// system provides its own time stamp.
+    #[inline(always)]
+    pub fn time(mut self, time: u32) -> Self {
+        self.time = time;
+        self
+    }
+
+    /// Sets the additional information (the `dwExtraInfo` field) of the event. By
+    /// default, this is the current injection tag (see [`set_injection_tag`]).
+    ///
+    /// [`set_injection_tag`]: fn.set_injection_tag.html
+    #[inline(always)]
+    pub fn extra_info(mut self, extra_info: usize) -> Self {
+        self.extra_info = extra_info;
+        self
+    }
+
+    /// Sets the raw `dwFlags` field of the event, replacing every flag previously set.
+    #[inline(always)]
+    pub fn flags(mut self, flags: u32) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Builds the [`Input`].
+    ///
+    /// [`Input`]: struct.Input.html
+    pub fn build(self) -> Input {
+        unsafe {
+            let mut input: winuser::INPUT = std::mem::zeroed();
+            input.type_ = winuser::INPUT_KEYBOARD;
+            let ki = input.u.ki_mut();
+            ki.wVk = if self.flags & winuser::KEYEVENTF_UNICODE != 0 {
+                0 // must be 0 for a unicode event
+            } else {
+                self.vk
+            };
+            ki.wScan = self.scan_code;
+            ki.dwFlags = self.flags;
+            ki.time = self.time;
+            ki.dwExtraInfo = self.extra_info;
+
+            Input(input)
+        }
+    }
+}
+
+impl From<KeyInput> for Input {
+    #[inline(always)]
+    fn from(key: KeyInput) -> Self {
+        key.build()
+    }
+}
+
+/// Retrieves one of the wheel settings of the user using the native
+/// `SystemParametersInfoW` function. The default value of the settings (`3`) is returned
+/// if the function fails.
+fn wheel_setting(action: u32) -> u32 {
+    let mut value: u32 = 3;
+
+    // Calling C code
+    let result = unsafe {
+        winuser::SystemParametersInfoW(action, 0, &mut value as *mut u32 as _, 0)
+    };
+
+    if result == 0 {
+        3
+    } else {
+        value
+    }
+}
+
+/// Returns the position and the size (`(left, top, width, height)`) of the area that
+/// absolute mouse motions map to: the virtual desktop, or the primary monitor.
+pub(crate) fn screen_bounds(virtual_desk: bool) -> (i32, i32, i32, i32) {
+    // Calling C code
+    unsafe {
+        if virtual_desk {
+            (
+                winuser::GetSystemMetrics(winuser::SM_XVIRTUALSCREEN),
+                winuser::GetSystemMetrics(winuser::SM_YVIRTUALSCREEN),
+                winuser::GetSystemMetrics(winuser::SM_CXVIRTUALSCREEN),
+                winuser::GetSystemMetrics(winuser::SM_CYVIRTUALSCREEN),
+            )
+        } else {
+            (
+                0,
+                0,
+                winuser::GetSystemMetrics(winuser::SM_CXSCREEN),
+                winuser::GetSystemMetrics(winuser::SM_CYSCREEN),
+            )
+        }
+    }
+}
+
+/// Converts a pixel offset into the normalized `0..=65535` range used by absolute mouse
+/// motions.
+///
+/// The system maps a normalized coordinate `n` to the pixel `n * size / 65536`
+/// (rounded down), so the smallest normalized coordinate that maps to `pixel` is
+/// computed by rounding up.
+pub(crate) fn normalize_pixel(pixel: i32, size: i32) -> i32 {
+    if size <= 0 {
+        return 0;
+    }
+
+    let pixel = i64::from(pixel.max(0).min(size - 1));
+    let size = i64::from(size);
+    ((pixel * 65536 + size - 1) / size).min(65535) as i32
+}
+
+/// Returns the scan code (and whether it is prefixed by `E0`) of the keys that are not
+/// properly recognized when synthesized with their Virtual-Key Code only.
+///
+/// * `PrintScreen` is `E0 37`.
+/// * `Pause` is `E1 1D 45`, which is produced by the non-extended `45` scan code.
+/// * `Cancel` (Ctrl+Break) is `E0 46`.
+fn special_scan_code(vk: Vk) -> Option<(u16, bool)> {
+    match vk {
+        Vk::PrintScreen => Some((0x37, true)),
+        Vk::Pause => Some((0x45, false)),
+        Vk::Cancel => Some((0x46, true)),
+        _ => None,
+    }
+}
+
+/// Synthesizes keystrokes, mouse motions, and button clicks.
+///
+/// ## Returns
+///
+/// This function returns the number of events that were successfully inserted onto the
+/// keyboard or mouse input stream.
+///
+/// If no events were successfully sent, the input stream was already blocked by another
+/// thread. You can use [`winput::WindowsError::from_last_error`] to retrieve additional
+/// information about this function failing to send events, or use [`try_send_inputs`]
+/// which does it for you.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::{Vk, Input, Action};
+///
+/// let inputs = [
+///     Input::from_vk(Vk::Shift, Action::Press),
+///     Input::from_vk(Vk::A, Action::Press),
+///     Input::from_vk(Vk::A, Action::Release),
+///     Input::from_vk(Vk::Shift, Action::Release),
+/// ];
+///
+/// winput::send_inputs(&inputs);
+/// ```
+///
+/// When sending is disabled (see [`set_sending_enabled`]), no event is inserted and the
+/// last error is set to `ERROR_CANCELLED`.
+///
+/// [`winput::WindowsError::from_last_error`]: struct.WindowsError.html#method.from_last_error
+/// [`try_send_inputs`]: fn.try_send_inputs.html
+/// [`set_sending_enabled`]: fn.set_sending_enabled.html
+pub fn send_inputs(inputs: impl AsRef<[Input]>) -> u32 {
+    use std::mem;
+    use winapi::um::errhandlingapi::SetLastError;
+
+    if !is_sending_enabled() {
+        // Calling C code
+        unsafe { SetLastError(ERROR_CANCELLED) };
+        return 0;
+    }
+
+    // Calling C code
+    unsafe {
+        winuser::SendInput(
+            inputs.as_ref().len() as _,
+            inputs.as_ref().as_ptr() as _,
+            mem::size_of::<winuser::INPUT>() as _,
+        )
+    }
+}
+
+/// The error code used by Windows when an operation was canceled by the user.
+pub(crate) const ERROR_CANCELLED: u32 = 1223;
+
+/// The error reported when events are rejected without the last error being set.
+const ERROR_ACCESS_DENIED: u32 = 5;
+
+/// Whether the functions of the crate are allowed to synthesize events.
+static SENDING_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Enables or disables the synthesis of events by this crate.
+///
+/// While sending is disabled, [`send_inputs`] and every function built on top of it
+/// (including the keyboard and mouse helpers) do not insert anything into the input
+/// stream. Functions that report errors return `ERROR_CANCELLED`, and
+/// [`send_inputs_with_pacing`] stops before its next chunk.
+///
+/// This can be used as an emergency switch, for example from a hotkey handler running
+/// on another thread, to stop a runaway automation script.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::{Action, Input, Vk};
+///
+/// winput::set_sending_enabled(false);
+///
+/// let inputs = [Input::from_vk(Vk::A, Action::Press)];
+/// assert_eq!(winput::send_inputs(&inputs), 0);
+///
+/// winput::set_sending_enabled(true);
+/// ```
+///
+/// [`send_inputs`]: fn.send_inputs.html
+/// [`send_inputs_with_pacing`]: fn.send_inputs_with_pacing.html
+#[inline]
+pub fn set_sending_enabled(enabled: bool) {
+    SENDING_ENABLED.store(enabled, Ordering::SeqCst);
+}
+
+/// Checks whether this crate is currently allowed to synthesize events (see
+/// [`set_sending_enabled`]).
+///
+/// [`set_sending_enabled`]: fn.set_sending_enabled.html
+#[inline]
+pub fn is_sending_enabled() -> bool {
+    SENDING_ENABLED.load(Ordering::SeqCst)
+}
+
+/// Returns an error if sending is disabled (see [`set_sending_enabled`]).
+///
+/// [`set_sending_enabled`]: fn.set_sending_enabled.html
+pub(crate) fn check_sending_enabled() -> Result<(), WindowsError> {
+    if is_sending_enabled() {
+        Ok(())
+    } else {
+        Err(WindowsError::from_error_code(ERROR_CANCELLED))
+    }
+}
+
+/// The value stored in the `dwExtraInfo` field of every `Input` created by the crate.
+static INJECTION_TAG: AtomicU32 = AtomicU32::new(0);
+
+/// Sets the tag stored in the additional information (the `dwExtraInfo` field) of every
+/// [`Input`] created from now on by this crate.
+///
+/// Applications that both listen to and synthesize input events (such as key
+/// remappers) can use the tag to recognize their own events and avoid processing them
+/// again. The tag is visible to low-level hooks (in `KBDLLHOOKSTRUCT::dwExtraInfo` and
+/// `MSLLHOOKSTRUCT::dwExtraInfo`), to raw input and to `GetMessageExtraInfo`.
+///
+/// A tag of `0`, which is the default, means that events are not tagged.
+///
+/// The tag is a 32-bit value even though `dwExtraInfo` is pointer-sized: raw input
+/// only reports the low 32 bits of the additional information (in
+/// `RAWKEYBOARD::ExtraInformation` and `RAWMOUSE::ulExtraInformation`), so a larger
+/// tag could not be recognized by the message loop.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::{Action, Input, Vk};
+///
+/// winput::set_injection_tag(0x7769_6e70);
+///
+/// let input = Input::from_vk(Vk::A, Action::Press);
+/// assert!(winput::is_injection_tag(input.extra_info()));
+/// ```
+///
+/// [`Input`]: struct.Input.html
+#[inline]
+pub fn set_injection_tag(tag: u32) {
+    INJECTION_TAG.store(tag, Ordering::Relaxed);
+}
+
+/// Returns the current injection tag (see [`set_injection_tag`]).
+///
+/// [`set_injection_tag`]: fn.set_injection_tag.html
+#[inline]
+pub fn injection_tag() -> u32 {
+    INJECTION_TAG.load(Ordering::Relaxed)
+}
+
+/// Checks whether the given additional information of an event (its `dwExtraInfo`)
+/// matches the current injection tag, meaning that the event was most likely
+/// synthesized by this process. This function always returns `false` when no tag is
+/// set.
+#[inline]
+pub fn is_injection_tag(extra_info: usize) -> bool {
+    let tag = injection_tag() as usize;
+    tag != 0 && tag == extra_info
+}
+
+/// Synthesizes keystrokes, mouse motions, and button clicks, and reports an error if
+/// any of them could not be inserted into the input stream.
+///
+/// This function behaves like [`send_inputs`], but retrieves the error reported by the
+/// system (using [`WindowsError::from_last_error`]) when fewer events than requested
+/// were inserted. An event is typically rejected when the input stream is blocked by
+/// another thread or by User Interface Privilege Isolation (UIPI).
+///
+/// ## Returns
+///
+/// On success, the number of events that were inserted (which is the number of given
+/// events) is returned. Otherwise, the returned [`SendInputsError`] holds the number of
+/// events that were inserted before the failure along with the error reported by the
+/// system.
+///
+/// Events blocked by UIPI are rejected without setting the last error. In that case,
+/// the error is reported as `ERROR_ACCESS_DENIED`.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::{Vk, Input, Action};
+///
+/// let inputs = [
+///     Input::from_vk(Vk::A, Action::Press),
+///     Input::from_vk(Vk::A, Action::Release),
+/// ];
+///
+/// if let Err(error) = winput::try_send_inputs(&inputs) {
+///     eprintln!("failed to send inputs: {}", error);
+/// }
+/// ```
+///
+/// [`send_inputs`]: fn.send_inputs.html
+/// [`SendInputsError`]: struct.SendInputsError.html
+/// [`WindowsError::from_last_error`]: struct.WindowsError.html#method.from_last_error
+pub fn try_send_inputs(inputs: impl AsRef<[Input]>) -> Result<u32, SendInputsError> {
+    use winapi::um::errhandlingapi::{GetLastError, SetLastError};
+
+    let inputs = inputs.as_ref();
+
+    // Calling C code
+    //
+    // The last error is cleared to distinguish the events rejected silently from the
+    // ones rejected with an error.
+    unsafe { SetLastError(0) };
+    let inserted = send_inputs(inputs);
+
+    if inserted as usize == inputs.len() {
+        return Ok(inserted);
+    }
+
+    // Calling C code
+    let error = match unsafe { GetLastError() } {
+        0 => WindowsError::from_error_code(ERROR_ACCESS_DENIED),
+        code => WindowsError::from_error_code(code),
+    };
+
+    Err(SendInputsError { inserted, error })
+}
+
+/// The error returned by [`try_send_inputs`] when some of the given events could not be
+/// inserted into the input stream.
+///
+/// [`try_send_inputs`]: fn.try_send_inputs.html
+#[derive(Clone, Debug)]
+pub struct SendInputsError {
+    inserted: u32,
+    error: WindowsError,
+}
+
+impl SendInputsError {
+    /// Returns the number of events that were inserted into the input stream before the
+    /// failure.
+    #[inline(always)]
+    pub fn inserted(&self) -> u32 {
+        self.inserted
+    }
+
+    /// Returns the error reported by the system.
+    #[inline(always)]
+    pub fn error(&self) -> &WindowsError {
+        &self.error
+    }
+}
+
+impl fmt::Display for SendInputsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "only {} events were inserted into the input stream: {}",
+            self.inserted, self.error,
+        )
+    }
+}
+
+impl Error for SendInputsError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<SendInputsError> for WindowsError {
+    #[inline(always)]
+    fn from(error: SendInputsError) -> Self {
+        error.error
+    }
+}
+
+/// Synthesizes the given inputs in chunks of `chunk_size` events, waiting for `delay`
+/// between two chunks.
+///
+/// Some applications drop keystrokes when many of them are inserted into the input
+/// stream at once. Sending them one (or a few) at a time with a short delay gives those
+/// applications the time to process every event. A `chunk_size` of `0` is treated as
+/// `1`.
+///
+/// ## Returns
+///
+/// This function returns the number of events that were successfully inserted into the
+/// input stream. If a chunk is not entirely inserted, the function stops early and the
+/// remaining inputs are not sent. You can use [`WindowsError::from_last_error`] to
+/// retrieve additional information about the failure.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use std::time::Duration;
+/// use winput::{Vk, Input, Action};
+///
+/// let inputs = [
+///     Input::from_vk(Vk::A, Action::Press),
+///     Input::from_vk(Vk::A, Action::Release),
+///     Input::from_vk(Vk::B, Action::Press),
+///     Input::from_vk(Vk::B, Action::Release),
+/// ];
+///
+/// winput::send_inputs_with_pacing(&inputs, 1, Duration::from_millis(10));
+/// ```
+///
+/// [`WindowsError::from_last_error`]: struct.WindowsError.html#method.from_last_error
+pub fn send_inputs_with_pacing(
+    inputs: impl AsRef<[Input]>,
+    chunk_size: usize,
+    delay: Duration,
+) -> u32 {
+    let mut count = 0;
+
+    for (i, chunk) in inputs.as_ref().chunks(chunk_size.max(1)).enumerate() {
+        if i != 0 {
+            thread::sleep(delay);
+        }
+
+        let sent = send_inputs(chunk);
+        count += sent;
+
+        if sent as usize != chunk.len() {
+            break;
+        }
+    }
+
+    count
+}
+
+/// Represents an action that can be taken on a key or button.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Action {
+    /// The action of pressing the key.
+    Press,
+    /// The action of releasing the key.
+    Release,
+}
+
+impl Action {
+    /// Creates a new [`Action`] from the given `bool`.
+    ///
+    /// * If `is_press` is `true`, `Action::Press` is returned.
+    /// * If `is_press` is `false`, `Action::Release` is returned.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Action;
+    ///
+    /// assert_eq!(Action::from_press(true), Action::Press);
+    /// assert_eq!(Action::from_press(false), Action::Release);
+    /// ```
+    ///
+    /// [`Action`]: enum.Action.html
+    pub fn from_press(is_press: bool) -> Self {
+        if is_press {
+            Self::Press
+        } else {
+            Self::Release
+        }
+    }
+
+    /// Creates a new [`Action`] from the given `bool`.
+    ///
+    /// * If `is_release` is `true`, `Action::Release` is returned.
+    /// * If `is_release` is `false`, `Action::Press` is returned.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Action;
+    ///
+    /// assert_eq!(Action::from_release(true), Action::Release);
+    /// assert_eq!(Action::from_release(false), Action::Press);
+    /// ```
+    ///
+    /// [`Action`]: enum.Action.html
+    pub fn from_release(is_release: bool) -> Self {
+        if is_release {
+            Self::Release
+        } else {
+            Self::Press
+        }
+    }
+}
+
+/// A mouse button.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Button {
+    /// The left mouse button.
+    Left,
+    /// The right mouse button.
+    Right,
+    /// The middle mouse button.
+    Middle,
+    /// The X1 mouse button.
+    X1,
+    /// The X2 mouse button.
+    X2,
+}
+
+impl Button {
+    /// Returns the Virtual-Key Code of this button.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::{Button, Vk};
+    ///
+    /// assert_eq!(Button::Middle.to_vk(), Vk::MouseMiddle);
+    /// ```
+    pub fn to_vk(self) -> Vk {
+        match self {
+            Button::Left => Vk::MouseLeft,
+            Button::Right => Vk::MouseRight,
+            Button::Middle => Vk::MouseMiddle,
+            Button::X1 => Vk::MouseX1,
+            Button::X2 => Vk::MouseX2,
+        }
+    }
+
+    /// Returns the button associated with the given Virtual-Key Code, if the key is a
+    /// mouse button.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::{Button, Vk};
+    ///
+    /// assert_eq!(Button::from_vk(Vk::MouseX1), Some(Button::X1));
+    /// assert_eq!(Button::from_vk(Vk::A), None);
+    /// ```
+    pub fn from_vk(vk: Vk) -> Option<Self> {
+        match vk {
+            Vk::MouseLeft => Some(Button::Left),
+            Vk::MouseRight => Some(Button::Right),
+            Vk::MouseMiddle => Some(Button::Middle),
+            Vk::MouseX1 => Some(Button::X1),
+            Vk::MouseX2 => Some(Button::X2),
+            _ => None,
+        }
+    }
+}
+
+/// Describes a mouse motion.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MouseMotion {
+    /// Describes a relative mouse motion, in pixels.
+    ///
+    /// Relative mouse motion is subject to the effects of the mouse speed and the
+    /// two-mouse threshold values. A user sets these three values with the Pointer Speed
+    /// slider of the Control Panel's Mouse Properties sheet.
+    Relative {
+        /// The number of pixels the mouse should move, on the horizontal axis.
+        dx: i32,
         /// The number of pixels the mouse should move, on the vertical axis. A positive
         /// value makes the mouse go down.
         dy: i32,
@@ -381,6 +1589,22 @@ pub enum MouseMotion {
         /// desktop (if multiple monitors are used, for example).
         virtual_desk: bool,
     },
+    /// Describes an absolute mouse motion, in pixels.
+    ///
+    /// The coordinates are converted into normalized coordinates when the [`Input`] is
+    /// created, using the size of the screen at that time.
+    ///
+    /// [`Input`]: struct.Input.html
+    AbsolutePixels {
+        /// The position of the mouse on the horizontal axis, in screen coordinates.
+        x: i32,
+        /// The position of the mouse on the vertical axis, in screen coordinates.
+        y: i32,
+        /// Whether the coordinates are relative to the entier virtual desktop. When
+        /// `false`, the coordinates are relative to the primary monitor and positions on
+        /// other monitors cannot be reached.
+        virtual_desk: bool,
+    },
 }
 
 /// Describes the direction of a mouse wheel.
@@ -390,3 +1614,321 @@ pub enum WheelDirection {
     Vertical,
     Horizontal,
 }
+
+/// A portable representation of [`Input`]s, used by their `Serialize` and `Deserialize`
+/// implementations.
+///
+/// Inputs are described by what they do rather than by the raw bytes of the native
+/// `INPUT` structure. The time stamp and the additional information of the events are
+/// not serialized.
+///
+/// [`Input`]: struct.Input.html
+#[cfg(feature = "serde")]
+mod portable {
+    use super::{Action, Button, Input, KeyInput, WheelDirection};
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use winapi::um::winuser;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(tag = "kind", rename_all = "snake_case")]
+    enum PortableInput {
+        /// A key identified by its Virtual-Key Code.
+        Key {
+            vk: u16,
+            scan_code: u16,
+            extended: bool,
+            action: Action,
+        },
+        /// A key identified by its scan code.
+        ScanCode {
+            scan_code: u16,
+            extended: bool,
+            action: Action,
+        },
+        /// A UTF-16 code unit.
+        Unicode { code_unit: u16, action: Action },
+        /// A mouse motion.
+        Move {
+            dx: i32,
+            dy: i32,
+            absolute: bool,
+            virtual_desk: bool,
+            #[serde(default)]
+            no_coalesce: bool,
+        },
+        /// A mouse button.
+        Button { button: Button, action: Action },
+        /// A rotation of the mouse wheel, in 1/120 of a notch (`WHEEL_DELTA`), so that
+        /// the rotation is stored exactly.
+        Wheel {
+            delta: i32,
+            direction: WheelDirection,
+        },
+        /// A mouse event that does not fit in the other variants.
+        RawMouse {
+            dx: i32,
+            dy: i32,
+            mouse_data: u32,
+            flags: u32,
+        },
+        /// A hardware message.
+        Hardware { message: u32, low: u16, high: u16 },
+    }
+
+    /// The mouse flags that describe the way a motion is interpreted.
+    const MOTION_FLAGS: u32 = winuser::MOUSEEVENTF_MOVE
+        | winuser::MOUSEEVENTF_ABSOLUTE
+        | winuser::MOUSEEVENTF_VIRTUALDESK
+        | winuser::MOUSEEVENTF_MOVE_NOCOALESCE;
+
+    /// Returns the button and action described by the given mouse flags, if the flags
+    /// describe exactly one button event.
+    fn button_from_flags(flags: u32, mouse_data: u32) -> Option<(Button, Action)> {
+        let button = match flags {
+            winuser::MOUSEEVENTF_LEFTDOWN => (Button::Left, Action::Press),
+            winuser::MOUSEEVENTF_LEFTUP => (Button::Left, Action::Release),
+            winuser::MOUSEEVENTF_RIGHTDOWN => (Button::Right, Action::Press),
+            winuser::MOUSEEVENTF_RIGHTUP => (Button::Right, Action::Release),
+            winuser::MOUSEEVENTF_MIDDLEDOWN => (Button::Middle, Action::Press),
+            winuser::MOUSEEVENTF_MIDDLEUP => (Button::Middle, Action::Release),
+            winuser::MOUSEEVENTF_XDOWN | winuser::MOUSEEVENTF_XUP => {
+                let action = Action::from_press(flags == winuser::MOUSEEVENTF_XDOWN);
+                match mouse_data {
+                    1 => (Button::X1, action),
+                    2 => (Button::X2, action),
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        Some(button)
+    }
+
+    impl From<&Input> for PortableInput {
+        fn from(input: &Input) -> Self {
+            if let Some(ki) = input.ki() {
+                let action =
+                    Action::from_release(ki.dwFlags & winuser::KEYEVENTF_KEYUP != 0);
+                let extended = ki.dwFlags & winuser::KEYEVENTF_EXTENDEDKEY != 0;
+
+                if ki.dwFlags & winuser::KEYEVENTF_UNICODE != 0 {
+                    PortableInput::Unicode {
+                        code_unit: ki.wScan,
+                        action,
+                    }
+                } else if ki.dwFlags & winuser::KEYEVENTF_SCANCODE != 0 {
+                    PortableInput::ScanCode {
+                        scan_code: ki.wScan,
+                        extended,
+                        action,
+                    }
+                } else {
+                    PortableInput::Key {
+                        vk: ki.wVk,
+                        scan_code: ki.wScan,
+                        extended,
+                        action,
+                    }
+                }
+            } else if let Some(mi) = input.mi() {
+                let flags = mi.dwFlags;
+
+                if flags & winuser::MOUSEEVENTF_MOVE != 0 && flags & !MOTION_FLAGS == 0 {
+                    PortableInput::Move {
+                        dx: mi.dx,
+                        dy: mi.dy,
+                        absolute: flags & winuser::MOUSEEVENTF_ABSOLUTE != 0,
+                        virtual_desk: flags & winuser::MOUSEEVENTF_VIRTUALDESK != 0,
+                        no_coalesce: flags & winuser::MOUSEEVENTF_MOVE_NOCOALESCE != 0,
+                    }
+                } else if flags == winuser::MOUSEEVENTF_WHEEL
+                    || flags == winuser::MOUSEEVENTF_HWHEEL
+                {
+                    PortableInput::Wheel {
+                        delta: mi.mouseData as i32,
+                        direction: if flags == winuser::MOUSEEVENTF_WHEEL {
+                            WheelDirection::Vertical
+                        } else {
+                            WheelDirection::Horizontal
+                        },
+                    }
+                } else if let Some((button, action)) =
+                    button_from_flags(flags, mi.mouseData)
+                {
+                    PortableInput::Button { button, action }
+                } else {
+                    PortableInput::RawMouse {
+                        dx: mi.dx,
+                        dy: mi.dy,
+                        mouse_data: mi.mouseData,
+                        flags,
+                    }
+                }
+            } else {
+                // An `Input` is always a keyboard, a mouse or a hardware event.
+                let (message, low, high) = input
+                    .hardware_message()
+                    .expect("the input is a hardware event");
+                PortableInput::Hardware { message, low, high }
+            }
+        }
+    }
+
+    impl From<PortableInput> for Input {
+        fn from(input: PortableInput) -> Self {
+            match input {
+                PortableInput::Key {
+                    vk,
+                    scan_code,
+                    extended,
+                    action,
+                } => KeyInput {
+                    vk,
+                    scan_code,
+                    flags: 0,
+                    time: 0,
+                    extra_info: super::injection_tag() as usize,
+                }
+                .extended(extended)
+                .action(action)
+                .build(),
+                PortableInput::ScanCode {
+                    scan_code,
+                    extended,
+                    action,
+                } => KeyInput {
+                    vk: 0,
+                    scan_code,
+                    flags: winuser::KEYEVENTF_SCANCODE,
+                    time: 0,
+                    extra_info: super::injection_tag() as usize,
+                }
+                .extended(extended)
+                .action(action)
+                .build(),
+                PortableInput::Unicode { code_unit, action } => {
+                    Input::from_utf16_unit(code_unit, action)
+                }
+                PortableInput::Move {
+                    dx,
+                    dy,
+                    absolute,
+                    virtual_desk,
+                    no_coalesce,
+                } => {
+                    let mut flags = winuser::MOUSEEVENTF_MOVE;
+                    if absolute {
+                        flags |= winuser::MOUSEEVENTF_ABSOLUTE;
+                    }
+                    if virtual_desk {
+                        flags |= winuser::MOUSEEVENTF_VIRTUALDESK;
+                    }
+                    if no_coalesce {
+                        flags |= winuser::MOUSEEVENTF_MOVE_NOCOALESCE;
+                    }
+                    Input::raw_mouse(dx, dy, 0, flags)
+                }
+                PortableInput::Button { button, action } => {
+                    Input::from_button(button, action)
+                }
+                PortableInput::Wheel { delta, direction } => {
+                    let flags = match direction {
+                        WheelDirection::Vertical => winuser::MOUSEEVENTF_WHEEL,
+                        WheelDirection::Horizontal => winuser::MOUSEEVENTF_HWHEEL,
+                    };
+                    Input::raw_mouse(0, 0, delta as u32, flags)
+                }
+                PortableInput::RawMouse {
+                    dx,
+                    dy,
+                    mouse_data,
+                    flags,
+                } => Input::raw_mouse(dx, dy, mouse_data, flags),
+                PortableInput::Hardware { message, low, high } => {
+                    Input::from_hardware(message, low, high)
+                }
+            }
+        }
+    }
+
+    impl Serialize for Input {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            PortableInput::from(self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Input {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            PortableInput::deserialize(deserializer).map(Input::from)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the scan code and whether the key is extended, for a press and a
+    /// release of the given key.
+    fn special_key(vk: Vk) -> [(Option<u16>, bool, Option<Action>); 2] {
+        let key = |action| {
+            let input = Input::from_vk(vk, action);
+            assert_eq!(input.vk(), Some(vk));
+
+            (
+                input.scan_code(),
+                input.flags() & winuser::KEYEVENTF_EXTENDEDKEY != 0,
+                input.action(),
+            )
+        };
+
+        [key(Action::Press), key(Action::Release)]
+    }
+
+    #[test]
+    fn print_screen_is_extended() {
+        assert_eq!(
+            special_key(Vk::PrintScreen),
+            [
+                (Some(0x37), true, Some(Action::Press)),
+                (Some(0x37), true, Some(Action::Release)),
+            ]
+        );
+    }
+
+    #[test]
+    fn pause_is_not_extended() {
+        assert_eq!(
+            special_key(Vk::Pause),
+            [
+                (Some(0x45), false, Some(Action::Press)),
+                (Some(0x45), false, Some(Action::Release)),
+            ]
+        );
+    }
+
+    #[test]
+    fn cancel_is_extended() {
+        assert_eq!(
+            special_key(Vk::Cancel),
+            [
+                (Some(0x46), true, Some(Action::Press)),
+                (Some(0x46), true, Some(Action::Release)),
+            ]
+        );
+    }
+
+    #[test]
+    fn other_keys_have_no_scan_code() {
+        assert_eq!(special_scan_code(Vk::A), None);
+        assert_eq!(
+            special_key(Vk::A),
+            [
+                (Some(0), false, Some(Action::Press)),
+                (Some(0), false, Some(Action::Release)),
+            ]
+        );
+    }
+}
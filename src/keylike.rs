@@ -1,6 +1,14 @@
-use crate::input::{send_inputs, Action, Button, Input};
+use crate::clipboard::Clipboard;
+use crate::error::WindowsError;
+use crate::input::{send_inputs, try_send_inputs, Action, Button, Input, InputKind};
+use crate::layout::{DeadKey, KeyboardLayout};
+use crate::modifiers::Modifiers;
 use crate::vk::Vk;
 
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+
 /// A trait for objects that can be used as keys. For example a [`Vk`] or a `char` can be
 /// used as a key.
 ///
@@ -16,6 +24,9 @@ use crate::vk::Vk;
 ///
 /// // Do the same with one line
 /// winput::send('A');
+///
+/// // Or using a key combination
+/// winput::send((Vk::Shift, Vk::A));
 /// ```
 ///
 /// [`Vk`]: enum.Vk.html
@@ -36,6 +47,34 @@ pub trait Keylike: Copy {
     /// winput::send_inputs(&[input]);
     /// ```
     fn produce_input(self, action: Action) -> Input;
+
+    /// Appends the `Input`s that cause the given action to be taken on `self` to
+    /// `inputs`.
+    ///
+    /// Most keys produce a single `Input` (the one returned by `produce_input`), which
+    /// is what the default implementation does. Key combinations, such as
+    /// `(Vk::Control, Vk::C)`, produce one `Input` per key: modifiers are pressed before
+    /// the key and released after it.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if `self` was not a valid key.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::{Action, Input, Keylike, Vk};
+    ///
+    /// let mut inputs = Vec::new();
+    /// (Vk::Control, Vk::C).produce_inputs(Action::Press, &mut inputs);
+    /// (Vk::Control, Vk::C).produce_inputs(Action::Release, &mut inputs);
+    ///
+    /// assert_eq!(inputs, Input::combo(&[Vk::Control], Vk::C));
+    /// ```
+    #[inline]
+    fn produce_inputs(self, action: Action, inputs: &mut Vec<Input>) {
+        inputs.push(self.produce_input(action));
+    }
 }
 
 impl Keylike for char {
@@ -43,6 +82,13 @@ impl Keylike for char {
     fn produce_input(self, action: Action) -> Input {
         Input::from_char(self, action).expect("character above 0x0000ffff")
     }
+
+    /// Characters above `0x0000ffff` produce two `Input`s, one for each of their UTF-16
+    /// code units.
+    #[inline]
+    fn produce_inputs(self, action: Action, inputs: &mut Vec<Input>) {
+        inputs.extend(Input::from_char_utf16(self, action));
+    }
 }
 
 impl Keylike for Vk {
@@ -58,74 +104,453 @@ impl Keylike for Button {
     }
 }
 
+/// A prebuilt `Input`, which makes it possible to mix hand-built events with other
+/// keys.
+///
+/// Keyboard events take the requested action on their key. Other events (such as
+/// mouse motions) are produced unchanged when the key is pressed, and nothing is
+/// produced when it is released.
+impl Keylike for Input {
+    #[inline(always)]
+    fn produce_input(self, action: Action) -> Input {
+        self.with_key_action(action)
+    }
+
+    fn produce_inputs(self, action: Action, inputs: &mut Vec<Input>) {
+        if action == Action::Release && self.kind() != InputKind::Keyboard {
+            return;
+        }
+
+        inputs.push(self.produce_input(action));
+    }
+}
+
+/// A character typed using the keys of the keyboard layout rather than a Unicode
+/// event.
+///
+/// A plain `char` is sent as a Unicode event, which applications that only look at the
+/// state of the keys never see as a keystroke (for example, `'A'` is not seen with
+/// `Shift` held down). A [`LayoutChar`] is resolved to a Virtual-Key Code and the
+/// modifiers it requires using the keyboard layout of the foreground window (see
+/// [`Vk::from_char_with_layout`]): the modifiers are pressed before the key and
+/// released after it. Characters that cannot be typed with a single keystroke on that
+/// layout are sent as Unicode events.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::LayoutChar;
+///
+/// // Presses `Shift`, then `1`, then releases both keys on a US layout.
+/// winput::send(LayoutChar('!'));
+/// ```
+///
+/// [`LayoutChar`]: struct.LayoutChar.html
+/// [`Vk::from_char_with_layout`]: enum.Vk.html#method.from_char_with_layout
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct LayoutChar(pub char);
+
+impl LayoutChar {
+    /// Resolves the key and the modifiers that type this character.
+    fn resolve(self) -> Option<(Vk, Modifiers)> {
+        Vk::from_char_with_layout(self.0, KeyboardLayout::foreground())
+    }
+}
+
+impl Keylike for LayoutChar {
+    /// Produces the `Input` of the key only, without the modifiers. Use
+    /// `produce_inputs` to include them.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if the character cannot be typed using the layout and is
+    /// above `0x0000ffff`.
+    fn produce_input(self, action: Action) -> Input {
+        match self.resolve() {
+            Some((vk, _)) => Input::from_vk(vk, action),
+            None => self.0.produce_input(action),
+        }
+    }
+
+    fn produce_inputs(self, action: Action, inputs: &mut Vec<Input>) {
+        match self.resolve() {
+            Some((vk, modifiers)) => {
+                let modifiers: Vec<Vk> = modifiers.keys().collect();
+                (&modifiers[..], vk).produce_inputs(action, inputs);
+            }
+            None => self.0.produce_inputs(action, inputs),
+        }
+    }
+}
+
+/// A key pressed while a modifier is held down, such as `(Vk::Control, Vk::C)`.
+///
+/// `produce_input` only produces the `Input` of the key. Use `produce_inputs`, or any
+/// of the functions of this module, to synthesize the modifier as well.
+impl Keylike for (Vk, Vk) {
+    #[inline(always)]
+    fn produce_input(self, action: Action) -> Input {
+        Input::from_vk(self.1, action)
+    }
+
+    #[inline]
+    fn produce_inputs(self, action: Action, inputs: &mut Vec<Input>) {
+        (&[self.0][..], self.1).produce_inputs(action, inputs);
+    }
+}
+
+/// A key pressed while some modifiers are held down, such as
+/// `(&[Vk::Control, Vk::Shift][..], Vk::Escape)`. Modifiers are pressed in order and
+/// released in reverse order.
+///
+/// `produce_input` only produces the `Input` of the key. Use `produce_inputs`, or any
+/// of the functions of this module, to synthesize the modifiers as well.
+impl Keylike for (&[Vk], Vk) {
+    #[inline(always)]
+    fn produce_input(self, action: Action) -> Input {
+        Input::from_vk(self.1, action)
+    }
+
+    fn produce_inputs(self, action: Action, inputs: &mut Vec<Input>) {
+        let (modifiers, key) = self;
+
+        match action {
+            Action::Press => {
+                inputs.extend(modifiers.iter().map(|&vk| Input::from_vk(vk, action)));
+                inputs.push(Input::from_vk(key, action));
+            }
+            Action::Release => {
+                inputs.push(Input::from_vk(key, action));
+                inputs
+                    .extend(modifiers.iter().rev().map(|&vk| Input::from_vk(vk, action)));
+            }
+        }
+    }
+}
+
 /// Synthesize an event that presses the key.
 ///
 /// If the function fails to synthesize the input, no error is emited and the
 /// function fails silently. If you wish to retreive an eventual error, use
-/// `send_inputs` instead.
+/// [`try_press`] instead.
 ///
 /// ## Panics
 ///
-/// This function panics if `key` was not a valid key. For example, any `char` that
-/// is above `0x0000ffff` cannot be turned into an `Input`.
+/// This function panics if `key` was not a valid key (see [`Keylike`]). Characters
+/// above `0x0000ffff` are sent as two UTF-16 code units.
 ///
 /// ## Example
 ///
 /// ```rust, ignore
 /// winput::press('A').unwrap();
 /// ```
+///
+/// [`Keylike`]: trait.Keylike.html
+/// [`try_press`]: fn.try_press.html
 #[inline]
 pub fn press<K: Keylike>(key: K) {
-    let input = key.produce_input(Action::Press);
-    crate::input::send_inputs(&[input]);
+    let _ = try_press(key);
 }
 
 /// Synthesizes an event that releases the key.
 ///
 /// If the function fails to synthesize the input, no error is emited and the
 /// function fails silently. If you wish to retreive an eventual error, use
-/// `send_inputs` instead.
+/// [`try_release`] instead.
 ///
 /// ## Panics
 ///
-/// This function panics if `key` was not a valid key. For example, any `char` that
-/// is above `0x0000ffff` cannot be turned into an `Input`.
+/// This function panics if `key` was not a valid key (see [`Keylike`]). Characters
+/// above `0x0000ffff` are sent as two UTF-16 code units.
 ///
 /// ## Example
 ///
 /// ```rust, ignore
 /// winput::release('B').unwrap();
 /// ```
+///
+/// [`Keylike`]: trait.Keylike.html
+/// [`try_release`]: fn.try_release.html
 #[inline(always)]
 pub fn release<K: Keylike>(key: K) {
-    let input = key.produce_input(Action::Release);
-    crate::input::send_inputs(&[input]);
+    let _ = try_release(key);
 }
 
 /// Synthesizes two events. One that presses the key, one that releases the key.
 ///
 /// If the function fails to synthesize the input, no error is emited and the
 /// function fails silently. If you wish to retreive an eventual error, use
-/// `send_inputs` instead.
+/// [`try_send`] instead.
 ///
 /// ## Panics
 ///
-/// This function panics if `key` was not a valid value. For example, any `char` that
-/// is above `0x0000ffff` cannot be turned into an `Input`.
+/// This function panics if `key` was not a valid key (see [`Keylike`]). Characters
+/// above `0x0000ffff` are sent as two UTF-16 code units.
 ///
 /// ## Example
 ///
 /// ```rust, ignore
 /// winput::send('C').unwrap();
 /// ```
+///
+/// [`Keylike`]: trait.Keylike.html
+/// [`try_send`]: fn.try_send.html
 #[inline(always)]
 pub fn send<K: Keylike>(key: K) {
-    let inputs = [
-        key.produce_input(Action::Press),
-        key.produce_input(Action::Release),
-    ];
+    let _ = try_send(key);
+}
+
+/// Synthesizes an event that presses the key, and reports an error if it could not be
+/// inserted into the input stream (see [`try_send_inputs`]).
+///
+/// ## Panics
+///
+/// This function panics if `key` was not a valid key (see [`Keylike`]). Characters
+/// above `0x0000ffff` are sent as two UTF-16 code units.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::Vk;
+///
+/// if let Err(error) = winput::try_press(Vk::Shift) {
+///     eprintln!("failed to press shift: {}", error);
+/// }
+/// ```
+///
+/// [`Keylike`]: trait.Keylike.html
+/// [`try_send_inputs`]: fn.try_send_inputs.html
+#[inline]
+pub fn try_press<K: Keylike>(key: K) -> Result<(), WindowsError> {
+    let mut inputs = Vec::new();
+    key.produce_inputs(Action::Press, &mut inputs);
+    try_send_inputs(&inputs)?;
+    Ok(())
+}
 
-    crate::input::send_inputs(&inputs);
+/// Synthesizes an event that releases the key, and reports an error if it could not be
+/// inserted into the input stream (see [`try_send_inputs`]).
+///
+/// ## Panics
+///
+/// This function panics if `key` was not a valid key (see [`Keylike`]). Characters
+/// above `0x0000ffff` are sent as two UTF-16 code units.
+///
+/// [`Keylike`]: trait.Keylike.html
+/// [`try_send_inputs`]: fn.try_send_inputs.html
+#[inline]
+pub fn try_release<K: Keylike>(key: K) -> Result<(), WindowsError> {
+    let mut inputs = Vec::new();
+    key.produce_inputs(Action::Release, &mut inputs);
+    try_send_inputs(&inputs)?;
+    Ok(())
+}
+
+/// Synthesizes two events, one that presses the key and one that releases it, and
+/// reports an error if they could not be inserted into the input stream (see
+/// [`try_send_inputs`]).
+///
+/// ## Panics
+///
+/// This function panics if `key` was not a valid key (see [`Keylike`]). Characters
+/// above `0x0000ffff` are sent as two UTF-16 code units.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// winput::try_send('🦀').unwrap();
+/// ```
+///
+/// [`Keylike`]: trait.Keylike.html
+/// [`try_send_inputs`]: fn.try_send_inputs.html
+pub fn try_send<K: Keylike>(key: K) -> Result<(), WindowsError> {
+    let mut inputs = Vec::with_capacity(2);
+    key.produce_inputs(Action::Press, &mut inputs);
+    key.produce_inputs(Action::Release, &mut inputs);
+    try_send_inputs(&inputs)?;
+    Ok(())
+}
+
+/// Synthesizes `count` events that press the key, waiting for `interval` between two
+/// of them, followed by a single event that releases the key.
+///
+/// This mimics the auto-repeat of a key held down on a physical keyboard, where the
+/// receiving application gets several `WM_KEYDOWN` messages before a `WM_KEYUP`. A
+/// `count` of `0` does not send anything.
+///
+/// The function returns the number of inputs that were successfully inserted into the
+/// input stream. If an event could not be inserted, the function stops early; the key
+/// is still released if it was pressed.
+///
+/// ## Panics
+///
+/// This function panics if `key` was not a valid key (see [`Keylike`]).
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use std::time::Duration;
+/// use winput::Vk;
+///
+/// // Scroll down a list as if the arrow key was held for a second.
+/// winput::send_repeat(Vk::DownArrow, 30, Duration::from_millis(33));
+/// ```
+///
+/// [`Keylike`]: trait.Keylike.html
+pub fn send_repeat<K: Keylike>(key: K, count: u32, interval: Duration) -> u32 {
+    let mut press = Vec::new();
+    key.produce_inputs(Action::Press, &mut press);
+    let mut release = Vec::new();
+    key.produce_inputs(Action::Release, &mut release);
+
+    let mut sent = 0;
+    for i in 0..count {
+        if i != 0 {
+            thread::sleep(interval);
+        }
+
+        let n = send_inputs(&press);
+        sent += n;
+
+        if n as usize != press.len() {
+            if n == 0 && i == 0 {
+                return sent;
+            }
+            break;
+        }
+    }
+
+    if count != 0 {
+        sent += send_inputs(&release);
+    }
+
+    sent
+}
+
+/// A guard that holds keys down until it is dropped.
+///
+/// The keys are pressed in order when the guard is created and released in reverse
+/// order when it is dropped, including when the thread panics. This ensures that
+/// modifiers are never left stuck.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::{HeldKeys, Vk};
+///
+/// {
+///     let _held = HeldKeys::press(&[Vk::Control, Vk::Shift]);
+///     winput::send(Vk::Escape);
+/// } // `Shift` and `Control` are released here
+/// ```
+#[derive(Debug)]
+#[must_use = "the keys are released as soon as the guard is dropped"]
+pub struct HeldKeys {
+    keys: Vec<Vk>,
+}
+
+impl HeldKeys {
+    /// Presses the given keys and returns a guard that releases them when dropped.
+    pub fn press(keys: &[Vk]) -> Self {
+        let inputs: Vec<Input> = keys
+            .iter()
+            .map(|&vk| Input::from_vk(vk, Action::Press))
+            .collect();
+        let _ = try_send_inputs(&inputs);
+
+        Self {
+            keys: keys.to_vec(),
+        }
+    }
+
+    /// Returns the keys held by this guard.
+    #[inline(always)]
+    pub fn keys(&self) -> &[Vk] {
+        &self.keys
+    }
+}
+
+impl Drop for HeldKeys {
+    fn drop(&mut self) {
+        let inputs: Vec<Input> = self
+            .keys
+            .iter()
+            .rev()
+            .map(|&vk| Input::from_vk(vk, Action::Release))
+            .collect();
+        let _ = try_send_inputs(&inputs);
+    }
+}
+
+/// A guard that holds a key down until it is dropped (see [`hold`]).
+///
+/// [`hold`]: fn.hold.html
+#[derive(Debug)]
+#[must_use = "the key is released as soon as the guard is dropped"]
+pub struct KeyHold<K: Keylike> {
+    key: K,
+}
+
+impl<K: Keylike> KeyHold<K> {
+    /// Returns the key held by this guard.
+    #[inline(always)]
+    pub fn key(&self) -> K {
+        self.key
+    }
+}
+
+impl<K: Keylike> Drop for KeyHold<K> {
+    fn drop(&mut self) {
+        release(self.key);
+    }
+}
+
+/// Presses the key and returns a guard that releases it when dropped, including when
+/// the thread panics.
+///
+/// ## Panics
+///
+/// This function panics if `key` was not a valid key (see [`Keylike`]).
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use std::time::Duration;
+/// use winput::{Button, Vk};
+///
+/// let _forward = winput::hold(Vk::W);
+/// std::thread::sleep(Duration::from_millis(1500));
+/// winput::send(Button::Left);
+/// std::thread::sleep(Duration::from_millis(1500));
+/// // `W` is released here
+/// ```
+///
+/// [`Keylike`]: trait.Keylike.html
+#[inline]
+pub fn hold<K: Keylike>(key: K) -> KeyHold<K> {
+    press(key);
+    KeyHold { key }
+}
+
+/// Presses the given keys, calls `f`, then releases the keys in reverse order.
+///
+/// The keys are released even if `f` panics (see [`HeldKeys`]).
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::Vk;
+///
+/// // Select three words to the left of the caret.
+/// winput::with_held(&[Vk::Control, Vk::Shift], || {
+///     winput::send_keys(vec![Vk::LeftArrow; 3]);
+/// });
+/// ```
+///
+/// [`HeldKeys`]: struct.HeldKeys.html
+pub fn with_held<R>(keys: &[Vk], f: impl FnOnce() -> R) -> R {
+    let _held = HeldKeys::press(keys);
+    f()
 }
 
 /// Synthesizes keystrokes according to the given iterator of keys.
@@ -143,7 +568,7 @@ pub fn send<K: Keylike>(key: K) {
 /// ## Panics
 ///
 /// This function panics if the buffer used to store the produced inputs fails to
-/// allocate or if any of the given keys is unable to produce an `Input`.
+/// allocate or if any of the given keys was not a valid key (see [`Keylike`]).
 ///
 /// ## Example
 ///
@@ -154,6 +579,8 @@ pub fn send<K: Keylike>(key: K) {
 ///
 /// winput::send_keys(keys);
 /// ```
+///
+/// [`Keylike`]: trait.Keylike.html
 pub fn send_keys<I>(keys: I) -> u32
 where
     I: IntoIterator,
@@ -163,15 +590,177 @@ where
     let mut buffer = Vec::with_capacity(iter.size_hint().0 * 2);
 
     for key in iter {
-        buffer.push(key.produce_input(Action::Press));
-        buffer.push(key.produce_input(Action::Release));
+        key.produce_inputs(Action::Press, &mut buffer);
+        key.produce_inputs(Action::Release, &mut buffer);
     }
 
     send_inputs(&buffer)
 }
 
+/// Synthesizes keystrokes according to the given iterator of keys, sending them in
+/// chunks of at most `chunk_size` inputs.
+///
+/// Unlike [`send_keys`], this function does not store every input produced by the
+/// keys before sending them: a buffer of `chunk_size` inputs is flushed every time it
+/// is full. This bounds the memory used when typing very long sequences. A
+/// `chunk_size` of `0` is treated as `1`.
+///
+/// The function returns the number of inputs that were successfully inserted into the
+/// keyboard input stream. If a chunk is not entirely inserted, the function stops
+/// early and the remaining keys are not sent. You can use
+/// `winput::WindowsError::from_last_error` to retrieve additional information about
+/// the failure.
+///
+/// ## Panics
+///
+/// This function panics if any of the given keys was not a valid key (see
+/// [`Keylike`]).
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// let text = std::fs::read_to_string("file.txt").unwrap();
+///
+/// winput::send_keys_chunked(text.chars(), 256);
+/// ```
+///
+/// [`send_keys`]: fn.send_keys.html
+/// [`Keylike`]: trait.Keylike.html
+pub fn send_keys_chunked<I>(keys: I, chunk_size: usize) -> u32
+where
+    I: IntoIterator,
+    I::Item: Keylike,
+{
+    let chunk_size = chunk_size.max(1);
+    let mut buffer = Vec::with_capacity(chunk_size);
+    let mut count = 0;
+
+    for key in keys {
+        key.produce_inputs(Action::Press, &mut buffer);
+        key.produce_inputs(Action::Release, &mut buffer);
+
+        // A single key may produce more inputs than the size of a chunk.
+        while buffer.len() >= chunk_size {
+            let sent = send_inputs(&buffer[..chunk_size]);
+            count += sent;
+
+            if sent as usize != chunk_size {
+                return count;
+            }
+
+            buffer.drain(..chunk_size);
+        }
+    }
+
+    if !buffer.is_empty() {
+        count += send_inputs(&buffer);
+    }
+
+    count
+}
+
+/// Synthesizes keystrokes according to the given iterator of keys, one key at a time,
+/// until the keys are exhausted or `cancel` is set.
+///
+/// This is meant for long sequences sent from a background thread: another thread (for
+/// example a user interface) can stop the typing by setting `cancel` to `true`, and
+/// `progress` is called with the number of keys sent so far after every key.
+///
+/// The function returns the number of inputs that were successfully inserted into the
+/// keyboard input stream. It also stops early if a key could not be inserted; you can
+/// use `winput::WindowsError::from_last_error` to retrieve additional information
+/// about the failure.
+///
+/// ## Panics
+///
+/// This function panics if any of the given keys was not a valid key (see
+/// [`Keylike`]).
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use std::sync::atomic::AtomicBool;
+/// use std::sync::Arc;
+///
+/// let cancel = Arc::new(AtomicBool::new(false));
+/// let text = std::fs::read_to_string("file.txt").unwrap();
+/// let total = text.chars().count();
+///
+/// let handle = std::thread::spawn({
+///     let cancel = Arc::clone(&cancel);
+///     move || {
+///         winput::send_keys_cancellable(text.chars(), &cancel, |sent| {
+///             println!("{}/{}", sent, total);
+///         })
+///     }
+/// });
+///
+/// // Stop typing from another thread.
+/// cancel.store(true, std::sync::atomic::Ordering::Relaxed);
+/// handle.join().unwrap();
+/// ```
+///
+/// [`Keylike`]: trait.Keylike.html
+pub fn send_keys_cancellable<I, F>(keys: I, cancel: &AtomicBool, mut progress: F) -> u32
+where
+    I: IntoIterator,
+    I::Item: Keylike,
+    F: FnMut(usize),
+{
+    let mut buffer = Vec::with_capacity(4);
+    let mut count = 0;
+
+    for (i, key) in keys.into_iter().enumerate() {
+        if cancel.load(Ordering::Relaxed) {
+            break;
+        }
+
+        buffer.clear();
+        key.produce_inputs(Action::Press, &mut buffer);
+        key.produce_inputs(Action::Release, &mut buffer);
+
+        let sent = send_inputs(&buffer);
+        count += sent;
+
+        if sent as usize != buffer.len() {
+            break;
+        }
+
+        progress(i + 1);
+    }
+
+    count
+}
+
+/// Synthesizes keystrokes following the given string reference, one character at a
+/// time, until the end of the string or until `cancel` is set.
+///
+/// `progress` is called with the number of characters sent so far after every
+/// character. See [`send_keys_cancellable`] for more information.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use std::sync::atomic::AtomicBool;
+///
+/// let cancel = AtomicBool::new(false);
+/// winput::send_str_cancellable("Hello, world", &cancel, |_| {});
+/// ```
+///
+/// [`send_keys_cancellable`]: fn.send_keys_cancellable.html
+#[inline]
+pub fn send_str_cancellable<F>(s: &str, cancel: &AtomicBool, progress: F) -> u32
+where
+    F: FnMut(usize),
+{
+    send_keys_cancellable(s.chars(), cancel, progress)
+}
+
 /// Synthesizes keystrokes following the given string reference.
 ///
+/// Characters above `0x0000ffff` (such as most emojis) are sent as two UTF-16 code
+/// units, which the receiver combines into the original character.
+///
 /// Note that this function needs to allocate a buffer to store the inputs produced by
 /// the characters.
 ///
@@ -184,15 +773,510 @@ where
 ///
 /// ## Panics
 ///
-/// This function panics if the buffer fails to allocate or if any of the given character
-/// fails to produce an `Input`.
+/// This function panics if the buffer fails to allocate.
 ///
 /// ## Example
 ///
 /// ```rust, ignore
-/// winput::send_str("Hello, world");
+/// winput::send_str("Hello, world 🦀");
 /// ```
-#[inline(always)]
 pub fn send_str(s: &str) -> u32 {
-    send_keys(s.chars())
+    let mut buffer = Vec::with_capacity(s.len() * 2);
+
+    for c in s.chars() {
+        buffer.extend(Input::from_char_utf16(c, Action::Press));
+        buffer.extend(Input::from_char_utf16(c, Action::Release));
+    }
+
+    send_inputs(&buffer)
+}
+
+/// Synthesizes keystrokes following the given string reference, waiting between two
+/// characters.
+///
+/// Some applications (such as web forms or remote desktop sessions) drop characters
+/// when a whole string is inserted into the input stream at once. This function sends
+/// the characters one by one and sleeps for `delay`, plus a random duration between
+/// zero and `jitter`, after each of them. Use a `jitter` of zero for a constant
+/// delay.
+///
+/// The function returns the number of inputs that were successfully inserted into the
+/// keyboard input stream. If a character could not be inserted, the function stops
+/// and the remaining characters are not sent. You can use
+/// `winput::WindowsError::from_last_error` to retreive additional information about
+/// the failure.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use std::time::Duration;
+///
+/// winput::send_str_with_delay(
+///     "Hello, world",
+///     Duration::from_millis(30),
+///     Duration::from_millis(20),
+/// );
+/// ```
+pub fn send_str_with_delay(s: &str, delay: Duration, jitter: Duration) -> u32 {
+    let mut rng = Jitter::new();
+    let mut count = 0;
+    let mut buffer = Vec::with_capacity(4);
+
+    for (i, c) in s.chars().enumerate() {
+        if i != 0 {
+            thread::sleep(delay + rng.next_duration(jitter));
+        }
+
+        buffer.clear();
+        buffer.extend(Input::from_char_utf16(c, Action::Press));
+        buffer.extend(Input::from_char_utf16(c, Action::Release));
+
+        let sent = send_inputs(&buffer);
+        count += sent;
+
+        if sent as usize != buffer.len() {
+            break;
+        }
+    }
+
+    count
+}
+
+/// Synthesizes keystrokes following the given string reference, using the keys of the
+/// keyboard layout instead of Unicode events.
+///
+/// Some applications (notably games and old Win32 applications) ignore the Unicode
+/// events produced by [`send_str`]. This function resolves every character to a
+/// Virtual-Key Code and the modifiers it requires using the keyboard layout of the
+/// foreground window (see [`Vk::from_char_with_layout`]), and synthesizes the
+/// corresponding key events.
+///
+/// On layouts with dead keys, accented characters (such as `é`) are typed as the dead
+/// key followed by the base key, and the character of a dead key (such as `´`) is
+/// typed as the dead key followed by a space. Characters that cannot be typed on the
+/// layout are sent as Unicode events.
+///
+/// The modifiers that are held down by the user while this function runs are combined
+/// with the synthesized ones, which may change the characters that are produced.
+///
+/// The function returns the number of inputs that were successfully inserted into the
+/// keyboard input stream.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// winput::send_str_vk("Hello, World!");
+/// ```
+///
+/// [`send_str`]: fn.send_str.html
+/// [`Vk::from_char_with_layout`]: enum.Vk.html#method.from_char_with_layout
+#[inline]
+pub fn send_str_vk(s: &str) -> u32 {
+    send_str_with(s, StrOptions::new().layout_keys(true))
+}
+
+/// Options that change how [`send_str_with`] types a string.
+///
+/// The default options type every character using Unicode events, like [`send_str`].
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::StrOptions;
+///
+/// let options = StrOptions::new().translate_whitespace(true);
+/// winput::send_str_with("first line\n\tindented line", options);
+/// ```
+///
+/// [`send_str_with`]: fn.send_str_with.html
+/// [`send_str`]: fn.send_str.html
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Debug)]
+pub struct StrOptions {
+    translate_whitespace: bool,
+    layout_keys: bool,
+}
+
+impl StrOptions {
+    /// Creates the default [`StrOptions`].
+    ///
+    /// [`StrOptions`]: struct.StrOptions.html
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends line breaks (`'\n'`, `'\r'` and `"\r\n"`) as a keystroke of [`Vk::Enter`]
+    /// and tabulations (`'\t'`) as a keystroke of [`Vk::Tab`], instead of the raw
+    /// control characters that many edit controls ignore.
+    ///
+    /// [`Vk::Enter`]: enum.Vk.html#variant.Enter
+    /// [`Vk::Tab`]: enum.Vk.html#variant.Tab
+    #[inline(always)]
+    pub fn translate_whitespace(mut self, yes: bool) -> Self {
+        self.translate_whitespace = yes;
+        self
+    }
+
+    /// Types characters using the keys of the keyboard layout of the foreground window
+    /// rather than Unicode events (see [`send_str_vk`]).
+    ///
+    /// [`send_str_vk`]: fn.send_str_vk.html
+    #[inline(always)]
+    pub fn layout_keys(mut self, yes: bool) -> Self {
+        self.layout_keys = yes;
+        self
+    }
+}
+
+/// Synthesizes keystrokes following the given string reference, using the given
+/// [`StrOptions`].
+///
+/// The function returns the number of inputs that were successfully inserted into the
+/// keyboard input stream.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::StrOptions;
+///
+/// let options = StrOptions::new().translate_whitespace(true).layout_keys(true);
+/// winput::send_str_with("Hello,\nWorld!", options);
+/// ```
+///
+/// [`StrOptions`]: struct.StrOptions.html
+pub fn send_str_with(s: &str, options: StrOptions) -> u32 {
+    let mut layout_keys = LayoutKeys::new(KeyboardLayout::foreground());
+    let mut buffer = Vec::with_capacity(s.len() * 2);
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if options.translate_whitespace {
+            let key = match c {
+                '\r' => {
+                    // "\r\n" is a single line break.
+                    if chars.peek() == Some(&'\n') {
+                        chars.next();
+                    }
+                    Some(Vk::Enter)
+                }
+                '\n' => Some(Vk::Enter),
+                '\t' => Some(Vk::Tab),
+                _ => None,
+            };
+
+            if let Some(key) = key {
+                buffer.push(Input::from_vk(key, Action::Press));
+                buffer.push(Input::from_vk(key, Action::Release));
+                continue;
+            }
+        }
+
+        if options.layout_keys {
+            layout_keys.push_char(c, &mut buffer);
+        } else {
+            buffer.extend(Input::from_char_utf16(c, Action::Press));
+            buffer.extend(Input::from_char_utf16(c, Action::Release));
+        }
+    }
+
+    send_inputs(&buffer)
+}
+
+/// Resolves characters to the keys that type them on a keyboard layout.
+struct LayoutKeys {
+    layout: KeyboardLayout,
+    /// The dead keys of the layout, looked up the first time they are needed.
+    dead_keys: Option<Vec<DeadKey>>,
+}
+
+impl LayoutKeys {
+    /// Creates a resolver for the given layout.
+    fn new(layout: KeyboardLayout) -> Self {
+        Self {
+            layout,
+            dead_keys: None,
+        }
+    }
+
+    /// Appends the key events that type the given character to `buffer`.
+    ///
+    /// Characters that require a dead key are typed as the dead key followed by the
+    /// base key, and the character of a dead key is typed as the dead key followed by
+    /// a space. Unicode events are used for the characters that cannot be typed on the
+    /// layout.
+    fn push_char(&mut self, c: char, buffer: &mut Vec<Input>) {
+        fn push_key(vk: Vk, modifiers: Modifiers, buffer: &mut Vec<Input>) {
+            let modifiers: Vec<Vk> = modifiers.keys().collect();
+            buffer.extend(Input::combo(&modifiers, vk));
+        }
+
+        let layout = self.layout;
+
+        if let Some((vk, modifiers)) = Vk::from_char_with_layout(c, layout) {
+            push_key(vk, modifiers, buffer);
+            if layout.is_dead_key(vk, modifiers) {
+                push_key(Vk::Space, Modifiers::empty(), buffer);
+            }
+            return;
+        }
+
+        let dead_keys = self.dead_keys.get_or_insert_with(|| layout.dead_keys());
+        match layout.find_composition(dead_keys, c) {
+            Some((dead_key, vk, modifiers)) => {
+                push_key(dead_key.vk, dead_key.modifiers, buffer);
+                push_key(vk, modifiers, buffer);
+            }
+            None => {
+                buffer.extend(Input::from_char_utf16(c, Action::Press));
+                buffer.extend(Input::from_char_utf16(c, Action::Release));
+            }
+        }
+    }
+}
+
+/// Types the given string by pasting it from the clipboard.
+///
+/// The text currently stored in the clipboard is saved, the string is placed in the
+/// clipboard, a `Ctrl+V` keystroke is synthesized, and the saved text is restored
+/// once the target application had the time to read the clipboard. For long strings,
+/// this is much faster and more reliable than typing every character (see
+/// [`send_str`]).
+///
+/// Only the textual content of the clipboard is restored: other formats (such as
+/// images or files) are lost.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// let text = std::fs::read_to_string("report.txt").unwrap();
+/// winput::send_str_paste(&text).unwrap();
+/// ```
+///
+/// [`send_str`]: fn.send_str.html
+pub fn send_str_paste(s: &str) -> Result<(), WindowsError> {
+    /// The time given to the target application to read the clipboard before its
+    /// previous content is restored.
+    const PASTE_DELAY: Duration = Duration::from_millis(100);
+
+    /// Puts the saved text back into the clipboard.
+    fn restore(clipboard: &Clipboard, saved: Option<&[u16]>) -> Result<(), WindowsError> {
+        match saved {
+            Some(saved) => clipboard.set_text(saved),
+            None => clipboard.clear(),
+        }
+    }
+
+    let text: Vec<u16> = s.encode_utf16().collect();
+
+    let saved = {
+        let clipboard = Clipboard::open()?;
+        let saved = clipboard.text();
+
+        if let Err(error) = clipboard.set_text(&text) {
+            // The clipboard may have been emptied before the failure.
+            let _ = restore(&clipboard, saved.as_deref());
+            return Err(error);
+        }
+
+        saved
+    };
+
+    let result = try_send_inputs(Input::combo(&[Vk::Control], Vk::V));
+    thread::sleep(PASTE_DELAY);
+
+    restore(&Clipboard::open()?, saved.as_deref())?;
+
+    result?;
+    Ok(())
+}
+
+/// A small pseudo-random generator (xorshift) used to vary the delay between
+/// keystrokes. It does not need to be cryptographically secure.
+struct Jitter(u64);
+
+impl Jitter {
+    /// Creates a new generator seeded from the randomly keyed hasher of the standard
+    /// library.
+    fn new() -> Self {
+        use std::collections::hash_map::RandomState;
+        use std::hash::{BuildHasher, Hasher};
+
+        let seed = RandomState::new().build_hasher().finish();
+        // The state of a xorshift generator must not be zero.
+        Self(seed | 1)
+    }
+
+    /// Returns a random duration between zero and `max`.
+    fn next_duration(&mut self, max: Duration) -> Duration {
+        if max == Duration::from_secs(0) {
+            return max;
+        }
+
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        let nanos = max.as_nanos().min(u128::from(u64::MAX - 1)) as u64;
+        Duration::from_nanos(self.0 % (nanos + 1))
+    }
+}
+
+/// Synthesizes a press of the `PrintScreen` key.
+///
+/// If `window_only` is `true`, the `Alt` key is held during the keystroke, which
+/// captures the active window instead of the whole screen.
+///
+/// The function returns the number of inputs that were successfully inserted into the
+/// keyboard input stream.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// // Capture the active window.
+/// winput::print_screen(true);
+/// ```
+pub fn print_screen(window_only: bool) -> u32 {
+    send_inputs(print_screen_inputs(window_only))
+}
+
+/// Returns the inputs sent by [`print_screen`].
+///
+/// [`print_screen`]: fn.print_screen.html
+fn print_screen_inputs(window_only: bool) -> Vec<Input> {
+    let modifiers: &[Vk] = if window_only { &[Vk::Alt] } else { &[] };
+    Input::combo(modifiers, Vk::PrintScreen)
+}
+
+/// Synthesizes a `Ctrl+Break` keystroke.
+///
+/// The function returns the number of inputs that were successfully inserted into the
+/// keyboard input stream.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// winput::ctrl_break();
+/// ```
+pub fn ctrl_break() -> u32 {
+    send_inputs(ctrl_break_inputs())
+}
+
+/// Returns the inputs sent by [`ctrl_break`].
+///
+/// [`ctrl_break`]: fn.ctrl_break.html
+fn ctrl_break_inputs() -> Vec<Input> {
+    Input::combo(&[Vk::Control], Vk::Cancel)
+}
+
+/// Synthesizes a release event for every key and mouse button that is currently held
+/// down.
+///
+/// This is useful to clean up after an automation script that was interrupted in the
+/// middle of a sequence, which could leave modifier keys such as `Ctrl` or `Shift`
+/// stuck. The state of the keys is retrieved using the native `GetAsyncKeyState`
+/// function (see [`Vk::is_down`]), which reflects the state of the whole system rather
+/// than the state seen by the calling thread.
+///
+/// The function returns the number of inputs that were successfully inserted into the
+/// input stream.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// winput::release_all_keys();
+/// ```
+///
+/// [`Vk::is_down`]: enum.Vk.html#method.is_down
+pub fn release_all_keys() -> u32 {
+    let inputs: Vec<Input> = Vk::ALL
+        .iter()
+        .copied()
+        .filter(|&vk| match vk {
+            // The sided variants of those keys are released instead.
+            Vk::Shift | Vk::Control | Vk::Alt => false,
+            _ => !vk.is_gamepad() && vk.is_down(),
+        })
+        .map(|vk| match Button::from_vk(vk) {
+            Some(button) => Input::from_button(button, Action::Release),
+            None => Input::from_vk(vk, Action::Release),
+        })
+        .collect();
+
+    if inputs.is_empty() {
+        return 0;
+    }
+
+    send_inputs(&inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use winapi::um::winuser::KEYEVENTF_EXTENDEDKEY;
+
+    /// The key, the scan code, whether the key is extended and the action of an input.
+    type Key = (Option<Vk>, Option<u16>, bool, Option<Action>);
+
+    /// Describes every input.
+    fn keys(inputs: &[Input]) -> Vec<Key> {
+        inputs
+            .iter()
+            .map(|input| {
+                (
+                    input.vk(),
+                    input.scan_code(),
+                    input.flags() & KEYEVENTF_EXTENDEDKEY != 0,
+                    input.action(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn print_screen_of_the_screen() {
+        assert_eq!(
+            keys(&print_screen_inputs(false)),
+            [
+                (Some(Vk::PrintScreen), Some(0x37), true, Some(Action::Press)),
+                (
+                    Some(Vk::PrintScreen),
+                    Some(0x37),
+                    true,
+                    Some(Action::Release)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn print_screen_of_the_active_window() {
+        assert_eq!(
+            keys(&print_screen_inputs(true)),
+            [
+                (Some(Vk::Alt), Some(0), false, Some(Action::Press)),
+                (Some(Vk::PrintScreen), Some(0x37), true, Some(Action::Press)),
+                (
+                    Some(Vk::PrintScreen),
+                    Some(0x37),
+                    true,
+                    Some(Action::Release)
+                ),
+                (Some(Vk::Alt), Some(0), false, Some(Action::Release)),
+            ]
+        );
+    }
+
+    #[test]
+    fn ctrl_break_sequence() {
+        assert_eq!(
+            keys(&ctrl_break_inputs()),
+            [
+                (Some(Vk::Control), Some(0), false, Some(Action::Press)),
+                (Some(Vk::Cancel), Some(0x46), true, Some(Action::Press)),
+                (Some(Vk::Cancel), Some(0x46), true, Some(Action::Release)),
+                (Some(Vk::Control), Some(0), false, Some(Action::Release)),
+            ]
+        );
+    }
 }
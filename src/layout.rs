@@ -0,0 +1,345 @@
+use crate::modifiers::Modifiers;
+use crate::vk::{KeyClass, Vk};
+
+use std::ptr;
+
+use winapi::shared::minwindef::HKL;
+use winapi::um::winuser;
+
+/// A keyboard layout (also called an input locale identifier).
+///
+/// Keyboard layouts define which character is produced by each key. Functions that
+/// translate between characters and keys use the layout of the calling thread unless a
+/// [`KeyboardLayout`] is explicitly provided.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::{KeyboardLayout, Vk};
+///
+/// let layout = KeyboardLayout::foreground();
+/// println!("{:?}", Vk::from_char_with_layout('é', layout));
+/// ```
+///
+/// [`KeyboardLayout`]: struct.KeyboardLayout.html
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct KeyboardLayout(HKL);
+
+// SAFETY: A `HKL` is an identifier that is valid for the whole system. It does not
+// point to any memory owned by the process.
+unsafe impl Send for KeyboardLayout {}
+unsafe impl Sync for KeyboardLayout {}
+
+impl KeyboardLayout {
+    /// Retrieves the keyboard layout of the calling thread.
+    #[inline]
+    pub fn current() -> Self {
+        // Calling C code
+        Self(unsafe { winuser::GetKeyboardLayout(0) })
+    }
+
+    /// Retrieves the keyboard layout of the thread that created the foreground window.
+    ///
+    /// This is the layout that is used to interpret synthesized keystrokes, which may
+    /// differ from the layout of the calling thread.
+    pub fn foreground() -> Self {
+        unsafe {
+            // Calling C code
+            let hwnd = winuser::GetForegroundWindow();
+            if hwnd.is_null() {
+                return Self::current();
+            }
+
+            let thread_id = winuser::GetWindowThreadProcessId(hwnd, ptr::null_mut());
+            Self(winuser::GetKeyboardLayout(thread_id))
+        }
+    }
+
+    /// Creates a [`KeyboardLayout`] from a raw `HKL` handle.
+    ///
+    /// [`KeyboardLayout`]: struct.KeyboardLayout.html
+    #[inline(always)]
+    pub fn from_raw(hkl: HKL) -> Self {
+        Self(hkl)
+    }
+
+    /// Returns the raw `HKL` handle of this layout.
+    #[inline(always)]
+    pub fn as_raw(self) -> HKL {
+        self.0
+    }
+
+    /// Returns the language identifier of this layout (the low word of the handle).
+    #[inline(always)]
+    pub fn language_id(self) -> u16 {
+        self.0 as usize as u16
+    }
+
+    /// Retrieves the characters produced by the given key on this layout, with and
+    /// without SHIFT.
+    ///
+    /// This is mostly useful for OEM keys (such as `Vk::Oem1`), whose characters vary
+    /// from one layout to another.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{KeyboardLayout, Vk};
+    ///
+    /// let chars = KeyboardLayout::foreground().key_chars(Vk::Oem1);
+    /// println!("{:?} / {:?}", chars.normal, chars.shifted);
+    /// ```
+    pub fn key_chars(self, vk: Vk) -> KeyChars {
+        KeyChars {
+            normal: vk.to_char_with_layout(Modifiers::empty(), self),
+            shifted: vk.to_char_with_layout(Modifiers::SHIFT, self),
+        }
+    }
+
+    /// Returns an iterator over the OEM keys that produce a character on this layout,
+    /// alongside the characters they produce.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::KeyboardLayout;
+    ///
+    /// for (vk, chars) in KeyboardLayout::current().oem_keys() {
+    ///     println!("{}: {:?} / {:?}", vk, chars.normal, chars.shifted);
+    /// }
+    /// ```
+    pub fn oem_keys(self) -> impl Iterator<Item = (Vk, KeyChars)> {
+        Vk::ALL
+            .iter()
+            .filter(|vk| vk.class() == KeyClass::Oem)
+            .map(move |&vk| (vk, self.key_chars(vk)))
+            .filter(|(_, chars)| chars.normal.is_some() || chars.shifted.is_some())
+    }
+
+    /// Returns the dead keys of this layout.
+    ///
+    /// A dead key does not produce a character by itself, but modifies the character
+    /// produced by the next key: on many European layouts, `´` followed by `e` produces
+    /// `é`. Only the keys pressed alone, with SHIFT, with ALT GR (CTRL and ALT) and with
+    /// both are considered.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::KeyboardLayout;
+    ///
+    /// for dead_key in KeyboardLayout::foreground().dead_keys() {
+    ///     println!("{:?}", dead_key);
+    /// }
+    /// ```
+    pub fn dead_keys(self) -> Vec<DeadKey> {
+        let mut dead_keys = Vec::new();
+
+        for &vk in Vk::ALL.iter() {
+            for &modifiers in DEAD_KEY_MODIFIERS.iter() {
+                let (len, buffer) = self.to_unicode(vk, modifiers, false);
+                if len >= 0 {
+                    continue;
+                }
+
+                if let Some(Ok(character)) =
+                    std::char::decode_utf16(buffer[..1].iter().copied()).next()
+                {
+                    dead_keys.push(DeadKey {
+                        vk,
+                        modifiers,
+                        character,
+                    });
+                }
+            }
+        }
+
+        dead_keys
+    }
+
+    /// Checks whether the given key, pressed with the given modifiers, is a dead key on
+    /// this layout (see [`KeyboardLayout::dead_keys`]).
+    ///
+    /// [`KeyboardLayout::dead_keys`]: struct.KeyboardLayout.html#method.dead_keys
+    #[inline]
+    pub fn is_dead_key(self, vk: Vk, modifiers: Modifiers) -> bool {
+        self.to_unicode(vk, modifiers, false).0 < 0
+    }
+
+    /// Retrieves the character produced when the given key is pressed after the given
+    /// dead key. `None` is returned if they do not combine into a single character.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{KeyboardLayout, Modifiers, Vk};
+    ///
+    /// let layout = KeyboardLayout::foreground();
+    /// for dead_key in layout.dead_keys() {
+    ///     println!("{:?}", layout.compose(dead_key, Vk::E, Modifiers::empty()));
+    /// }
+    /// ```
+    pub fn compose(
+        self,
+        dead_key: DeadKey,
+        vk: Vk,
+        modifiers: Modifiers,
+    ) -> Option<char> {
+        // The dead key is stored in the keyboard state of the calling thread, and is
+        // consumed by the next key.
+        self.to_unicode(dead_key.vk, dead_key.modifiers, true);
+        let (len, buffer) = self.to_unicode(vk, modifiers, true);
+
+        if len != 1 {
+            // The next key may be a dead key as well, which must not remain in the
+            // keyboard state.
+            if len < 0 {
+                self.to_unicode(Vk::Space, Modifiers::empty(), true);
+            }
+            return None;
+        }
+
+        std::char::decode_utf16(buffer[..1].iter().copied())
+            .next()
+            .and_then(Result::ok)
+    }
+
+    /// Finds a dead key and a key that combine into the given character on this layout.
+    /// The keys are looked up among the given dead keys, and the letters and the space
+    /// bar, with and without SHIFT.
+    ///
+    /// Returns the dead key and the key to press after it, with its modifiers.
+    #[cfg(not(feature = "minimal"))]
+    pub(crate) fn find_composition(
+        self,
+        dead_keys: &[DeadKey],
+        c: char,
+    ) -> Option<(DeadKey, Vk, Modifiers)> {
+        let bases = Vk::ALL
+            .iter()
+            .copied()
+            .filter(|vk| vk.class() == KeyClass::Letter)
+            .flat_map(|vk| {
+                std::iter::once((vk, Modifiers::empty()))
+                    .chain(std::iter::once((vk, Modifiers::SHIFT)))
+            })
+            .chain(std::iter::once((Vk::Space, Modifiers::empty())));
+
+        let bases: Vec<(Vk, Modifiers)> = bases.collect();
+
+        dead_keys.iter().find_map(|&dead_key| {
+            bases
+                .iter()
+                .find(|&&(vk, modifiers)| {
+                    self.compose(dead_key, vk, modifiers) == Some(c)
+                })
+                .map(|&(vk, modifiers)| (dead_key, vk, modifiers))
+        })
+    }
+
+    /// Calls the native `ToUnicodeEx` function for the given key pressed with the given
+    /// modifiers, and returns its result along with the UTF-16 code units it wrote.
+    ///
+    /// Unless `change_state` is set, the keyboard state of the calling thread (which
+    /// stores pending dead keys) is not modified.
+    pub(crate) fn to_unicode(
+        self,
+        vk: Vk,
+        modifiers: Modifiers,
+        change_state: bool,
+    ) -> (i32, [u16; 8]) {
+        const DOWN: u8 = 0x80;
+
+        let mut state = [0u8; 256];
+        for key in modifiers.keys() {
+            // Generic keys are seen as their left key, and sided keys must also mark
+            // their generic key as down.
+            let sided = if key == key.to_generic() {
+                key.left_variant().unwrap_or(key)
+            } else {
+                key
+            };
+
+            state[key.to_generic() as usize] = DOWN;
+            state[sided as usize] = DOWN;
+        }
+
+        // Calling C code
+        let scan_code = unsafe {
+            winuser::MapVirtualKeyExW(vk.into(), winuser::MAPVK_VK_TO_VSC, self.0)
+        };
+
+        self.to_unicode_with_state(vk, scan_code, &state, change_state)
+    }
+
+    /// Calls the native `ToUnicodeEx` function for the given key and keyboard state
+    /// (one byte per virtual-key code, as returned by `GetKeyboardState`).
+    ///
+    /// See [`KeyboardLayout::to_unicode`] for more information.
+    ///
+    /// [`KeyboardLayout::to_unicode`]: struct.KeyboardLayout.html#method.to_unicode
+    pub(crate) fn to_unicode_with_state(
+        self,
+        vk: Vk,
+        scan_code: u32,
+        state: &[u8; 256],
+        change_state: bool,
+    ) -> (i32, [u16; 8]) {
+        /// Tells `ToUnicodeEx` not to change the keyboard state (dead keys).
+        const NO_STATE_CHANGE: u32 = 0x04;
+
+        let flags = if change_state { 0 } else { NO_STATE_CHANGE };
+        let mut buffer = [0u16; 8];
+
+        // Calling C code
+        let len = unsafe {
+            winuser::ToUnicodeEx(
+                vk.into(),
+                scan_code,
+                state.as_ptr(),
+                buffer.as_mut_ptr(),
+                buffer.len() as _,
+                flags,
+                self.0,
+            )
+        };
+
+        (len, buffer)
+    }
+}
+
+/// The modifiers with which dead keys are looked up: none, SHIFT, ALT GR and both.
+const DEAD_KEY_MODIFIERS: [Modifiers; 4] = [
+    Modifiers::empty(),
+    Modifiers::SHIFT,
+    Modifiers::from_bits_truncate(Modifiers::CONTROL.bits() | Modifiers::ALT.bits()),
+    Modifiers::from_bits_truncate(
+        Modifiers::SHIFT.bits() | Modifiers::CONTROL.bits() | Modifiers::ALT.bits(),
+    ),
+];
+
+/// A dead key of a keyboard layout (see [`KeyboardLayout::dead_keys`]).
+///
+/// [`KeyboardLayout::dead_keys`]: struct.KeyboardLayout.html#method.dead_keys
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct DeadKey {
+    /// The Virtual-Key Code of the dead key.
+    pub vk: Vk,
+    /// The modifiers that must be held down for the key to be a dead key.
+    pub modifiers: Modifiers,
+    /// The character of the dead key itself, such as `'´'`.
+    pub character: char,
+}
+
+/// The characters produced by a key on a given keyboard layout.
+///
+/// This structure is returned by [`KeyboardLayout::key_chars`].
+///
+/// [`KeyboardLayout::key_chars`]: struct.KeyboardLayout.html#method.key_chars
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct KeyChars {
+    /// The character produced when the key is pressed alone.
+    pub normal: Option<char>,
+    /// The character produced when the key is pressed while SHIFT is held down.
+    pub shifted: Option<char>,
+}
@@ -2,18 +2,63 @@ mod error;
 pub use error::WindowsError;
 
 mod vk;
-pub use vk::Vk;
+pub use vk::{KeyClass, ParseVkError, TryFromVkError, Vk};
+
+mod layout;
+pub use layout::{DeadKey, KeyChars, KeyboardLayout};
+
+mod modifiers;
+pub use modifiers::Modifiers;
+
+mod shortcut;
+pub use shortcut::{ParseShortcutError, Shortcut};
 
 mod input;
-pub use input::{send_inputs, Action, Button, Input, MouseMotion, WheelDirection};
+pub use input::{
+    injection_tag, is_injection_tag, is_sending_enabled, send_inputs,
+    send_inputs_with_pacing, set_injection_tag, set_sending_enabled, try_send_inputs,
+    Action, Button, Input, InputKind, KeyInput, MouseMotion, SendInputsError,
+    WheelDirection,
+};
+
+#[cfg(not(feature = "minimal"))]
+mod clipboard;
 
 #[cfg(not(feature = "minimal"))]
 mod keylike;
 #[cfg(not(feature = "minimal"))]
-pub use keylike::{press, release, send, send_keys, send_str, Keylike};
+pub use keylike::{
+    ctrl_break, hold, press, print_screen, release, release_all_keys, send, send_keys,
+    send_keys_cancellable, send_keys_chunked, send_repeat, send_str,
+    send_str_cancellable, send_str_paste, send_str_vk, send_str_with,
+    send_str_with_delay, try_press, try_release, try_send, with_held, HeldKeys, KeyHold,
+    Keylike, LayoutChar, StrOptions,
+};
+
+#[cfg(not(feature = "minimal"))]
+mod sequence;
+#[cfg(not(feature = "minimal"))]
+pub use sequence::InputSequence;
+#[cfg(not(feature = "minimal"))]
+#[doc(hidden)]
+pub use sequence::SequenceLiteral;
+
+mod path;
+pub use path::{Easing, MousePath};
 
 mod mouse;
-pub use mouse::Mouse;
+#[cfg(feature = "message_loop")]
+pub use mouse::RelativeCapture;
+pub use mouse::{
+    ClipGuard, CursorGuard, LogicalButton, Mouse, MouseAcceleration, MouseSettingsGuard,
+    PositionGuard,
+};
 
 #[cfg(feature = "message_loop")]
 pub mod message_loop;
+
+pub mod window;
+
+mod uia;
+
+pub mod accessibility;
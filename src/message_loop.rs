@@ -2,7 +2,8 @@
 //! input messages directly from the system.
 //!
 //! Internally, a [message-only window](https://docs.microsoft.com/en-us/windows/win32/winmsg/window-features#message-only-windows)
-//! is created to receive the messages.
+//! is created to receive the messages. The window is shared by every receiver created
+//! with [`start`], and each receiver gets its own copy of the events.
 //!
 //! ## Examples
 //!
@@ -12,8 +13,8 @@
 //!
 //! let receiver = message_loop::start().unwrap();
 //!
-//! loop {
-//!     match receiver.next_event() {
+//! while let Ok(event) = receiver.next_event() {
+//!     match event {
 //!         message_loop::Event::Keyboard {
 //!             vk,
 //!             action: Action::Press,
@@ -29,19 +30,30 @@
 //!     }
 //! }
 //! ```
+//!
+//! [`start`]: fn.start.html
 
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::error::Error;
 use std::ffi::OsStr;
 use std::mem::MaybeUninit;
 use std::os::windows::ffi::OsStrExt;
-use std::sync::atomic::{AtomicU8, Ordering};
-use std::sync::mpsc;
-use std::time::Duration;
-use std::{iter, mem, ptr};
+use std::sync::atomic::{
+    AtomicBool, AtomicI32, AtomicU64, AtomicU8, AtomicUsize, Ordering,
+};
+use std::sync::{mpsc, Arc, Condvar, Mutex, MutexGuard, Once};
+#[cfg(feature = "async")]
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+use std::{fmt, iter, mem, ptr};
 
 use winapi::shared::{hidusage, minwindef, windef};
-use winapi::um::{libloaderapi, winuser};
+use winapi::um::{errhandlingapi, libloaderapi, winnls, winnt, winuser};
 
-use crate::input::{Action, Button};
+use crate::input::{is_injection_tag, Action, Button, Input, MouseMotion};
+use crate::layout::{DeadKey, KeyboardLayout};
+use crate::modifiers::Modifiers;
 use crate::vk::Vk;
 use crate::{WheelDirection, WindowsError};
 
@@ -54,14 +66,682 @@ use crate::{WheelDirection, WindowsError};
 /// * 3 -> The message loop is now exiting.
 static STATE: AtomicU8 = AtomicU8::new(0);
 
-// This value initialized if `STATE` is `2`. It is uninitialized if `STATE` is `0`.
-// `SENDER` must only be used on the message loop's thread.
-static mut SENDER: MaybeUninit<mpsc::Sender<Event>> = MaybeUninit::uninit();
+/// The handle of the message-only window. This value is only meaningful if `STATE`
+/// is `2`.
+static HWND: AtomicUsize = AtomicUsize::new(0);
+
+/// The identifier given to the next subscriber.
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// The error code used by Windows when a window class is registered twice.
+const ERROR_CLASS_ALREADY_EXISTS: u32 = 1410;
+
+/// The message posted to the message loop's window when the devices it listens to
+/// have to be updated.
+const WM_UPDATE_DEVICES: minwindef::UINT = winuser::WM_APP;
+
+/// The events waiting in a `Channel`.
+#[derive(Debug)]
+struct Queue {
+    events: VecDeque<Event>,
+    /// Whether the `Subscriber` that sends the events is still alive.
+    connected: bool,
+    /// Signals `Channel::ready` when events are sent. It is dropped when the channel
+    /// is disconnected.
+    #[cfg(feature = "crossbeam")]
+    ready: Option<crossbeam_channel::Sender<()>>,
+}
+
+/// The reason why no event could be received from a `Channel`.
+enum RecvError {
+    /// No event is waiting in the channel.
+    Empty,
+    /// No event is waiting and the message loop won't send any more.
+    Disconnected,
+}
+
+/// The channel through which a `Subscriber` sends events to its `EventReceiver`.
+#[derive(Debug)]
+struct Channel {
+    queue: Mutex<Queue>,
+    /// Notified when an event is sent or the channel is disconnected.
+    available: Condvar,
+    capacity: Option<usize>,
+    overflow: Overflow,
+    /// The number of events that were discarded because the channel was full.
+    dropped: AtomicU64,
+    /// The task waiting for the next event, if any.
+    #[cfg(feature = "async")]
+    waker: Mutex<Option<Waker>>,
+    /// Receives a message when events are waiting in the channel.
+    #[cfg(feature = "crossbeam")]
+    ready: crossbeam_channel::Receiver<()>,
+}
+
+impl Channel {
+    /// Creates an empty channel with the given capacity and overflow policy.
+    fn new(capacity: Option<usize>, overflow: Overflow) -> Self {
+        // A single message is enough to wake the receiving end up.
+        #[cfg(feature = "crossbeam")]
+        let (ready_sender, ready) = crossbeam_channel::bounded(1);
+
+        Self {
+            queue: Mutex::new(Queue {
+                events: VecDeque::new(),
+                connected: true,
+                #[cfg(feature = "crossbeam")]
+                ready: Some(ready_sender),
+            }),
+            available: Condvar::new(),
+            capacity,
+            overflow,
+            dropped: AtomicU64::new(0),
+            #[cfg(feature = "async")]
+            waker: Mutex::new(None),
+            #[cfg(feature = "crossbeam")]
+            ready,
+        }
+    }
+
+    /// Locks the queue of the channel. The queue is still usable if a thread panicked
+    /// while holding the lock.
+    fn lock(&self) -> MutexGuard<'_, Queue> {
+        self.queue
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Adds an event to the channel, applying the overflow policy if it is full.
+    fn send(&self, event: Event) {
+        let mut queue = self.lock();
+
+        let full =
+            matches!(self.capacity, Some(capacity) if queue.events.len() >= capacity);
+
+        if full {
+            if self.overflow == Overflow::CoalesceMoves {
+                if let Some(last) = queue.events.back_mut() {
+                    if last.coalesce(&event) {
+                        // The receiver already has events to take.
+                        return;
+                    }
+                }
+            }
+
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+
+            // A channel without capacity can't make room for the new event.
+            if self.overflow == Overflow::DropNewest || queue.events.pop_front().is_none()
+            {
+                return;
+            }
+        }
+
+        queue.events.push_back(event);
+
+        // The message is already waiting if the channel is full.
+        #[cfg(feature = "crossbeam")]
+        if let Some(ready) = &queue.ready {
+            let _ = ready.try_send(());
+        }
+
+        drop(queue);
+        self.notify();
+    }
+
+    /// Tells the receiving end that the message loop won't send any more events.
+    fn disconnect(&self) {
+        let mut queue = self.lock();
+        queue.connected = false;
+        #[cfg(feature = "crossbeam")]
+        {
+            queue.ready = None;
+        }
+
+        drop(queue);
+        self.notify();
+    }
+
+    /// Wakes up the thread or the task waiting for the next event.
+    fn notify(&self) {
+        self.available.notify_all();
+
+        #[cfg(feature = "async")]
+        {
+            let waker = self.waker.lock().unwrap().take();
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+    }
+
+    /// Takes the next event of the channel without blocking.
+    fn try_recv(&self) -> Result<Event, RecvError> {
+        let mut queue = self.lock();
+
+        match queue.events.pop_front() {
+            Some(event) => Ok(event),
+            None if queue.connected => Err(RecvError::Empty),
+            None => Err(RecvError::Disconnected),
+        }
+    }
+
+    /// Blocks the current thread until an event is received or the given deadline is
+    /// reached. Without a deadline, `RecvError::Empty` is never returned.
+    fn recv_deadline(&self, deadline: Option<Instant>) -> Result<Event, RecvError> {
+        let mut queue = self.lock();
+
+        loop {
+            if let Some(event) = queue.events.pop_front() {
+                return Ok(event);
+            } else if !queue.connected {
+                return Err(RecvError::Disconnected);
+            }
+
+            queue = match deadline {
+                None => self
+                    .available
+                    .wait(queue)
+                    .unwrap_or_else(|poisoned| poisoned.into_inner()),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(RecvError::Empty);
+                    }
+
+                    self.available
+                        .wait_timeout(queue, deadline - now)
+                        .unwrap_or_else(|poisoned| poisoned.into_inner())
+                        .0
+                }
+            };
+        }
+    }
+}
+
+/// A function that tells whether a subscriber wants to receive an event.
+type Filter = Box<dyn Fn(&Event) -> bool + Send>;
+
+/// A receiver of the events of the message loop.
+struct Subscriber {
+    id: u64,
+    options: Options,
+    filter: Option<Filter>,
+    /// The channel shared with the `EventReceiver`.
+    channel: Arc<Channel>,
+}
+
+impl Subscriber {
+    /// Checks whether the subscriber is interested in the given event. `background`
+    /// indicates whether the event occured while the application was not in the
+    /// foreground.
+    fn accepts(&self, event: &Event, background: bool) -> bool {
+        let device = match *event {
+            Event::Keyboard { .. } => self.options.keyboard && !self.options.raw,
+            Event::Char { .. } => self.options.keyboard && self.options.chars,
+            Event::Hid {
+                usage_page, usage, ..
+            } => self.options.hid.contains(&(usage_page, usage)),
+            Event::Raw {
+                raw: RawEvent::Keyboard(_),
+                ..
+            } => self.options.keyboard && self.options.raw,
+            Event::Raw {
+                raw: RawEvent::Mouse(_),
+                ..
+            } => self.options.mouse && self.options.raw,
+            _ => self.options.mouse && !self.options.raw,
+        };
+
+        if !device || (background && !self.options.background) {
+            return false;
+        }
+
+        match &self.filter {
+            Some(filter) => filter(event),
+            None => true,
+        }
+    }
+
+    /// Sends an event to the subscriber. `false` is returned if its `EventReceiver` was
+    /// dropped.
+    fn send(&self, event: Event) -> bool {
+        if Arc::strong_count(&self.channel) == 1 {
+            return false;
+        }
+
+        self.channel.send(event);
+        true
+    }
+}
+
+impl Drop for Subscriber {
+    fn drop(&mut self) {
+        // The `EventReceiver` must notice that no more events will be sent.
+        self.channel.disconnect();
+    }
+}
+
+/// Returns the list of the subscribers of the message loop.
+///
+/// The list is shared between the message loop's thread, which sends the events to
+/// every subscriber, and the threads that start and stop the message loop.
+fn subscribers() -> &'static Mutex<Vec<Subscriber>> {
+    static INIT: Once = Once::new();
+    static mut SUBSCRIBERS: MaybeUninit<Mutex<Vec<Subscriber>>> = MaybeUninit::uninit();
+
+    unsafe {
+        INIT.call_once(|| SUBSCRIBERS = MaybeUninit::new(Mutex::new(Vec::new())));
+
+        // SAFETY:
+        // `SUBSCRIBERS` was initialized by the `call_once` function.
+        &*SUBSCRIBERS.as_ptr()
+    }
+}
+
+/// Returns the condition variable notified when the message loop's thread has exited.
+/// It is used along with the lock of the list of the subscribers.
+fn stopped() -> &'static Condvar {
+    static INIT: Once = Once::new();
+    static mut STOPPED: MaybeUninit<Condvar> = MaybeUninit::uninit();
+
+    unsafe {
+        INIT.call_once(|| STOPPED = MaybeUninit::new(Condvar::new()));
+
+        // SAFETY:
+        // `STOPPED` was initialized by the `call_once` function.
+        &*STOPPED.as_ptr()
+    }
+}
+
+/// Locks the list of the subscribers. The list is still usable if a thread panicked
+/// while holding the lock.
+fn lock_subscribers() -> MutexGuard<'static, Vec<Subscriber>> {
+    subscribers()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Blocks the current thread until the message loop is not active anymore, given the
+/// locked list of the subscribers. The lock is released while waiting, so that the
+/// message loop's thread can exit.
+fn wait_until_stopped(
+    mut subscribers: MutexGuard<'static, Vec<Subscriber>>,
+) -> MutexGuard<'static, Vec<Subscriber>> {
+    while STATE.load(Ordering::SeqCst) != 0 {
+        subscribers = stopped()
+            .wait(subscribers)
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+    }
+
+    subscribers
+}
+
+/// Sends an event to every subscriber interested in it, forgetting the ones that were
+/// dropped.
+fn dispatch(event: Event, background: bool) {
+    lock_subscribers().retain(|subscriber| {
+        if !subscriber.accepts(&event, background) {
+            return true;
+        }
+
+        let mut event = event.clone();
+        if subscriber.options.sided_modifiers {
+            event.resolve_modifier_side();
+        }
+
+        subscriber.send(event)
+    });
+}
+
+/// The raw input devices the message loop listens to.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Devices {
+    keyboard: bool,
+    mouse: bool,
+    /// The usage pages and usages of the other HID devices.
+    hid: Vec<(u16, u16)>,
+    background: bool,
+}
+
+impl Devices {
+    /// Returns the devices needed by a subscriber created with the given options.
+    fn of(options: &Options) -> Self {
+        Self {
+            keyboard: options.keyboard,
+            mouse: options.mouse,
+            hid: options.hid.clone(),
+            background: options.background,
+        }
+    }
+
+    /// Returns the devices needed by the given subscribers.
+    fn needed_by(subscribers: &[Subscriber]) -> Self {
+        let mut devices = Self::default();
+
+        for options in subscribers.iter().map(|subscriber| &subscriber.options) {
+            devices.keyboard |= options.keyboard;
+            devices.mouse |= options.mouse;
+            devices.background |= options.background;
+
+            for usage in options.hid.iter() {
+                if !devices.hid.contains(usage) {
+                    devices.hid.push(*usage);
+                }
+            }
+        }
+
+        devices
+    }
+
+    /// Returns the usage page, the usage and the registration flags of every device.
+    fn usages(&self) -> Vec<(u16, u16, u32)> {
+        let sink = if self.background {
+            winuser::RIDEV_INPUTSINK
+        } else {
+            0
+        };
+
+        let mut usages = Vec::with_capacity(self.hid.len() + 2);
+
+        // `RIDEV_NOLEGACY` is only valid for keyboards and mice.
+        if self.keyboard {
+            usages.push((
+                hidusage::HID_USAGE_PAGE_GENERIC,
+                hidusage::HID_USAGE_GENERIC_KEYBOARD,
+                winuser::RIDEV_NOLEGACY | sink,
+            ));
+        }
+        if self.mouse {
+            usages.push((
+                hidusage::HID_USAGE_PAGE_GENERIC,
+                hidusage::HID_USAGE_GENERIC_MOUSE,
+                winuser::RIDEV_NOLEGACY | sink,
+            ));
+        }
+        usages.extend(self.hid.iter().map(|&(page, usage)| (page, usage, sink)));
+
+        usages
+    }
+
+    /// Tells the system which devices the given window wants to receive inputs from.
+    /// The devices of `previous` that are not needed anymore are unregistered.
+    unsafe fn register(
+        &self,
+        previous: &Self,
+        hwnd: windef::HWND,
+    ) -> Result<(), WindowsError> {
+        let usages = self.usages();
+
+        let mut rid: Vec<winuser::RAWINPUTDEVICE> = Vec::with_capacity(usages.len());
+        for &(page, usage, flags) in usages.iter() {
+            let mut device: winuser::RAWINPUTDEVICE = mem::zeroed();
+            device.usUsagePage = page;
+            device.usUsage = usage;
+            device.dwFlags = flags;
+            device.hwndTarget = hwnd;
+            rid.push(device);
+        }
+
+        for &(page, usage, _) in previous.usages().iter() {
+            if !usages.iter().any(|&(p, u, _)| (p, u) == (page, usage)) {
+                let mut device: winuser::RAWINPUTDEVICE = mem::zeroed();
+                device.usUsagePage = page;
+                device.usUsage = usage;
+                device.dwFlags = winuser::RIDEV_REMOVE;
+                rid.push(device);
+            }
+        }
+
+        if rid.is_empty() {
+            return Ok(());
+        }
+
+        let result = winuser::RegisterRawInputDevices(
+            rid.as_ptr(),
+            rid.len() as _,
+            mem::size_of::<winuser::RAWINPUTDEVICE>() as _,
+        );
+
+        if result == 0 {
+            Err(WindowsError::from_last_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Asks the message loop to update the devices it listens to.
+fn update_devices() {
+    let hwnd = HWND.load(Ordering::SeqCst) as windef::HWND;
+    // Calling C code
+    unsafe { winuser::PostMessageW(hwnd, WM_UPDATE_DEVICES, 0, 0) };
+}
 
 /// A buffer that must only be used on the message loop's thread. This buffer must
 /// be properly initialized when the message loop's thread is started.
 static mut BUFFER: MaybeUninit<Vec<u8>> = MaybeUninit::uninit();
 
+/// The usage page and the usage of the HID devices that sent reports, so that they are
+/// only retrieved once. This list must only be used on the message loop's thread, and
+/// is initialized along with `BUFFER`.
+static mut HID_USAGES: MaybeUninit<Vec<(DeviceId, u16, u16)>> = MaybeUninit::uninit();
+
+/// Retrieves the usage page and the usage of a HID device. This function must only be
+/// called on the message loop's thread.
+unsafe fn hid_usage(device: DeviceId) -> Option<(u16, u16)> {
+    let cache = &mut *HID_USAGES.as_mut_ptr();
+
+    if let Some(&(_, page, usage)) = cache.iter().find(|&&(d, ..)| d == device) {
+        return Some((page, usage));
+    }
+
+    let info = device.raw_info().ok()?;
+    if info.dwType != winuser::RIM_TYPEHID {
+        return None;
+    }
+
+    let hid = info.u.hid();
+    cache.push((device, hid.usUsagePage, hid.usUsage));
+    Some((hid.usUsagePage, hid.usUsage))
+}
+
+/// The keys that are currently held down, one bit per virtual-key code. This set must
+/// only be used on the message loop's thread.
+static mut KEYS_DOWN: [u32; 8] = [0; 8];
+
+/// Whether CAPS LOCK is toggled on. This value must only be used on the message loop's
+/// thread.
+static mut CAPS_LOCK: bool = false;
+
+/// Records that the given action was taken on a key, and returns whether the event is
+/// an auto-repeated press of a key that was already held down. This function must only
+/// be called on the message loop's thread.
+unsafe fn update_key_state(vk: Vk, action: Action) -> bool {
+    let (index, bit) = (vk.into_u8() as usize / 32, 1 << (vk.into_u8() % 32));
+    let was_down = KEYS_DOWN[index] & bit != 0;
+
+    match action {
+        Action::Press => KEYS_DOWN[index] |= bit,
+        Action::Release => KEYS_DOWN[index] &= !bit,
+    }
+
+    let repeat = was_down && action == Action::Press;
+    if vk == Vk::CapsLock && action == Action::Press && !repeat {
+        CAPS_LOCK = !CAPS_LOCK;
+    }
+
+    repeat
+}
+
+/// The dead key that was pressed before the current key, if any. This value must only
+/// be used on the message loop's thread.
+static mut DEAD_KEY: Option<DeadKey> = None;
+
+/// Returns the modifiers that are currently held down. This function must only be
+/// called on the message loop's thread.
+unsafe fn modifiers_down() -> Modifiers {
+    let is_down =
+        |vk: Vk| KEYS_DOWN[vk.into_u8() as usize / 32] & (1 << (vk.into_u8() % 32)) != 0;
+
+    let mut modifiers = Modifiers::empty();
+    if is_down(Vk::Shift) {
+        modifiers |= Modifiers::SHIFT;
+    }
+    if is_down(Vk::Control) {
+        modifiers |= Modifiers::CONTROL;
+    }
+    if is_down(Vk::Alt) {
+        modifiers |= Modifiers::ALT;
+    }
+    if is_down(Vk::LeftWin) || is_down(Vk::RightWin) {
+        modifiers |= Modifiers::WIN;
+    }
+
+    modifiers
+}
+
+/// Combines the character of a dead key with the character typed after it, such as
+/// `'´'` and `'e'` into `'é'`. `None` is returned if they don't form a single
+/// character.
+fn combine(dead_key: char, base: char) -> Option<char> {
+    // The combining mark that corresponds to the spacing character of a dead key.
+    let mark = match dead_key {
+        '`' => '\u{300}',
+        '´' | '\'' => '\u{301}',
+        '^' | 'ˆ' => '\u{302}',
+        '~' | '˜' => '\u{303}',
+        '¯' => '\u{304}',
+        '˘' => '\u{306}',
+        '˙' => '\u{307}',
+        '¨' | '"' => '\u{308}',
+        '˚' | '°' => '\u{30a}',
+        '˝' => '\u{30b}',
+        'ˇ' => '\u{30c}',
+        '¸' => '\u{327}',
+        '˛' => '\u{328}',
+        _ => return None,
+    };
+
+    let mut source = [0u16; 3];
+    let len = base.encode_utf16(&mut source).len();
+    mark.encode_utf16(&mut source[len..]);
+
+    let mut buffer = [0u16; 8];
+
+    // Calling C code
+    let len = unsafe {
+        winnls::NormalizeString(
+            winnls::NormalizationC,
+            source.as_ptr(),
+            len as i32 + 1,
+            buffer.as_mut_ptr(),
+            buffer.len() as _,
+        )
+    };
+
+    if len <= 0 {
+        return None;
+    }
+
+    let mut chars = std::char::decode_utf16(buffer[..len as usize].iter().copied());
+    match (chars.next(), chars.next()) {
+        (Some(Ok(c)), None) => Some(c),
+        _ => None,
+    }
+}
+
+/// Computes the characters typed by a key press, given the keys that are currently held
+/// down. This function must only be called on the message loop's thread.
+///
+/// The pending dead key stored by the system is shared with the application the user
+/// is typing into, so it must not be changed: dead keys are tracked in `DEAD_KEY`
+/// instead.
+unsafe fn translate(vk: Vk, scan_code: u32) -> String {
+    const DOWN: u8 = 0x80;
+    const TOGGLED: u8 = 0x01;
+
+    let mut state = [0u8; 256];
+    for (code, byte) in state.iter_mut().enumerate() {
+        if KEYS_DOWN[code / 32] & (1 << (code % 32)) != 0 {
+            *byte = DOWN;
+        }
+    }
+    if CAPS_LOCK {
+        state[Vk::CapsLock.into_u8() as usize] |= TOGGLED;
+    }
+
+    // The prefix of extended scan codes is not expected by `ToUnicodeEx`.
+    let (len, buffer) = KeyboardLayout::foreground().to_unicode_with_state(
+        vk,
+        scan_code & 0xff,
+        &state,
+        false,
+    );
+
+    if len < 0 {
+        let character = std::char::decode_utf16(buffer[..1].iter().copied())
+            .next()
+            .and_then(Result::ok);
+
+        return match (DEAD_KEY.take(), character) {
+            // Two dead keys in a row type both of their characters.
+            (Some(previous), Some(character)) => {
+                [previous.character, character].iter().collect()
+            }
+            (_, Some(character)) => {
+                DEAD_KEY = Some(DeadKey {
+                    vk,
+                    modifiers: modifiers_down(),
+                    character,
+                });
+                String::new()
+            }
+            (_, None) => String::new(),
+        };
+    }
+
+    if len == 0 {
+        // Keys that type nothing (such as modifiers) leave the dead key pending.
+        return String::new();
+    }
+
+    let typed: String = std::char::decode_utf16(buffer[..len as usize].iter().copied())
+        .filter_map(Result::ok)
+        .collect();
+
+    let dead_key = match DEAD_KEY.take() {
+        Some(dead_key) => dead_key,
+        None => return typed,
+    };
+
+    let mut chars = typed.chars();
+    match (chars.next(), chars.next()) {
+        // The space bar types the character of the dead key alone.
+        (Some(' '), None) => dead_key.character.to_string(),
+        (Some(base), None) => match combine(dead_key.character, base) {
+            Some(combined) => combined.to_string(),
+            None => [dead_key.character, base].iter().collect(),
+        },
+        _ => format!("{}{}", dead_key.character, typed),
+    }
+}
+
+/// Checks whether at least one subscriber wants to receive `Event::Char` events.
+fn translating() -> bool {
+    lock_subscribers()
+        .iter()
+        .any(|subscriber| subscriber.options.chars)
+}
+
+/// Whether relative motions are being accumulated for a `RelativeCapture`.
+pub(crate) static CAPTURING: AtomicBool = AtomicBool::new(false);
+
+/// The relative motions of physical mice accumulated since the last call to
+/// `RelativeCapture::delta`. Synthesized motions are not accumulated.
+pub(crate) static CAPTURED_X: AtomicI32 = AtomicI32::new(0);
+pub(crate) static CAPTURED_Y: AtomicI32 = AtomicI32::new(0);
+
 /// Checks whether `short` contains all the bits of `mask`.
 #[inline]
 fn has_flags(short: u16, mask: u16) -> bool {
@@ -78,6 +758,10 @@ unsafe extern "system" fn window_proc(
     match msg {
         // Note: This loop is only here to break from the scope early.
         winuser::WM_INPUT => loop {
+            let background =
+                winuser::GET_RAWINPUT_CODE_WPARAM(w_param) == winuser::RIM_INPUTSINK;
+            let dispatch = |event| dispatch(event, background);
+
             // Determine how big should our buffer be.
             let mut size = 0;
             let mut result = winuser::GetRawInputData(
@@ -117,159 +801,265 @@ unsafe extern "system" fn window_proc(
             // The `GetRawInputData` function did not failed.
             let raw_input = &*(buffer.as_mut_ptr() as winuser::PRAWINPUT);
 
-            // SAFETY:
-            // We are on the message loop's thread, `SENDER` must be initialized.
-            let sender = &mut *SENDER.as_mut_ptr();
+            let device = DeviceId::from_raw(raw_input.header.hDevice);
+            let time = Instant::now();
 
             match raw_input.header.dwType {
                 winuser::RIM_TYPEMOUSE => {
                     // Mouse event
                     let data = raw_input.data.mouse();
+                    let origin = Origin::new(device, data.ulExtraInformation as usize);
+
+                    dispatch(Event::Raw {
+                        device,
+                        time,
+                        origin,
+                        raw: RawEvent::Mouse(RawMouse {
+                            flags: data.usFlags,
+                            button_flags: data.usButtonFlags,
+                            button_data: data.usButtonData,
+                            raw_buttons: data.ulRawButtons,
+                            last_x: data.lLastX,
+                            last_y: data.lLastY,
+                            extra_information: data.ulExtraInformation,
+                        }),
+                    });
+
+                    if !has_flags(data.usFlags, winuser::MOUSE_MOVE_ABSOLUTE)
+                        && origin == Origin::Device
+                        && CAPTURING.load(Ordering::Relaxed)
+                    {
+                        CAPTURED_X.fetch_add(data.lLastX, Ordering::Relaxed);
+                        CAPTURED_Y.fetch_add(data.lLastY, Ordering::Relaxed);
+                    }
 
                     if has_flags(data.usFlags, winuser::MOUSE_MOVE_RELATIVE) {
-                        sender
-                            .send(Event::MouseMoveRelative {
-                                x: data.lLastX,
-                                y: data.lLastY,
-                            })
-                            .unwrap();
+                        dispatch(Event::MouseMoveRelative {
+                            device,
+                            time,
+                            origin,
+                            x: data.lLastX,
+                            y: data.lLastY,
+                        });
                     }
 
                     if has_flags(data.usFlags, winuser::MOUSE_MOVE_ABSOLUTE) {
-                        sender
-                            .send(Event::MouseMoveAbsolute {
-                                x: data.lLastX as f32 / 65535.0,
-                                y: data.lLastY as f32 / 65535.0,
-                                virtual_desk: data.usFlags
-                                    & winuser::MOUSE_VIRTUAL_DESKTOP
-                                    == winuser::MOUSE_VIRTUAL_DESKTOP,
-                            })
-                            .unwrap();
+                        dispatch(Event::MouseMoveAbsolute {
+                            device,
+                            time,
+                            origin,
+                            x: data.lLastX as f32 / 65535.0,
+                            y: data.lLastY as f32 / 65535.0,
+                            virtual_desk: data.usFlags & winuser::MOUSE_VIRTUAL_DESKTOP
+                                == winuser::MOUSE_VIRTUAL_DESKTOP,
+                        });
                     }
 
                     if has_flags(data.usButtonFlags, winuser::RI_MOUSE_LEFT_BUTTON_DOWN) {
-                        sender
-                            .send(Event::MouseButton {
-                                action: Action::Press,
-                                button: Button::Left,
-                            })
-                            .unwrap();
+                        dispatch(Event::MouseButton {
+                            device,
+                            time,
+                            origin,
+                            action: Action::Press,
+                            button: Button::Left,
+                        });
                     }
 
                     if has_flags(data.usButtonFlags, winuser::RI_MOUSE_LEFT_BUTTON_UP) {
-                        sender
-                            .send(Event::MouseButton {
-                                action: Action::Release,
-                                button: Button::Left,
-                            })
-                            .unwrap();
+                        dispatch(Event::MouseButton {
+                            device,
+                            time,
+                            origin,
+                            action: Action::Release,
+                            button: Button::Left,
+                        });
                     }
 
                     if has_flags(data.usButtonFlags, winuser::RI_MOUSE_RIGHT_BUTTON_DOWN)
                     {
-                        sender
-                            .send(Event::MouseButton {
-                                action: Action::Press,
-                                button: Button::Right,
-                            })
-                            .unwrap();
+                        dispatch(Event::MouseButton {
+                            device,
+                            time,
+                            origin,
+                            action: Action::Press,
+                            button: Button::Right,
+                        });
                     }
 
                     if has_flags(data.usButtonFlags, winuser::RI_MOUSE_RIGHT_BUTTON_UP) {
-                        sender
-                            .send(Event::MouseButton {
-                                action: Action::Release,
-                                button: Button::Right,
-                            })
-                            .unwrap();
+                        dispatch(Event::MouseButton {
+                            device,
+                            time,
+                            origin,
+                            action: Action::Release,
+                            button: Button::Right,
+                        });
                     }
 
                     if has_flags(data.usButtonFlags, winuser::RI_MOUSE_MIDDLE_BUTTON_DOWN)
                     {
-                        sender
-                            .send(Event::MouseButton {
-                                action: Action::Press,
-                                button: Button::Middle,
-                            })
-                            .unwrap();
+                        dispatch(Event::MouseButton {
+                            device,
+                            time,
+                            origin,
+                            action: Action::Press,
+                            button: Button::Middle,
+                        });
                     }
 
                     if has_flags(data.usButtonFlags, winuser::RI_MOUSE_MIDDLE_BUTTON_UP) {
-                        sender
-                            .send(Event::MouseButton {
-                                action: Action::Release,
-                                button: Button::Middle,
-                            })
-                            .unwrap();
+                        dispatch(Event::MouseButton {
+                            device,
+                            time,
+                            origin,
+                            action: Action::Release,
+                            button: Button::Middle,
+                        });
                     }
 
                     if has_flags(data.usButtonFlags, winuser::RI_MOUSE_BUTTON_4_DOWN) {
-                        sender
-                            .send(Event::MouseButton {
-                                action: Action::Press,
-                                button: Button::X1,
-                            })
-                            .unwrap();
+                        dispatch(Event::MouseButton {
+                            device,
+                            time,
+                            origin,
+                            action: Action::Press,
+                            button: Button::X1,
+                        });
                     }
 
                     if has_flags(data.usButtonFlags, winuser::RI_MOUSE_BUTTON_4_UP) {
-                        sender
-                            .send(Event::MouseButton {
-                                action: Action::Release,
-                                button: Button::X1,
-                            })
-                            .unwrap();
+                        dispatch(Event::MouseButton {
+                            device,
+                            time,
+                            origin,
+                            action: Action::Release,
+                            button: Button::X1,
+                        });
                     }
 
                     if has_flags(data.usButtonFlags, winuser::RI_MOUSE_BUTTON_5_DOWN) {
-                        sender
-                            .send(Event::MouseButton {
-                                action: Action::Press,
-                                button: Button::X2,
-                            })
-                            .unwrap();
+                        dispatch(Event::MouseButton {
+                            device,
+                            time,
+                            origin,
+                            action: Action::Press,
+                            button: Button::X2,
+                        });
                     }
 
                     if has_flags(data.usButtonFlags, winuser::RI_MOUSE_BUTTON_5_UP) {
-                        sender
-                            .send(Event::MouseButton {
-                                action: Action::Release,
-                                button: Button::X2,
-                            })
-                            .unwrap();
+                        dispatch(Event::MouseButton {
+                            device,
+                            time,
+                            origin,
+                            action: Action::Release,
+                            button: Button::X2,
+                        });
                     }
 
                     if has_flags(data.usButtonFlags, winuser::RI_MOUSE_WHEEL) {
-                        sender
-                            .send(Event::MouseWheel {
-                                delta: data.usButtonData as i16 as f32 / 120.0,
-                                direction: WheelDirection::Vertical,
-                            })
-                            .unwrap();
+                        dispatch(Event::MouseWheel {
+                            device,
+                            time,
+                            origin,
+                            delta: data.usButtonData as i16 as f32 / 120.0,
+                            direction: WheelDirection::Vertical,
+                        });
                     }
 
                     if has_flags(data.usButtonFlags, 0x0800) {
-                        sender
-                            .send(Event::MouseWheel {
-                                delta: data.usButtonData as i16 as f32 / 120.0,
-                                direction: WheelDirection::Horizontal,
-                            })
-                            .unwrap();
+                        dispatch(Event::MouseWheel {
+                            device,
+                            time,
+                            origin,
+                            delta: data.usButtonData as i16 as f32 / 120.0,
+                            direction: WheelDirection::Horizontal,
+                        });
                     }
                 }
                 winuser::RIM_TYPEKEYBOARD => {
                     // Keyboard event
                     let data = raw_input.data.keyboard();
-
-                    sender
-                        .send(Event::Keyboard {
-                            vk: Vk::from_u8(data.VKey as u8),
-                            scan_code: data.MakeCode as u32,
-                            action: Action::from_press(data.Flags & 1 == 0),
-                        })
-                        .unwrap();
+                    let origin = Origin::new(device, data.ExtraInformation as usize);
+
+                    dispatch(Event::Raw {
+                        device,
+                        time,
+                        origin,
+                        raw: RawEvent::Keyboard(RawKeyboard {
+                            make_code: data.MakeCode,
+                            flags: data.Flags,
+                            vkey: data.VKey,
+                            message: data.Message,
+                            extra_information: data.ExtraInformation,
+                        }),
+                    });
+
+                    // Codes that are not known (such as the `0xff` code that the system
+                    // uses for fake keys) are ignored.
+                    if let Ok(vk) = Vk::try_from(data.VKey) {
+                        let action = Action::from_press(data.Flags & 1 == 0);
+
+                        // The prefix of extended scan codes goes in the high byte.
+                        let mut scan_code = data.MakeCode as u32;
+                        if data.Flags as u32 & winuser::RI_KEY_E0 != 0 {
+                            scan_code |= 0xe000;
+                        } else if data.Flags as u32 & winuser::RI_KEY_E1 != 0 {
+                            scan_code |= 0xe100;
+                        }
+
+                        dispatch(Event::Keyboard {
+                            device,
+                            time,
+                            origin,
+                            vk,
+                            scan_code,
+                            action,
+                            repeat: update_key_state(vk, action),
+                        });
+
+                        // The pending dead key is tracked by the message loop, so the
+                        // translation is done once and shared by every subscriber.
+                        if action == Action::Press && translating() {
+                            for character in translate(vk, scan_code).chars() {
+                                dispatch(Event::Char {
+                                    device,
+                                    time,
+                                    origin,
+                                    character,
+                                });
+                            }
+                        }
+                    }
+                }
+                winuser::RIM_TYPEHID => {
+                    // HID event
+                    let data = raw_input.data.hid();
+                    let origin = Origin::new(device, 0);
+
+                    let (usage_page, usage) = match hid_usage(device) {
+                        Some(usage) => usage,
+                        None => break,
+                    };
+
+                    // The input may contain several reports of the same size.
+                    let size = data.dwSizeHid as usize;
+                    let reports = std::slice::from_raw_parts(
+                        data.bRawData.as_ptr(),
+                        size * data.dwCount as usize,
+                    );
+
+                    for report in reports.chunks(size.max(1)) {
+                        dispatch(Event::Hid {
+                            device,
+                            time,
+                            origin,
+                            usage_page,
+                            usage,
+                            report: report.to_vec(),
+                        });
+                    }
                 }
-                2 => (),
                 _ => unreachable!("Invalid message"),
             }
 
@@ -287,17 +1077,18 @@ unsafe extern "system" fn window_proc(
 /// [`start`]: fn.start.html
 #[derive(Clone, Debug)]
 pub enum MessageLoopError {
-    /// Only one message loop can be created at any given time. This error
-    /// is produced when [`start`] is called even though the message loop
-    /// was already active.
+    /// A resource built on top of the message loop that can only be used once at any
+    /// given time, such as [`Mouse::capture_relative`], is already in use.
+    ///
+    /// [`Mouse::capture_relative`]: ../struct.Mouse.html#method.capture_relative
     AlreadyActive,
 
     /// Windows raised an error.
     OsError(WindowsError),
 }
 
-/// Checks if the message loop is currently active. When this function returns
-/// `true`, calling `start` always produces an error.
+/// Checks if the message loop is currently active, which means that at least one
+/// [`EventReceiver`] is alive.
 ///
 /// ## Examples
 ///
@@ -306,18 +1097,267 @@ pub enum MessageLoopError {
 /// assert!(winput::message_loop::is_active());
 ///
 /// ```
+///
+/// [`EventReceiver`]: struct.EventReceiver.html
 #[inline]
 pub fn is_active() -> bool {
     STATE.load(Ordering::Acquire) != 0
 }
 
-/// Starts the message loop on a new thread.
+/// Options that control which events an [`EventReceiver`] gets (see
+/// [`start_with_options`]).
+///
+/// ## Example
 ///
-/// ## Returns
+/// ```rust, ignore
+/// use winput::message_loop::{self, Options};
 ///
-/// This function returns an error if the message loop is already active: only one
-/// message loop can be started at any given time. Be carfull if another library is
-/// also using the message loop.
+/// // Only listen to the keyboard.
+/// let receiver = message_loop::start_with_options(Options::new().mouse(false)).unwrap();
+/// ```
+///
+/// [`EventReceiver`]: struct.EventReceiver.html
+/// [`start_with_options`]: fn.start_with_options.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Options {
+    keyboard: bool,
+    mouse: bool,
+    hid: Vec<(u16, u16)>,
+    background: bool,
+    sided_modifiers: bool,
+    chars: bool,
+    raw: bool,
+    capacity: Option<usize>,
+    overflow: Overflow,
+}
+
+impl Options {
+    /// Creates the default options. Both keyboard and mouse events are received,
+    /// including while the application is not in the foreground, and the channel of
+    /// the receiver is unbounded.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            keyboard: true,
+            mouse: true,
+            hid: Vec::new(),
+            background: true,
+            sided_modifiers: false,
+            chars: false,
+            raw: false,
+            capacity: None,
+            overflow: Overflow::DropNewest,
+        }
+    }
+
+    /// Sets whether keyboard events are received.
+    #[inline]
+    pub fn keyboard(mut self, yes: bool) -> Self {
+        self.keyboard = yes;
+        self
+    }
+
+    /// Sets whether mouse events are received. Mouse motions are by far the most
+    /// frequent events, so applications that don't need them should disable them.
+    #[inline]
+    pub fn mouse(mut self, yes: bool) -> Self {
+        self.mouse = yes;
+        self
+    }
+
+    /// Receives the reports of the HID devices (other than keyboards and mice) with the
+    /// given usage page and usage, as [`Event::Hid`] events. This function can be
+    /// called several times to receive the reports of several kinds of devices.
+    ///
+    /// Common values are:
+    ///
+    /// * `(0x01, 0x04)`: joysticks.
+    /// * `(0x01, 0x05)`: gamepads.
+    /// * `(0x0C, 0x01)`: consumer controls (such as multimedia keys).
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::message_loop::{self, Event, Options};
+    ///
+    /// let options = Options::new().keyboard(false).mouse(false).hid(0x01, 0x05);
+    /// let receiver = message_loop::start_with_options(options).unwrap();
+    ///
+    /// for event in receiver.iter() {
+    ///     if let Event::Hid { report, .. } = event {
+    ///         println!("{:02x?}", report);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Event::Hid`]: enum.Event.html#variant.Hid
+    #[inline]
+    pub fn hid(mut self, usage_page: u16, usage: u16) -> Self {
+        if !self.hid.contains(&(usage_page, usage)) {
+            self.hid.push((usage_page, usage));
+        }
+        self
+    }
+
+    /// Sets whether events are received while the application is not in the
+    /// foreground (`RIDEV_INPUTSINK`).
+    #[inline]
+    pub fn background(mut self, yes: bool) -> Self {
+        self.background = yes;
+        self
+    }
+
+    /// Sets whether the generic `Shift`, `Control` and `Alt` keys reported by the
+    /// system are replaced by their left or right variant (such as [`Vk::LeftShift`]
+    /// or [`Vk::RightMenu`]) in [`Event::Keyboard`] events. The side is determined from
+    /// the scan code of the key.
+    ///
+    /// This is disabled by default.
+    ///
+    /// [`Vk::LeftShift`]: ../enum.Vk.html#variant.LeftShift
+    /// [`Vk::RightMenu`]: ../enum.Vk.html#variant.RightMenu
+    /// [`Event::Keyboard`]: enum.Event.html#variant.Keyboard
+    #[inline]
+    pub fn sided_modifiers(mut self, yes: bool) -> Self {
+        self.sided_modifiers = yes;
+        self
+    }
+
+    /// Sets whether [`Event::Char`] events are received. Such an event is sent after
+    /// the [`Event::Keyboard`] event of a key press, for every character the press
+    /// would type in the foreground application: the keyboard layout of that
+    /// application, the modifiers that are held down, CAPS LOCK and dead keys are
+    /// taken into account.
+    ///
+    /// Keyboard events must be received as well. This is disabled by default.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::message_loop::{self, Event, Options};
+    ///
+    /// let receiver = message_loop::start_with_options(Options::new().chars(true)).unwrap();
+    ///
+    /// for event in receiver.iter() {
+    ///     if let Event::Char { character, .. } = event {
+    ///         print!("{}", character);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Event::Char`]: enum.Event.html#variant.Char
+    /// [`Event::Keyboard`]: enum.Event.html#variant.Keyboard
+    #[inline]
+    pub fn chars(mut self, yes: bool) -> Self {
+        self.chars = yes;
+        self
+    }
+
+    /// Sets whether keyboard and mouse inputs are received as [`Event::Raw`] events,
+    /// which carry every field of the `RAWKEYBOARD` and `RAWMOUSE` structures sent by
+    /// the system, instead of the simplified [`Event::Keyboard`] and mouse events.
+    ///
+    /// Inputs that the simplified events can't represent, such as keys without a
+    /// known virtual-key code or horizontal wheel rotations, are received as well.
+    /// [`Event::Char`] events are not affected.
+    ///
+    /// This is disabled by default.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::message_loop::{self, Event, Options, RawEvent};
+    ///
+    /// let receiver = message_loop::start_with_options(Options::new().raw(true)).unwrap();
+    ///
+    /// for event in receiver.iter() {
+    ///     if let Event::Raw { raw: RawEvent::Mouse(mouse), .. } = event {
+    ///         println!("raw buttons: {:#x}", mouse.raw_buttons);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Event::Raw`]: enum.Event.html#variant.Raw
+    /// [`Event::Keyboard`]: enum.Event.html#variant.Keyboard
+    /// [`Event::Char`]: enum.Event.html#variant.Char
+    #[inline]
+    pub fn raw(mut self, yes: bool) -> Self {
+        self.raw = yes;
+        self
+    }
+
+    /// Sets the maximum number of events that can be waiting in the channel of the
+    /// receiver. Events that are received while the channel is full are handled
+    /// according to the [`Overflow`] policy of the receiver, and the number of
+    /// discarded events is given by [`EventReceiver::dropped_events`].
+    ///
+    /// By default, the channel is unbounded.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::message_loop::{self, Options, Overflow};
+    ///
+    /// let options = Options::new().capacity(256).overflow(Overflow::CoalesceMoves);
+    /// let receiver = message_loop::start_with_options(options).unwrap();
+    /// ```
+    ///
+    /// [`Overflow`]: enum.Overflow.html
+    /// [`EventReceiver::dropped_events`]: struct.EventReceiver.html#method.dropped_events
+    #[inline]
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Sets what happens to the events received while the channel of the receiver is
+    /// full (see [`Options::capacity`]).
+    ///
+    /// The default policy is [`Overflow::DropNewest`].
+    ///
+    /// [`Options::capacity`]: struct.Options.html#method.capacity
+    /// [`Overflow::DropNewest`]: enum.Overflow.html#variant.DropNewest
+    #[inline]
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+}
+
+impl Default for Options {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What happens to the events received while the channel of an [`EventReceiver`] is
+/// full (see [`Options::overflow`]).
+///
+/// [`EventReceiver`]: struct.EventReceiver.html
+/// [`Options::overflow`]: struct.Options.html#method.overflow
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// The oldest waiting event is discarded to make room for the new one.
+    DropOldest,
+    /// The new event is discarded.
+    DropNewest,
+    /// A mouse motion is merged into the last waiting event when it is a motion of the
+    /// same kind, from the same device: relative motions are added together and
+    /// absolute motions replace each other. Otherwise, the oldest waiting event is
+    /// discarded.
+    ///
+    /// Merged motions are not counted as discarded events.
+    CoalesceMoves,
+}
+
+/// Subscribes to the events of the message loop, starting it on a new thread if it is
+/// not active yet.
+///
+/// Any number of [`EventReceiver`]s can be alive at the same time, for example in
+/// different components of an application or in different libraries. Each receiver
+/// gets its own copy of every event received after it was created. The message loop
+/// stops when the last receiver is dropped.
 ///
 /// You can check if the message loop is currently active by calling [`is_active`].
 ///
@@ -328,34 +1368,210 @@ pub fn is_active() -> bool {
 ///
 /// let receiver = message_loop::start().unwrap();
 ///
-/// loop {
-///     println!("{:?}", receiver.next_event());
+/// while let Ok(event) = receiver.next_event() {
+///     println!("{:?}", event);
 /// }
 /// ```
 ///
+/// [`EventReceiver`]: struct.EventReceiver.html
 /// [`is_active`]: fn.is_active.html
+#[inline]
 pub fn start() -> Result<EventReceiver, MessageLoopError> {
+    start_with_options(Options::new())
+}
+
+/// Subscribes to the events of the message loop with the given [`Options`], starting
+/// the message loop on a new thread if it is not active yet.
+///
+/// The system only sends the message loop the inputs of the devices that at least
+/// one receiver is interested in.
+///
+/// See [`start`] for more information.
+///
+/// [`Options`]: struct.Options.html
+/// [`start`]: fn.start.html
+#[inline]
+pub fn start_with_options(options: Options) -> Result<EventReceiver, MessageLoopError> {
+    subscribe(options, None)
+}
+
+/// Subscribes to the events of the message loop that match the given filter, starting
+/// the message loop on a new thread if it is not active yet.
+///
+/// The filter is called on the message loop's thread, before the event is copied into
+/// the channel of the receiver: discarding the events that are not needed (such as
+/// mouse motions) saves a lot of work. As every receiver waits for the filter, it
+/// should return quickly. It must not panic nor call the functions of this module.
+///
+/// See [`start`] for more information.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::message_loop::{self, Event};
+/// use winput::{Action, Vk};
+///
+/// // Only receive the presses of F9.
+/// let receiver = message_loop::start_with_filter(|event: &Event| {
+///     matches!(
+///         event,
+///         Event::Keyboard { vk: Vk::F9, action: Action::Press, .. }
+///     )
+/// })
+/// .unwrap();
+///
+/// for _ in receiver.iter() {
+///     println!("F9 was pressed!");
+/// }
+/// ```
+///
+/// [`start`]: fn.start.html
+#[inline]
+pub fn start_with_filter<F>(filter: F) -> Result<EventReceiver, MessageLoopError>
+where
+    F: Fn(&Event) -> bool + Send + 'static,
+{
+    subscribe(Options::new(), Some(Box::new(filter)))
+}
+
+/// Calls the given function with the events of the message loop on a new dispatcher
+/// thread, starting the message loop if it is not active yet.
+///
+/// This is an alternative to polling an [`EventReceiver`]: the dispatcher thread owns
+/// the receiver and calls `callback` for every event, in order. The callback stops
+/// being called when the returned [`CallbackHandle`] is dropped or when the message
+/// loop stops.
+///
+/// See [`start`] for more information.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::message_loop::{self, Event};
+///
+/// let handle = message_loop::start_with_callback(|event: Event| {
+///     println!("{:?}", event);
+/// })
+/// .unwrap();
+///
+/// // Events are printed until `handle` is dropped.
+/// std::thread::sleep(std::time::Duration::from_secs(10));
+/// ```
+///
+/// [`EventReceiver`]: struct.EventReceiver.html
+/// [`CallbackHandle`]: struct.CallbackHandle.html
+/// [`start`]: fn.start.html
+pub fn start_with_callback<F>(callback: F) -> Result<CallbackHandle, MessageLoopError>
+where
+    F: FnMut(Event) + Send + 'static,
+{
+    let receiver = start()?;
+    let id = receiver.id;
+
+    // The iterator ends when the subscriber is forgotten or when the message loop
+    // stops, and the receiver is then dropped.
+    let thread = std::thread::spawn(move || receiver.for_each(callback));
+
+    Ok(CallbackHandle {
+        id,
+        thread: Some(thread),
+    })
+}
+
+/// The result of the [`start_with_callback`] function. Dropping this structure stops
+/// the callback and waits until the dispatcher thread has exited.
+///
+/// [`start_with_callback`]: fn.start_with_callback.html
+#[derive(Debug)]
+#[must_use]
+pub struct CallbackHandle {
+    /// The identifier of the `Subscriber` of the dispatcher thread.
+    id: u64,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl CallbackHandle {
+    /// Lets the callback be called until the message loop stops, without keeping the
+    /// handle alive.
+    #[inline]
+    pub fn detach(mut self) {
+        self.thread = None;
+    }
+}
+
+impl Drop for CallbackHandle {
+    fn drop(&mut self) {
+        let thread = match self.thread.take() {
+            Some(thread) => thread,
+            None => return,
+        };
+
+        // Forgetting the subscriber disconnects the receiver of the dispatcher thread,
+        // which then exits (and stops the message loop if nobody else is listening).
+        lock_subscribers().retain(|subscriber| subscriber.id != self.id);
+
+        // The handle may be dropped by the callback itself.
+        if thread.thread().id() != std::thread::current().id() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Adds a subscriber with the given options and filter, starting the message loop if
+/// it is not active yet.
+pub(crate) fn subscribe(
+    options: Options,
+    filter: Option<Filter>,
+) -> Result<EventReceiver, MessageLoopError> {
+    let mut subscribers = lock_subscribers();
+
     loop {
-        match STATE.compare_exchange(0, 1, Ordering::SeqCst, Ordering::SeqCst) {
-            Ok(0) => break,
+        match STATE.load(Ordering::SeqCst) {
+            2 => {
+                update_devices();
+                break;
+            }
+            0 => {
+                STATE.store(1, Ordering::SeqCst);
+
+                if let Err(err) = spawn(Devices::of(&options)) {
+                    STATE.store(0, Ordering::SeqCst);
+                    return Err(err);
+                }
+
+                break;
+            }
 
             // If the message loop is shutting down, we can just wait
-            // a bit until we can start it again.
-            Err(3) => (),
-            _ => return Err(MessageLoopError::AlreadyActive),
+            // until we can start it again.
+            _ => subscribers = wait_until_stopped(subscribers),
         }
-
-        std::hint::spin_loop();
     }
 
-    // The message loop is now starting.
     // This channel is used to receive the messages of the message loop.
-    let (s, r) = mpsc::channel();
+    let channel = Arc::new(Channel::new(options.capacity, options.overflow));
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+
+    subscribers.push(Subscriber {
+        id,
+        options,
+        filter,
+        channel: Arc::clone(&channel),
+    });
+
+    Ok(EventReceiver { id, channel })
+}
 
-    // We have to initialize `SENDER` and `BUFFER`.
+/// Starts the message loop's thread and waits until it is ready to receive events from
+/// the given devices.
+fn spawn(devices: Devices) -> Result<(), MessageLoopError> {
+    // We have to initialize `BUFFER`.
     unsafe {
-        SENDER = MaybeUninit::new(s);
         BUFFER = MaybeUninit::new(Vec::new());
+        HID_USAGES = MaybeUninit::new(Vec::new());
+        KEYS_DOWN = [0; 8];
+        CAPS_LOCK = Vk::CapsLock.is_toggled();
+        DEAD_KEY = None;
     }
 
     // This channel is used to retreive a potential error from the message loop's
@@ -380,7 +1596,10 @@ pub fn start() -> Result<EventReceiver, MessageLoopError> {
 
             let class = winuser::RegisterClassW(&wnd_class);
 
-            if class == 0 {
+            // The class is unregistered when the message loop exits, but it is reused if
+            // that failed for some reason.
+            if class == 0 && errhandlingapi::GetLastError() != ERROR_CLASS_ALREADY_EXISTS
+            {
                 error_s
                     .send(Err(MessageLoopError::OsError(
                         WindowsError::from_last_error(),
@@ -389,6 +1608,15 @@ pub fn start() -> Result<EventReceiver, MessageLoopError> {
                 return;
             }
 
+            // Destroys the window (if it was created) and unregisters its class, so that
+            // the message loop can be started again.
+            let destroy = |h_wnd: windef::HWND| {
+                if !h_wnd.is_null() {
+                    winuser::DestroyWindow(h_wnd);
+                }
+                winuser::UnregisterClassW(class_name.as_ptr(), h_instance);
+            };
+
             let h_wnd = winuser::CreateWindowExW(
                 0,
                 class_name.as_ptr(),
@@ -405,44 +1633,23 @@ pub fn start() -> Result<EventReceiver, MessageLoopError> {
             );
 
             if h_wnd.is_null() {
-                error_s
-                    .send(Err(MessageLoopError::OsError(
-                        WindowsError::from_last_error(),
-                    )))
-                    .unwrap();
+                let err = WindowsError::from_last_error();
+                destroy(h_wnd);
+                error_s.send(Err(MessageLoopError::OsError(err))).unwrap();
                 return;
             }
 
             // Tell the system we want to receive inputs.
-            let mut rid: [winuser::RAWINPUTDEVICE; 2] = mem::zeroed();
-            // Keyboard
-            rid[0].dwFlags = winuser::RIDEV_NOLEGACY | winuser::RIDEV_INPUTSINK;
-            rid[0].usUsagePage = hidusage::HID_USAGE_PAGE_GENERIC;
-            rid[0].usUsage = hidusage::HID_USAGE_GENERIC_KEYBOARD;
-            rid[0].hwndTarget = h_wnd;
-            // Mouse
-            rid[1].dwFlags = winuser::RIDEV_NOLEGACY | winuser::RIDEV_INPUTSINK;
-            rid[1].usUsagePage = hidusage::HID_USAGE_PAGE_GENERIC;
-            rid[1].usUsage = hidusage::HID_USAGE_GENERIC_MOUSE;
-            rid[1].hwndTarget = h_wnd;
-
-            let result = winuser::RegisterRawInputDevices(
-                rid.as_ptr(),
-                rid.len() as _,
-                mem::size_of::<winuser::RAWINPUTDEVICE>() as _,
-            );
-
-            if result == 0 {
-                error_s
-                    .send(Err(MessageLoopError::OsError(
-                        WindowsError::from_last_error(),
-                    )))
-                    .unwrap();
+            if let Err(err) = devices.register(&Devices::default(), h_wnd) {
+                destroy(h_wnd);
+                error_s.send(Err(MessageLoopError::OsError(err))).unwrap();
                 return;
             }
+            let mut registered = devices;
 
             // The message loop has now started.
             // It is ready to receive events.
+            HWND.store(h_wnd as usize, Ordering::SeqCst);
             STATE.store(2, Ordering::SeqCst);
 
             // Notify the main thread that the initialisation is a success.
@@ -459,6 +1666,14 @@ pub fn start() -> Result<EventReceiver, MessageLoopError> {
                 if result == -1 {
                     // An error occured in the message loop.
                     break;
+                } else if msg.message == WM_UPDATE_DEVICES {
+                    // A receiver was created or dropped.
+                    let devices = Devices::needed_by(&lock_subscribers());
+                    if devices != registered
+                        && devices.register(&registered, h_wnd).is_ok()
+                    {
+                        registered = devices;
+                    }
                 } else {
                     winuser::TranslateMessage(&msg);
                     winuser::DispatchMessageW(&msg);
@@ -466,35 +1681,91 @@ pub fn start() -> Result<EventReceiver, MessageLoopError> {
             }
 
             // The message loop is now exiting.
+            // The receivers must notice it even if the loop stopped on its own.
+            let mut subscribers = lock_subscribers();
+            subscribers.clear();
+            STATE.store(3, Ordering::SeqCst);
 
-            // Deinitialize the sender and the buffer.
+            // Stop receiving inputs and release the window, so that nothing remains
+            // from this message loop when the next one starts.
+            let _ = Devices::default().register(&registered, ptr::null_mut());
+            destroy(h_wnd);
+
+            // Deinitialize the buffers.
             // TODO: Use `MaybeUninit::assume_init_drop` when stable.
-            ptr::drop_in_place(SENDER.as_mut_ptr());
             ptr::drop_in_place(BUFFER.as_mut_ptr());
+            ptr::drop_in_place(HID_USAGES.as_mut_ptr());
 
             // The message loop is now shut down.
+            // The state changes while the lock is held so that no waiting thread
+            // misses the notification.
             STATE.store(0, Ordering::SeqCst);
+            drop(subscribers);
+            stopped().notify_all();
         }
     });
 
-    error_r
-        .recv()
-        .unwrap()
-        .map(|()| EventReceiver { receiver: r })
+    let result = error_r.recv().unwrap();
+
+    if result.is_err() {
+        // The message loop's thread exited without using the buffers.
+        unsafe {
+            ptr::drop_in_place(BUFFER.as_mut_ptr());
+            ptr::drop_in_place(HID_USAGES.as_mut_ptr());
+        }
+    }
+
+    result
 }
 
 /// An event of any kind.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub enum Event {
     Keyboard {
+        /// The device that produced the event.
+        device: DeviceId,
+        /// The moment the message loop received the event.
+        time: Instant,
+        /// Whether the event was produced by a device or synthesized.
+        origin: Origin,
         /// The virtual keycode of the key that was pressed.
         vk: Vk,
         /// The scan code of that key.
+        ///
+        /// Extended scan codes have their prefix in the high byte, like the ones
+        /// returned by [`Vk::to_scan_code`]: for example, the Enter key of the numeric
+        /// keypad is `0xe01c` while the main Enter key is `0x1c`.
+        ///
+        /// [`Vk::to_scan_code`]: ../enum.Vk.html#method.to_scan_code
         scan_code: u32,
         /// The action that was taken on the key.
         action: Action,
+        /// Whether this event is an auto-repeated press, generated by the system while
+        /// the key is held down. This is always `false` for releases.
+        repeat: bool,
+    },
+    /// A character typed by a key press (see [`Options::chars`]).
+    ///
+    /// [`Options::chars`]: struct.Options.html#method.chars
+    Char {
+        /// The device that produced the event.
+        device: DeviceId,
+        /// The moment the message loop received the event.
+        time: Instant,
+        /// Whether the event was produced by a device or synthesized.
+        origin: Origin,
+        /// The typed character. Like the characters of `WM_CHAR` messages, it can be a
+        /// control character, such as `'\r'` for the Enter key or `'\u{8}'` for
+        /// Backspace.
+        character: char,
     },
     MouseMoveRelative {
+        /// The device that produced the event.
+        device: DeviceId,
+        /// The moment the message loop received the event.
+        time: Instant,
+        /// Whether the event was produced by a device or synthesized.
+        origin: Origin,
         /// The x coordinate of the mouse, in [per-monitor-aware] screen coordinates.
         ///
         /// [per-monitor-aware]: https://docs.microsoft.com/en-us/windows/desktop/api/shellscalingapi/ne-shellscalingapi-process_dpi_awareness
@@ -505,6 +1776,12 @@ pub enum Event {
         y: i32,
     },
     MouseMoveAbsolute {
+        /// The device that produced the event.
+        device: DeviceId,
+        /// The moment the message loop received the event.
+        time: Instant,
+        /// Whether the event was produced by a device or synthesized.
+        origin: Origin,
         /// The x coordinate of the mouse in screen coordinates.
         x: f32,
         /// The y coordinate of the mouse in screen coordinates.
@@ -514,12 +1791,24 @@ pub enum Event {
         virtual_desk: bool,
     },
     MouseButton {
+        /// The device that produced the event.
+        device: DeviceId,
+        /// The moment the message loop received the event.
+        time: Instant,
+        /// Whether the event was produced by a device or synthesized.
+        origin: Origin,
         /// The action that was taken on the mouse button.
         action: Action,
         /// The mouse button involved in the event.
         button: Button,
     },
     MouseWheel {
+        /// The device that produced the event.
+        device: DeviceId,
+        /// The moment the message loop received the event.
+        time: Instant,
+        /// Whether the event was produced by a device or synthesized.
+        origin: Origin,
         /// The amount of rotation of the wheel. Positive values indicate that the wheel
         /// was rotated forward, away from the user; a negative value means that the wheel
         /// was rotated backward, toward the user.
@@ -527,93 +1816,937 @@ pub enum Event {
         /// The direction of the wheel.
         direction: WheelDirection,
     },
+    Hid {
+        /// The device that produced the event.
+        device: DeviceId,
+        /// The moment the message loop received the event.
+        time: Instant,
+        /// Whether the event was produced by a device or synthesized.
+        origin: Origin,
+        /// The usage page of the device (see [`Options::hid`]).
+        ///
+        /// [`Options::hid`]: struct.Options.html#method.hid
+        usage_page: u16,
+        /// The usage of the device (see [`Options::hid`]).
+        ///
+        /// [`Options::hid`]: struct.Options.html#method.hid
+        usage: u16,
+        /// The raw report sent by the device. Its format depends on the device, and is
+        /// described by the report descriptor of the device.
+        report: Vec<u8>,
+    },
+    /// A keyboard or mouse input, as sent by the system (see [`Options::raw`]).
+    ///
+    /// [`Options::raw`]: struct.Options.html#method.raw
+    Raw {
+        /// The device that produced the event.
+        device: DeviceId,
+        /// The moment the message loop received the event.
+        time: Instant,
+        /// Whether the event was produced by a device or synthesized.
+        origin: Origin,
+        /// The data of the input.
+        raw: RawEvent,
+    },
+}
+
+/// The data of a keyboard or mouse input, as sent by the system (see [`Event::Raw`]).
+///
+/// [`Event::Raw`]: enum.Event.html#variant.Raw
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RawEvent {
+    Keyboard(RawKeyboard),
+    Mouse(RawMouse),
+}
+
+/// The fields of a [`RAWKEYBOARD`] structure.
+///
+/// [`RAWKEYBOARD`]: https://docs.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawkeyboard
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RawKeyboard {
+    /// The scan code of the key, without its `E0` or `E1` prefix.
+    pub make_code: u16,
+    /// The `RI_KEY_*` flags: whether the key was released and the prefix of its scan
+    /// code.
+    pub flags: u16,
+    /// The virtual-key code of the key, which may not be a known [`Vk`].
+    ///
+    /// [`Vk`]: ../enum.Vk.html
+    pub vkey: u16,
+    /// The corresponding window message, such as `WM_KEYDOWN`.
+    pub message: u32,
+    /// The additional information associated with the input.
+    pub extra_information: u32,
+}
+
+/// The fields of a [`RAWMOUSE`] structure.
+///
+/// [`RAWMOUSE`]: https://docs.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-rawmouse
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RawMouse {
+    /// The `MOUSE_*` flags that describe the motion.
+    pub flags: u16,
+    /// The `RI_MOUSE_*` flags that describe the buttons that changed and the wheels.
+    pub button_flags: u16,
+    /// The rotation of the wheel, as a signed value, when `button_flags` contains
+    /// `RI_MOUSE_WHEEL` or `RI_MOUSE_HWHEEL`.
+    pub button_data: u16,
+    /// The raw state of the buttons, as reported by the device.
+    pub raw_buttons: u32,
+    /// The motion in the x direction, relative or absolute depending on `flags`.
+    pub last_x: i32,
+    /// The motion in the y direction, relative or absolute depending on `flags`.
+    pub last_y: i32,
+    /// The additional information associated with the input.
+    pub extra_information: u32,
+}
+
+impl Event {
+    /// Returns the moment the message loop received this event.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::message_loop;
+    ///
+    /// let receiver = message_loop::start().unwrap();
+    ///
+    /// let mut last = receiver.next_event().unwrap().time();
+    /// for event in receiver.iter() {
+    ///     println!("{:?} after the previous event", event.time() - last);
+    ///     last = event.time();
+    /// }
+    /// ```
+    #[inline]
+    pub fn time(&self) -> Instant {
+        match *self {
+            Event::Keyboard { time, .. }
+            | Event::Char { time, .. }
+            | Event::MouseMoveRelative { time, .. }
+            | Event::MouseMoveAbsolute { time, .. }
+            | Event::MouseButton { time, .. }
+            | Event::MouseWheel { time, .. }
+            | Event::Hid { time, .. }
+            | Event::Raw { time, .. } => time,
+        }
+    }
+
+    /// Returns whether this event was produced by a device or synthesized.
+    #[inline]
+    pub fn origin(&self) -> Origin {
+        match *self {
+            Event::Keyboard { origin, .. }
+            | Event::Char { origin, .. }
+            | Event::MouseMoveRelative { origin, .. }
+            | Event::MouseMoveAbsolute { origin, .. }
+            | Event::MouseButton { origin, .. }
+            | Event::MouseWheel { origin, .. }
+            | Event::Hid { origin, .. }
+            | Event::Raw { origin, .. } => origin,
+        }
+    }
+
+    /// Checks whether this event was synthesized rather than produced by a device
+    /// (see [`Origin`]).
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::message_loop;
+    ///
+    /// let receiver = message_loop::start().unwrap();
+    ///
+    /// // Only count the activity of the user.
+    /// let physical = receiver.iter().filter(|e| !e.is_injected()).take(100).count();
+    /// ```
+    ///
+    /// [`Origin`]: enum.Origin.html
+    #[inline]
+    pub fn is_injected(&self) -> bool {
+        self.origin() != Origin::Device
+    }
+
+    /// Replaces the generic modifier of a keyboard event by its left or right variant.
+    fn resolve_modifier_side(&mut self) {
+        /// The scan code of the right Shift key, which is not an extended key.
+        const RIGHT_SHIFT_SCAN_CODE: u32 = 0x36;
+
+        if let Event::Keyboard { vk, scan_code, .. } = self {
+            let extended = *scan_code >> 8 == 0xe0;
+
+            *vk = match *vk {
+                Vk::Shift if *scan_code == RIGHT_SHIFT_SCAN_CODE => Vk::RightShift,
+                Vk::Shift => Vk::LeftShift,
+                Vk::Control if extended => Vk::RightControl,
+                Vk::Control => Vk::LeftControl,
+                Vk::Alt if extended => Vk::RightMenu,
+                Vk::Alt => Vk::LeftMenu,
+                other => other,
+            };
+        }
+    }
+
+    /// Merges the given mouse motion into this one, if they are of the same kind and
+    /// come from the same device. Returns whether they were merged.
+    fn coalesce(&mut self, next: &Event) -> bool {
+        match (self, next) {
+            (
+                Event::MouseMoveRelative {
+                    device,
+                    time,
+                    origin,
+                    x,
+                    y,
+                },
+                Event::MouseMoveRelative {
+                    device: next_device,
+                    time: next_time,
+                    origin: next_origin,
+                    x: next_x,
+                    y: next_y,
+                },
+            ) if device == next_device && origin == next_origin => {
+                *time = *next_time;
+                *x += *next_x;
+                *y += *next_y;
+                true
+            }
+            (
+                Event::MouseMoveAbsolute {
+                    device,
+                    time,
+                    origin,
+                    x,
+                    y,
+                    virtual_desk,
+                },
+                Event::MouseMoveAbsolute {
+                    device: next_device,
+                    time: next_time,
+                    origin: next_origin,
+                    x: next_x,
+                    y: next_y,
+                    virtual_desk: next_virtual_desk,
+                },
+            ) if device == next_device
+                && origin == next_origin
+                && virtual_desk == next_virtual_desk =>
+            {
+                *time = *next_time;
+                *x = *next_x;
+                *y = *next_y;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the device that produced this event.
+    #[inline]
+    pub fn device(&self) -> DeviceId {
+        match *self {
+            Event::Keyboard { device, .. }
+            | Event::Char { device, .. }
+            | Event::MouseMoveRelative { device, .. }
+            | Event::MouseMoveAbsolute { device, .. }
+            | Event::MouseButton { device, .. }
+            | Event::MouseWheel { device, .. }
+            | Event::Hid { device, .. }
+            | Event::Raw { device, .. } => device,
+        }
+    }
+}
+
+/// Whether an [`Event`] was produced by a device or synthesized.
+///
+/// [`Event`]: enum.Event.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Origin {
+    /// The event was produced by a physical device.
+    Device,
+    /// The event was synthesized, for example by another application calling
+    /// `SendInput`.
+    Injected,
+    /// The event was synthesized and carries the current injection tag of this crate,
+    /// which means it was most likely sent by this process (see
+    /// [`set_injection_tag`]).
+    ///
+    /// [`set_injection_tag`]: ../fn.set_injection_tag.html
+    Tagged,
+}
+
+impl Origin {
+    /// Determines the origin of an event given the device that produced it and its
+    /// additional information.
+    fn new(device: DeviceId, extra_info: usize) -> Self {
+        if is_injection_tag(extra_info) {
+            Origin::Tagged
+        } else if device.as_raw().is_null() {
+            Origin::Injected
+        } else {
+            Origin::Device
+        }
+    }
+}
+
+/// An opaque identifier of the device that produced an [`Event`].
+///
+/// Every keyboard and every mouse plugged into the computer has its own identifier,
+/// which makes it possible to distinguish, for example, a macro pad from the main
+/// keyboard. Identifiers remain valid as long as the device stays plugged.
+///
+/// [`Event`]: enum.Event.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceId(usize);
+
+impl DeviceId {
+    /// Creates a `DeviceId` from a raw input device handle.
+    #[inline]
+    pub fn from_raw(handle: winnt::HANDLE) -> Self {
+        Self(handle as usize)
+    }
+
+    /// Returns the raw input device handle of this `DeviceId`.
+    #[inline]
+    pub fn as_raw(self) -> winnt::HANDLE {
+        self.0 as winnt::HANDLE
+    }
+
+    /// Retrieves the name of the device. The name is a path that uniquely identifies
+    /// the device, and that stays the same when the device is plugged again.
+    pub fn name(self) -> Result<String, WindowsError> {
+        let mut len = 0;
+
+        unsafe {
+            // Calling C code
+            let result = winuser::GetRawInputDeviceInfoW(
+                self.as_raw(),
+                winuser::RIDI_DEVICENAME,
+                ptr::null_mut(),
+                &mut len,
+            );
+
+            if result != 0 {
+                return Err(WindowsError::from_last_error());
+            }
+
+            let mut buffer = vec![0u16; len as usize];
+
+            // Calling C code
+            let result = winuser::GetRawInputDeviceInfoW(
+                self.as_raw(),
+                winuser::RIDI_DEVICENAME,
+                buffer.as_mut_ptr() as _,
+                &mut len,
+            );
+
+            if result == -1i32 as u32 {
+                return Err(WindowsError::from_last_error());
+            }
+
+            let name = &buffer[..result as usize];
+            let name = name.split(|&c| c == 0).next().unwrap_or(name);
+            Ok(String::from_utf16_lossy(name))
+        }
+    }
+
+    /// Retrieves information about the device, including its vendor and product
+    /// identifiers.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::message_loop;
+    ///
+    /// let receiver = message_loop::start().unwrap();
+    ///
+    /// for event in receiver.iter().take(10) {
+    ///     println!("{:?}", event.device().info());
+    /// }
+    /// ```
+    pub fn info(self) -> Result<DeviceInfo, WindowsError> {
+        let name = self.name()?;
+
+        let (mut vendor_id, mut product_id) = (
+            parse_device_id(&name, "VID_"),
+            parse_device_id(&name, "PID_"),
+        );
+
+        // Only HID devices report their identifiers directly.
+        let info = self.raw_info()?;
+        if info.dwType == winuser::RIM_TYPEHID {
+            let hid = unsafe { info.u.hid() };
+            vendor_id = Some(hid.dwVendorId as u16);
+            product_id = Some(hid.dwProductId as u16);
+        }
+
+        Ok(DeviceInfo {
+            name,
+            vendor_id,
+            product_id,
+        })
+    }
+
+    /// Retrieves the `RID_DEVICE_INFO` structure of the device.
+    fn raw_info(self) -> Result<winuser::RID_DEVICE_INFO, WindowsError> {
+        unsafe {
+            let mut info: winuser::RID_DEVICE_INFO = mem::zeroed();
+            let mut size = mem::size_of::<winuser::RID_DEVICE_INFO>() as u32;
+            info.cbSize = size;
+
+            // Calling C code
+            let result = winuser::GetRawInputDeviceInfoW(
+                self.as_raw(),
+                winuser::RIDI_DEVICEINFO,
+                &mut info as *mut winuser::RID_DEVICE_INFO as _,
+                &mut size,
+            );
+
+            if result == -1i32 as u32 {
+                Err(WindowsError::from_last_error())
+            } else {
+                Ok(info)
+            }
+        }
+    }
+}
+
+/// Parses the four hexadecimal digits that follow `prefix` in the name of a device
+/// (for example `VID_046D`).
+fn parse_device_id(name: &str, prefix: &str) -> Option<u16> {
+    let name = name.to_ascii_uppercase();
+    let start = name.find(prefix)? + prefix.len();
+    let digits = name.get(start..start + 4)?;
+    u16::from_str_radix(digits, 16).ok()
+}
+
+/// Information about a device, retrieved by the [`DeviceId::info`] function.
+///
+/// [`DeviceId::info`]: struct.DeviceId.html#method.info
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeviceInfo {
+    /// The name of the device (see [`DeviceId::name`]).
+    ///
+    /// [`DeviceId::name`]: struct.DeviceId.html#method.name
+    pub name: String,
+    /// The USB vendor identifier of the device, if it could be determined.
+    pub vendor_id: Option<u16>,
+    /// The USB product identifier of the device, if it could be determined.
+    pub product_id: Option<u16>,
+}
+
+impl TryFrom<Event> for Input {
+    type Error = Event;
+
+    /// Converts a captured [`Event`] into the [`Input`] that reproduces it, so that a
+    /// recorded stream of events can be replayed with [`send_inputs`].
+    ///
+    /// Keystrokes are replayed with their scan code, and extended keys keep their `E0`
+    /// prefix (`KEYEVENTF_EXTENDEDKEY`). This lets the receiver tell apart keys that
+    /// share a Virtual-Key Code, such as the two Enter keys.
+    ///
+    /// Relative mouse motions are replayed as relative motions, which means they are
+    /// subject to the mouse speed settings of the user.
+    ///
+    /// The reports of HID devices and raw inputs cannot be replayed, and typed
+    /// characters are already reproduced by the key presses that produced them: the
+    /// event is returned as an error.
+    ///
+    /// [`Event`]: enum.Event.html
+    /// [`Input`]: ../struct.Input.html
+    /// [`send_inputs`]: ../fn.send_inputs.html
+    fn try_from(event: Event) -> Result<Self, Event> {
+        match event {
+            Event::Keyboard {
+                vk,
+                scan_code,
+                action,
+                ..
+            } => Ok(match scan_code >> 8 {
+                // `SendInput` has no way to express the `E1` prefix of the Pause key.
+                0xe1 => Input::from_vk(vk, action),
+                prefix => Input::keyboard(vk)
+                    .scan_code(scan_code as u16 & 0xff)
+                    .extended(prefix == 0xe0)
+                    .action(action)
+                    .build(),
+            }),
+            Event::MouseMoveRelative { x, y, .. } => {
+                Ok(Input::from_motion(MouseMotion::Relative { dx: x, dy: y }))
+            }
+            Event::MouseMoveAbsolute {
+                x, y, virtual_desk, ..
+            } => Ok(Input::from_motion(MouseMotion::Absolute {
+                x,
+                y,
+                virtual_desk,
+            })),
+            Event::MouseButton { action, button, .. } => {
+                Ok(Input::from_button(button, action))
+            }
+            Event::MouseWheel {
+                delta, direction, ..
+            } => Ok(Input::from_wheel(delta, direction)),
+            Event::Char { .. } | Event::Hid { .. } | Event::Raw { .. } => Err(event),
+        }
+    }
+}
+
+impl From<Event> for Vec<Input> {
+    /// Converts a captured [`Event`] into the [`Input`]s that reproduce it. The
+    /// reports of HID devices, typed characters and raw inputs produce no input.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{message_loop, Input};
+    ///
+    /// let receiver = message_loop::start().unwrap();
+    ///
+    /// let mut recorded = Vec::new();
+    /// for _ in 0..100 {
+    ///     recorded.extend(Vec::<Input>::from(receiver.next_event().unwrap()));
+    /// }
+    ///
+    /// winput::send_inputs(&recorded);
+    /// ```
+    ///
+    /// [`Event`]: enum.Event.html
+    /// [`Input`]: ../struct.Input.html
+    #[inline]
+    fn from(event: Event) -> Self {
+        Input::try_from(event).into_iter().collect()
+    }
 }
 
-// Only one instance of `EventReceiver` can be created at any given time.
-// That only instance relies on `STATE` and `SENDER` that is only initialized
-// when `STATE` is `2`.
-//
 /// The result of the [`start`] function. This structure receives the messages
 /// received by the message loop.
 ///
-/// The message loop is automatically stopped when this structure is dropped.
+/// The message loop is automatically stopped when the last receiver is dropped.
 ///
 /// [`start`]: fn.start.html
+#[derive(Debug)]
 pub struct EventReceiver {
-    receiver: mpsc::Receiver<Event>,
+    /// The identifier of the `Subscriber` associated with this receiver.
+    id: u64,
+    channel: Arc<Channel>,
 }
 
 impl EventReceiver {
     /// Discard all the events stored in the receiver.
     #[inline]
     pub fn clear(&self) {
-        if is_active() {
-            while let Some(_) = self.try_next_event() {}
-        }
+        self.channel.lock().events.clear();
     }
 
     /// Blocks the current thread until an event is received.
+    ///
+    /// A [`DisconnectedError`] is returned once the message loop has stopped and every
+    /// waiting event has been received.
+    ///
+    /// [`DisconnectedError`]: struct.DisconnectedError.html
     #[inline]
-    pub fn next_event(&self) -> Event {
-        self.receiver
-            .recv()
-            .expect("The message loop is not active")
+    pub fn next_event(&self) -> Result<Event, DisconnectedError> {
+        self.channel
+            .recv_deadline(None)
+            .map_err(|_| DisconnectedError)
     }
 
     /// Blocks the current thread until an event is received or the given
     /// duration is reached.
+    ///
+    /// A [`DisconnectedError`] is returned once the message loop has stopped and every
+    /// waiting event has been received.
+    ///
+    /// [`DisconnectedError`]: struct.DisconnectedError.html
     #[inline]
-    pub fn next_event_timeout(&self, timeout: Duration) -> Option<Event> {
-        match self.receiver.recv_timeout(timeout) {
-            Ok(val) => Some(val),
-            Err(mpsc::RecvTimeoutError::Timeout) => None,
-            Err(mpsc::RecvTimeoutError::Disconnected) => {
-                panic!("The message loop is not active")
-            }
+    pub fn next_event_timeout(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Event>, DisconnectedError> {
+        match self.channel.recv_deadline(Some(Instant::now() + timeout)) {
+            Ok(val) => Ok(Some(val)),
+            Err(RecvError::Empty) => Ok(None),
+            Err(RecvError::Disconnected) => Err(DisconnectedError),
         }
     }
 
     /// Tries to receive an event without blocking the thread.
+    ///
+    /// A [`DisconnectedError`] is returned once the message loop has stopped and every
+    /// waiting event has been received.
+    ///
+    /// [`DisconnectedError`]: struct.DisconnectedError.html
+    #[inline]
+    pub fn try_next_event(&self) -> Result<Option<Event>, DisconnectedError> {
+        match self.channel.try_recv() {
+            Ok(val) => Ok(Some(val)),
+            Err(RecvError::Empty) => Ok(None),
+            Err(RecvError::Disconnected) => Err(DisconnectedError),
+        }
+    }
+
+    /// Checks whether the message loop may still send events to this receiver. Once
+    /// it returns `false`, the events that are still waiting can be received, but no
+    /// other event will arrive.
+    #[inline]
+    pub fn is_connected(&self) -> bool {
+        self.channel.lock().connected
+    }
+
+    /// Returns a [crossbeam channel] that receives a message whenever events are sent
+    /// to this receiver, so that they can be waited for with [`select!`] along with
+    /// other channels and timers. The channel is disconnected once the message loop
+    /// has stopped.
+    ///
+    /// The channel only holds one message at a time, which may stand for any number
+    /// of events: every waiting event should be received after each message, for
+    /// example with [`drain`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use crossbeam_channel::{select, tick};
+    /// use std::time::Duration;
+    /// use winput::message_loop;
+    ///
+    /// let receiver = message_loop::start().unwrap();
+    /// let ticks = tick(Duration::from_secs(1));
+    ///
+    /// loop {
+    ///     select! {
+    ///         recv(receiver.ready()) -> message => {
+    ///             for event in receiver.drain() {
+    ///                 println!("{:?}", event);
+    ///             }
+    ///
+    ///             if message.is_err() {
+    ///                 break;
+    ///             }
+    ///         }
+    ///         recv(ticks) -> _ => println!("tick"),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [crossbeam channel]: https://docs.rs/crossbeam-channel/0.5/crossbeam_channel/struct.Receiver.html
+    /// [`select!`]: https://docs.rs/crossbeam-channel/0.5/crossbeam_channel/macro.select.html
+    /// [`drain`]: struct.EventReceiver.html#method.drain
+    #[cfg(feature = "crossbeam")]
+    #[inline]
+    pub fn ready(&self) -> &crossbeam_channel::Receiver<()> {
+        &self.channel.ready
+    }
+
+    /// Takes every event waiting in the receiver without blocking the thread, in the
+    /// order they were received.
+    ///
+    /// Once the message loop has stopped, the events that were still waiting are
+    /// returned, and then nothing (see [`is_connected`]).
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::message_loop;
+    ///
+    /// let receiver = message_loop::start().unwrap();
+    ///
+    /// loop {
+    ///     for event in receiver.drain() {
+    ///         // Handle the events received since the last frame.
+    ///     }
+    ///
+    ///     // Update and render the frame.
+    /// }
+    /// ```
+    ///
+    /// [`is_connected`]: struct.EventReceiver.html#method.is_connected
+    #[inline]
+    pub fn drain(&self) -> Vec<Event> {
+        self.channel.lock().events.drain(..).collect()
+    }
+
+    /// Returns an iterator over the events that are waiting in the receiver. The
+    /// iterator never blocks the thread: it ends as soon as no event is waiting, or
+    /// once the message loop has stopped.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::message_loop::{self, Event};
+    ///
+    /// let receiver = message_loop::start().unwrap();
+    ///
+    /// let clicks = receiver
+    ///     .try_iter()
+    ///     .filter(|e| matches!(e, Event::MouseButton { .. }))
+    ///     .count();
+    /// ```
+    #[inline]
+    pub fn try_iter(&self) -> TryIter<'_> {
+        TryIter { receiver: self }
+    }
+
+    /// Returns the number of events that were discarded because the channel of this
+    /// receiver was full (see [`Options::capacity`]).
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::message_loop::{self, Options};
+    ///
+    /// let receiver = message_loop::start_with_options(Options::new().capacity(64)).unwrap();
+    ///
+    /// // ...
+    ///
+    /// if receiver.dropped_events() != 0 {
+    ///     println!("{} events were lost", receiver.dropped_events());
+    /// }
+    /// ```
+    ///
+    /// [`Options::capacity`]: struct.Options.html#method.capacity
+    #[inline]
+    pub fn dropped_events(&self) -> u64 {
+        self.channel.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Returns an iterator that blocks the current thread until an event is received,
+    /// and ends when the message loop stops.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::message_loop;
+    ///
+    /// let receiver = message_loop::start().unwrap();
+    ///
+    /// for event in receiver.iter().take(10) {
+    ///     println!("{:?}", event);
+    /// }
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter { receiver: self }
+    }
+
+    /// Converts this receiver into an asynchronous [`Stream`] of events, which ends
+    /// when the message loop stops.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use futures::StreamExt;
+    /// use winput::message_loop;
+    ///
+    /// let mut events = message_loop::start().unwrap().into_stream();
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     println!("{:?}", event);
+    /// }
+    /// ```
+    ///
+    /// [`Stream`]: https://docs.rs/futures-core/0.3/futures_core/stream/trait.Stream.html
+    #[cfg(feature = "async")]
+    #[inline]
+    pub fn into_stream(self) -> EventStream {
+        EventStream {
+            receiver: self,
+            terminated: false,
+        }
+    }
+}
+
+impl Iterator for EventReceiver {
+    type Item = Event;
+
+    /// Blocks the current thread until an event is received. `None` is returned once
+    /// the message loop has stopped.
+    #[inline]
+    fn next(&mut self) -> Option<Event> {
+        self.channel.recv_deadline(None).ok()
+    }
+}
+
+impl<'a> IntoIterator for &'a EventReceiver {
+    type Item = Event;
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// The error returned by the functions of an [`EventReceiver`] once the message loop
+/// has stopped, either because [`stop`] was called or because the message loop's
+/// thread failed.
+///
+/// [`EventReceiver`]: struct.EventReceiver.html
+/// [`stop`]: fn.stop.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DisconnectedError;
+
+impl fmt::Display for DisconnectedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the message loop has stopped")
+    }
+}
+
+impl Error for DisconnectedError {}
+
+/// An iterator over the events of an [`EventReceiver`], created by the
+/// [`EventReceiver::iter`] function.
+///
+/// [`EventReceiver`]: struct.EventReceiver.html
+/// [`EventReceiver::iter`]: struct.EventReceiver.html#method.iter
+#[derive(Debug)]
+pub struct Iter<'a> {
+    receiver: &'a EventReceiver,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = Event;
+
     #[inline]
-    pub fn try_next_event(&self) -> Option<Event> {
-        match self.receiver.try_recv() {
-            Ok(val) => Some(val),
-            Err(mpsc::TryRecvError::Empty) => None,
-            Err(mpsc::TryRecvError::Disconnected) => {
-                panic!("The message loop is not active")
+    fn next(&mut self) -> Option<Event> {
+        self.receiver.channel.recv_deadline(None).ok()
+    }
+}
+
+/// An iterator over the events waiting in an [`EventReceiver`], created by the
+/// [`EventReceiver::try_iter`] function.
+///
+/// [`EventReceiver`]: struct.EventReceiver.html
+/// [`EventReceiver::try_iter`]: struct.EventReceiver.html#method.try_iter
+#[derive(Debug)]
+pub struct TryIter<'a> {
+    receiver: &'a EventReceiver,
+}
+
+impl Iterator for TryIter<'_> {
+    type Item = Event;
+
+    #[inline]
+    fn next(&mut self) -> Option<Event> {
+        self.receiver.channel.try_recv().ok()
+    }
+}
+
+/// An asynchronous stream of the events received by the message loop, created by the
+/// [`EventReceiver::into_stream`] function.
+///
+/// Dropping this structure drops the underlying [`EventReceiver`].
+///
+/// [`EventReceiver`]: struct.EventReceiver.html
+///
+/// [`EventReceiver::into_stream`]: struct.EventReceiver.html#method.into_stream
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub struct EventStream {
+    receiver: EventReceiver,
+    terminated: bool,
+}
+
+#[cfg(feature = "async")]
+impl EventStream {
+    /// Returns the `EventReceiver` this stream was created from.
+    #[inline]
+    pub fn into_inner(self) -> EventReceiver {
+        self.receiver
+    }
+}
+
+#[cfg(feature = "async")]
+impl futures_core::Stream for EventStream {
+    type Item = Event;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Event>> {
+        let this = self.get_mut();
+
+        if this.terminated {
+            return Poll::Ready(None);
+        }
+
+        // The waker is registered before checking the channel a second time, so that
+        // an event sent in between is never missed.
+        let channel = &this.receiver.channel;
+        for registered in [false, true].iter() {
+            match channel.try_recv() {
+                Ok(event) => return Poll::Ready(Some(event)),
+                Err(RecvError::Disconnected) => {
+                    this.terminated = true;
+                    return Poll::Ready(None);
+                }
+                Err(RecvError::Empty) if !registered => {
+                    *channel.waker.lock().unwrap() = Some(cx.waker().clone());
+                }
+                Err(RecvError::Empty) => (),
             }
         }
+
+        Poll::Pending
     }
+}
 
-    // TODO: add `next_event_deadline` when `Reciever::recv_deadline` is stable.
+#[cfg(feature = "async")]
+impl futures_core::FusedStream for EventStream {
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
 }
 
 impl Drop for EventReceiver {
     fn drop(&mut self) {
-        // Stop the message loop.
-        stop();
+        let mut subscribers = lock_subscribers();
+        subscribers.retain(|subscriber| subscriber.id != self.id);
+
+        if STATE.load(Ordering::SeqCst) == 2 {
+            if subscribers.is_empty() {
+                // Stop the message loop if nobody is listening anymore.
+                stop_locked(subscribers);
+            } else {
+                update_devices();
+            }
+        }
     }
 }
 
 /// Stops the message loop.
 ///
-/// After calling this function, using the `EventReceiver` will always result
-/// in a panic.
+/// After calling this function, the functions of every `EventReceiver` return a
+/// [`DisconnectedError`] once the events that were still waiting have been received.
 ///
-/// Be careful, if another libary already created a message loop, this function will
-/// still stop it.
+/// Be careful, the message loop is shared by every `EventReceiver`. If another
+/// component (or another library) is also receiving events, this function will still
+/// stop the message loop for it.
+///
+/// [`DisconnectedError`]: struct.DisconnectedError.html
 pub fn stop() {
-    if !is_active() {
+    let mut subscribers = lock_subscribers();
+    if STATE.load(Ordering::SeqCst) != 2 {
         return;
     }
 
-    // If the `EventReceiver` was able to be constructed,
-    // that means that `STATE` is currently `2`.
+    // Dropping the subscribers disconnects the receivers.
+    subscribers.clear();
+    stop_locked(subscribers);
+}
+
+/// Stops the message loop, given the locked list of its subscribers. The lock is
+/// released while waiting for the message loop's thread to exit.
+fn stop_locked(subscribers: MutexGuard<'static, Vec<Subscriber>>) {
     STATE.store(3, Ordering::SeqCst);
 
+    // Wake the message loop up so that it notices it has to exit.
+    let hwnd = HWND.load(Ordering::SeqCst) as windef::HWND;
+    // Calling C code
+    unsafe { winuser::PostMessageW(hwnd, winuser::WM_NULL, 0, 0) };
+
     // Cleaning up the static variables is up to the message loop thread.
     // We just have to wait until it finishes.
-    while STATE.load(Ordering::Acquire) != 0 {
-        std::hint::spin_loop();
-    }
+    drop(wait_until_stopped(subscribers));
 }
@@ -0,0 +1,418 @@
+use crate::vk::Vk;
+
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not, Sub, SubAssign};
+
+/// A set of modifier keys.
+///
+/// Each modifier exists in a generic form (such as [`Modifiers::SHIFT`]) and in two
+/// sided forms (such as [`Modifiers::LEFT_SHIFT`] and [`Modifiers::RIGHT_SHIFT`]). A
+/// sided modifier always contains its generic form: this means that checking whether
+/// a set contains `SHIFT` is true if either of the SHIFT keys is part of the set, and
+/// removing `SHIFT` from a set removes both of its sides.
+///
+/// ## Example
+///
+/// ```rust
+/// use winput::Modifiers;
+///
+/// let modifiers = Modifiers::CONTROL | Modifiers::LEFT_SHIFT;
+///
+/// assert!(modifiers.contains(Modifiers::CONTROL));
+/// assert!(modifiers.contains(Modifiers::SHIFT));
+/// assert!(!modifiers.contains(Modifiers::RIGHT_SHIFT));
+/// assert!(!modifiers.contains(Modifiers::ALT));
+/// ```
+///
+/// [`Modifiers::SHIFT`]: struct.Modifiers.html#associatedconstant.SHIFT
+/// [`Modifiers::LEFT_SHIFT`]: struct.Modifiers.html#associatedconstant.LEFT_SHIFT
+/// [`Modifiers::RIGHT_SHIFT`]: struct.Modifiers.html#associatedconstant.RIGHT_SHIFT
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Modifiers(u16);
+
+/// The bits of the generic modifiers.
+const GENERIC: u16 = 0x000f;
+/// The bits of the sided modifiers.
+const SIDED: u16 = 0x0ff0;
+
+/// Describes a modifier: its generic flag, its sided flags and the Virtual-Key Codes
+/// used to synthesize them.
+///
+/// The order of this table is the order in which modifiers are pressed.
+const MODIFIER_KEYS: [(Modifiers, Vk, Modifiers, Vk, Modifiers, Vk); 4] = [
+    (
+        Modifiers::CONTROL,
+        Vk::Control,
+        Modifiers::LEFT_CONTROL,
+        Vk::LeftControl,
+        Modifiers::RIGHT_CONTROL,
+        Vk::RightControl,
+    ),
+    (
+        Modifiers::ALT,
+        Vk::Alt,
+        Modifiers::LEFT_ALT,
+        Vk::LeftMenu,
+        Modifiers::RIGHT_ALT,
+        Vk::RightMenu,
+    ),
+    (
+        Modifiers::SHIFT,
+        Vk::Shift,
+        Modifiers::LEFT_SHIFT,
+        Vk::LeftShift,
+        Modifiers::RIGHT_SHIFT,
+        Vk::RightShift,
+    ),
+    (
+        Modifiers::WIN,
+        Vk::LeftWin,
+        Modifiers::LEFT_WIN,
+        Vk::LeftWin,
+        Modifiers::RIGHT_WIN,
+        Vk::RightWin,
+    ),
+];
+
+impl Modifiers {
+    /// Any of the SHIFT keys.
+    pub const SHIFT: Self = Self(0x0001);
+    /// Any of the CTRL keys.
+    pub const CONTROL: Self = Self(0x0002);
+    /// Any of the ALT keys.
+    pub const ALT: Self = Self(0x0004);
+    /// Any of the Windows keys.
+    pub const WIN: Self = Self(0x0008);
+
+    /// The left SHIFT key.
+    pub const LEFT_SHIFT: Self = Self(0x0010 | Self::SHIFT.0);
+    /// The right SHIFT key.
+    pub const RIGHT_SHIFT: Self = Self(0x0020 | Self::SHIFT.0);
+    /// The left CTRL key.
+    pub const LEFT_CONTROL: Self = Self(0x0040 | Self::CONTROL.0);
+    /// The right CTRL key.
+    pub const RIGHT_CONTROL: Self = Self(0x0080 | Self::CONTROL.0);
+    /// The left ALT key.
+    pub const LEFT_ALT: Self = Self(0x0100 | Self::ALT.0);
+    /// The right ALT key (also called ALT GR on some layouts).
+    pub const RIGHT_ALT: Self = Self(0x0200 | Self::ALT.0);
+    /// The left Windows key.
+    pub const LEFT_WIN: Self = Self(0x0400 | Self::WIN.0);
+    /// The right Windows key.
+    pub const RIGHT_WIN: Self = Self(0x0800 | Self::WIN.0);
+
+    /// Creates an empty set of modifiers.
+    #[inline(always)]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Creates a set that contains every modifier.
+    #[inline(always)]
+    pub const fn all() -> Self {
+        Self(GENERIC | SIDED)
+    }
+
+    /// Returns the raw bits of this set of modifiers.
+    #[inline(always)]
+    pub const fn bits(self) -> u16 {
+        self.0
+    }
+
+    /// Creates a set of modifiers from raw bits. Bits that do not correspond to any
+    /// modifier are ignored.
+    #[inline(always)]
+    pub const fn from_bits_truncate(bits: u16) -> Self {
+        Self::normalized(bits & Self::all().0)
+    }
+
+    /// Ensures that the generic flag of every sided modifier of `bits` is set.
+    const fn normalized(bits: u16) -> Self {
+        let sided = bits & SIDED;
+        let mut generic = 0;
+        // Each generic modifier has two sided modifiers stored in consecutive bits.
+        // SHIFT, CONTROL, ALT and WIN are stored in that order in both the generic and
+        // the sided bits.
+        let mut i = 0;
+        while i < 4 {
+            if sided & (0b11 << (4 + i * 2)) != 0 {
+                generic |= 1 << i;
+            }
+            i += 1;
+        }
+
+        Self(bits | generic)
+    }
+
+    /// Returns the bits to clear in order to remove `bits` from a set: a generic
+    /// modifier given without any of its sides removes both of its sides.
+    const fn removed_bits(bits: u16) -> u16 {
+        let mut removed = bits;
+        let mut i = 0;
+        while i < 4 {
+            let sides = 0b11 << (4 + i * 2);
+            if bits & (1 << i) != 0 && bits & sides == 0 {
+                removed |= sides;
+            }
+            i += 1;
+        }
+
+        removed
+    }
+
+    /// Checks whether this set does not contain any modifier.
+    #[inline(always)]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Checks whether this set contains every modifier of `other`.
+    #[inline(always)]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Checks whether this set contains at least one of the modifiers of `other`.
+    #[inline(always)]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Adds the modifiers of `other` to this set.
+    #[inline(always)]
+    pub fn insert(&mut self, other: Self) {
+        *self |= other;
+    }
+
+    /// Removes the modifiers of `other` from this set.
+    #[inline(always)]
+    pub fn remove(&mut self, other: Self) {
+        *self -= other;
+    }
+
+    /// Removes the information about which side of each modifier is part of this set,
+    /// only keeping the generic modifiers.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Modifiers;
+    ///
+    /// let modifiers = Modifiers::LEFT_SHIFT | Modifiers::RIGHT_ALT;
+    /// assert_eq!(modifiers.to_generic(), Modifiers::SHIFT | Modifiers::ALT);
+    /// ```
+    #[inline(always)]
+    pub const fn to_generic(self) -> Self {
+        Self(self.0 & GENERIC)
+    }
+
+    /// Returns the modifier associated with the given Virtual-Key Code, if the key is a
+    /// modifier key.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::{Modifiers, Vk};
+    ///
+    /// assert_eq!(Modifiers::from_vk(Vk::Control), Some(Modifiers::CONTROL));
+    /// assert_eq!(Modifiers::from_vk(Vk::RightMenu), Some(Modifiers::RIGHT_ALT));
+    /// assert_eq!(Modifiers::from_vk(Vk::A), None);
+    /// ```
+    pub fn from_vk(vk: Vk) -> Option<Self> {
+        MODIFIER_KEYS.iter().find_map(
+            |&(generic, generic_vk, left, left_vk, right, right_vk)| {
+                if vk == left_vk {
+                    Some(left)
+                } else if vk == right_vk {
+                    Some(right)
+                } else if vk == generic_vk {
+                    Some(generic)
+                } else {
+                    None
+                }
+            },
+        )
+    }
+
+    /// Retrieves the modifiers that are currently held down, as seen by the calling
+    /// thread. The returned set contains the side of every held modifier.
+    ///
+    /// This function relies on the native `GetKeyState` function (see
+    /// [`Vk::is_down_sync`]).
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Modifiers;
+    ///
+    /// if Modifiers::current().contains(Modifiers::CONTROL) {
+    ///     println!("CTRL is down");
+    /// }
+    /// ```
+    ///
+    /// [`Vk::is_down_sync`]: enum.Vk.html#method.is_down_sync
+    #[inline]
+    pub fn current() -> Self {
+        Self::snapshot(Vk::is_down_sync)
+    }
+
+    /// Retrieves the modifiers that are physically held down at the time of the call.
+    ///
+    /// This function relies on the native `GetAsyncKeyState` function (see
+    /// [`Vk::is_down`]).
+    ///
+    /// [`Vk::is_down`]: enum.Vk.html#method.is_down
+    #[inline]
+    pub fn current_async() -> Self {
+        Self::snapshot(Vk::is_down)
+    }
+
+    /// Creates a set of the sided modifiers for which `is_down` returns `true`.
+    fn snapshot(is_down: fn(Vk) -> bool) -> Self {
+        let mut modifiers = Self::empty();
+
+        for &(_, _, left, left_vk, right, right_vk) in MODIFIER_KEYS.iter() {
+            if is_down(left_vk) {
+                modifiers |= left;
+            }
+            if is_down(right_vk) {
+                modifiers |= right;
+            }
+        }
+
+        modifiers
+    }
+
+    /// Returns an iterator over the Virtual-Key Codes that must be pressed to produce
+    /// this set of modifiers.
+    ///
+    /// Sided modifiers produce their sided key. Generic modifiers produce their generic
+    /// key (the left key for the Windows key, which has no generic Virtual-Key Code).
+    ///
+    /// Keys are yielded in the order they should be pressed. Reverse the iterator to get
+    /// the order in which they should be released.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::{Modifiers, Vk};
+    ///
+    /// let modifiers = Modifiers::SHIFT | Modifiers::RIGHT_CONTROL;
+    /// let keys: Vec<Vk> = modifiers.keys().collect();
+    ///
+    /// assert_eq!(keys, [Vk::RightControl, Vk::Shift]);
+    /// ```
+    pub fn keys(self) -> impl DoubleEndedIterator<Item = Vk> {
+        MODIFIER_KEYS.iter().flat_map(
+            move |&(generic, generic_vk, left, left_vk, right, right_vk)| {
+                let has_left = self.0 & left.0 & SIDED != 0;
+                let has_right = self.0 & right.0 & SIDED != 0;
+                let has_generic = self.contains(generic) && !has_left && !has_right;
+
+                let key = |is_set: bool, vk: Vk| if is_set { Some(vk) } else { None };
+
+                key(has_generic, generic_vk)
+                    .into_iter()
+                    .chain(key(has_left, left_vk))
+                    .chain(key(has_right, right_vk))
+            },
+        )
+    }
+}
+
+impl BitOr for Modifiers {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for Modifiers {
+    #[inline(always)]
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
+
+impl BitAnd for Modifiers {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitand(self, other: Self) -> Self {
+        Self::normalized(self.0 & other.0)
+    }
+}
+
+impl BitAndAssign for Modifiers {
+    #[inline(always)]
+    fn bitand_assign(&mut self, other: Self) {
+        *self = *self & other;
+    }
+}
+
+impl Sub for Modifiers {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self {
+        Self::normalized(self.0 & !Self::removed_bits(other.0))
+    }
+}
+
+impl SubAssign for Modifiers {
+    #[inline(always)]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Not for Modifiers {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self {
+        Self::all() - self
+    }
+}
+
+impl fmt::Debug for Modifiers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const NAMES: [(Modifiers, &str); 12] = [
+            (Modifiers::LEFT_CONTROL, "LEFT_CONTROL"),
+            (Modifiers::RIGHT_CONTROL, "RIGHT_CONTROL"),
+            (Modifiers::CONTROL, "CONTROL"),
+            (Modifiers::LEFT_ALT, "LEFT_ALT"),
+            (Modifiers::RIGHT_ALT, "RIGHT_ALT"),
+            (Modifiers::ALT, "ALT"),
+            (Modifiers::LEFT_SHIFT, "LEFT_SHIFT"),
+            (Modifiers::RIGHT_SHIFT, "RIGHT_SHIFT"),
+            (Modifiers::SHIFT, "SHIFT"),
+            (Modifiers::LEFT_WIN, "LEFT_WIN"),
+            (Modifiers::RIGHT_WIN, "RIGHT_WIN"),
+            (Modifiers::WIN, "WIN"),
+        ];
+
+        if self.is_empty() {
+            return f.write_str("(empty)");
+        }
+
+        let mut first = true;
+        let mut written = Modifiers::empty();
+        for &(m, name) in NAMES.iter() {
+            // Generic modifiers are only written when none of their sides were.
+            if self.contains(m) && !written.contains(m) {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_str(name)?;
+                written |= m;
+                first = false;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,11 +1,34 @@
 use crate::error::WindowsError;
+use crate::input::{check_sending_enabled, normalize_pixel, screen_bounds, Button};
+use crate::vk::Vk;
+use crate::window::{Monitor, Rect};
+
+#[cfg(feature = "message_loop")]
+use crate::message_loop::{self, EventReceiver, MessageLoopError, Options};
+
+#[cfg(not(feature = "minimal"))]
+use crate::input::{
+    send_inputs_with_pacing, try_send_inputs, Action, Input, MouseMotion, WheelDirection,
+};
+#[cfg(not(feature = "minimal"))]
+use crate::modifiers::Modifiers;
+#[cfg(not(feature = "minimal"))]
+use crate::path::{Easing, MousePath};
+
+use std::marker::PhantomData;
+#[cfg(feature = "message_loop")]
+use std::sync::atomic::Ordering;
+use std::time::Duration;
 
 #[cfg(not(feature = "minimal"))]
-use crate::input::{send_inputs, Input, MouseMotion, WheelDirection};
+use std::convert::TryFrom;
 
 use winapi::shared::windef;
 use winapi::um::winuser;
 
+/// The error code used by Windows when a window handle is invalid.
+const ERROR_INVALID_WINDOW_HANDLE: u32 = 1400;
+
 /// A zero-sized structure that wraps functions related to the mouse.
 pub struct Mouse;
 
@@ -32,8 +55,232 @@ impl Mouse {
         }
     }
 
+    /// Retrieves the position of the mouse, in physical screen coordinates.
+    ///
+    /// Unless the calling process is per-monitor DPI aware, the coordinates returned by
+    /// [`Mouse::position`] are scaled by the system, which makes them inaccurate on
+    /// setups that mix monitors with different scale factors. Physical coordinates are
+    /// the actual pixels of the monitors and are never scaled.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// println!("The mouse is at {:?}", Mouse::position_physical());
+    /// ```
+    ///
+    /// [`Mouse::position`]: struct.Mouse.html#method.position
+    pub fn position_physical() -> Result<(i32, i32), WindowsError> {
+        unsafe {
+            let mut point: windef::POINT = std::mem::zeroed();
+
+            // Calling C code
+            if winuser::GetPhysicalCursorPos(&mut point) != 0 {
+                Ok((point.x, point.y))
+            } else {
+                Err(WindowsError::from_last_error())
+            }
+        }
+    }
+
+    /// Converts a point from physical screen coordinates to the logical coordinates of
+    /// the window under that point, whatever the DPI awareness of the calling process
+    /// is.
+    ///
+    /// This function relies on the native `PhysicalToLogicalPointForPerMonitorDPI`
+    /// function, available since Windows 8.1.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// let (x, y) = Mouse::position_physical().unwrap();
+    /// println!("{:?}", Mouse::physical_to_logical(x, y));
+    /// ```
+    pub fn physical_to_logical(x: i32, y: i32) -> Result<(i32, i32), WindowsError> {
+        let mut point = windef::POINT { x, y };
+
+        unsafe {
+            // Calling C code
+            let hwnd = winuser::WindowFromPhysicalPoint(point);
+            if hwnd.is_null() {
+                return Err(WindowsError::from_error_code(ERROR_INVALID_WINDOW_HANDLE));
+            }
+
+            // Calling C code
+            if winuser::PhysicalToLogicalPointForPerMonitorDPI(hwnd, &mut point) == 0 {
+                return Err(WindowsError::from_last_error());
+            }
+        }
+
+        Ok((point.x, point.y))
+    }
+
+    /// Converts a point from the logical coordinates of the window under that point to
+    /// physical screen coordinates, whatever the DPI awareness of the calling process
+    /// is.
+    ///
+    /// This function relies on the native `LogicalToPhysicalPointForPerMonitorDPI`
+    /// function, available since Windows 8.1.
+    pub fn logical_to_physical(x: i32, y: i32) -> Result<(i32, i32), WindowsError> {
+        let mut point = windef::POINT { x, y };
+
+        unsafe {
+            // Calling C code
+            let hwnd = winuser::WindowFromPoint(point);
+            if hwnd.is_null() {
+                return Err(WindowsError::from_error_code(ERROR_INVALID_WINDOW_HANDLE));
+            }
+
+            // Calling C code
+            if winuser::LogicalToPhysicalPointForPerMonitorDPI(hwnd, &mut point) == 0 {
+                return Err(WindowsError::from_last_error());
+            }
+        }
+
+        Ok((point.x, point.y))
+    }
+
+    /// Saves the current position of the mouse, and returns a guard that moves the mouse
+    /// back to that position when dropped, including when the thread panics.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Button, Mouse};
+    ///
+    /// {
+    ///     let _position = Mouse::save_position().unwrap();
+    ///     Mouse::set_position(1200, 20).unwrap();
+    ///     Mouse::click(Button::Left);
+    /// } // The mouse is moved back here
+    /// ```
+    pub fn save_position() -> Result<PositionGuard, WindowsError> {
+        let (x, y) = Self::position()?;
+        Ok(PositionGuard { x, y })
+    }
+
+    /// Retrieve the current position of the mouse, in the normalized coordinates used
+    /// by [`MouseMotion::Absolute`].
+    ///
+    /// When `virtual_desk` is `true`, the coordinates are relative to the whole virtual
+    /// desktop. Otherwise, they are relative to the primary monitor.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// let (x, y) = Mouse::position_normalized(true).unwrap();
+    /// println!("The mouse is at {:.1}% / {:.1}%", x * 100.0, y * 100.0);
+    /// ```
+    ///
+    /// [`MouseMotion::Absolute`]: enum.MouseMotion.html#variant.Absolute
+    pub fn position_normalized(virtual_desk: bool) -> Result<(f32, f32), WindowsError> {
+        let (x, y) = Self::position()?;
+        Ok(Self::pixels_to_normalized(x, y, virtual_desk))
+    }
+
+    /// Converts a position in screen coordinates into the normalized coordinates used by
+    /// [`MouseMotion::Absolute`].
+    ///
+    /// When `virtual_desk` is `true`, the coordinates are relative to the whole virtual
+    /// desktop (including monitors with negative coordinates). Otherwise, they are
+    /// relative to the primary monitor. Positions outside of that area are clamped.
+    ///
+    /// The returned coordinates, once sent as an absolute motion with the same
+    /// `virtual_desk` value, move the cursor to exactly the given pixel.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Mouse, MouseMotion};
+    ///
+    /// let (x, y) = Mouse::pixels_to_normalized(-800, 300, true);
+    /// let motion = MouseMotion::Absolute { x, y, virtual_desk: true };
+    /// ```
+    ///
+    /// [`MouseMotion::Absolute`]: enum.MouseMotion.html#variant.Absolute
+    pub fn pixels_to_normalized(x: i32, y: i32, virtual_desk: bool) -> (f32, f32) {
+        /// Converts a normalized coordinate in `0..=65535` into the `0.0..=1.0` range.
+        /// Half a unit is added so that the coordinate is not truncated to the
+        /// previous unit when converted back.
+        fn to_unit(n: i32) -> f32 {
+            ((n as f32 + 0.5) / 65535.0).min(1.0)
+        }
+
+        let (left, top, width, height) = screen_bounds(virtual_desk);
+        (
+            to_unit(normalize_pixel(x - left, width)),
+            to_unit(normalize_pixel(y - top, height)),
+        )
+    }
+
+    /// Converts normalized coordinates, as used by [`MouseMotion::Absolute`], into a
+    /// position in screen coordinates.
+    ///
+    /// When `virtual_desk` is `true`, the coordinates are relative to the whole virtual
+    /// desktop. Otherwise, they are relative to the primary monitor.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// // The center of the primary monitor.
+    /// let (x, y) = Mouse::normalized_to_pixels(0.5, 0.5, false);
+    /// ```
+    ///
+    /// [`MouseMotion::Absolute`]: enum.MouseMotion.html#variant.Absolute
+    pub fn normalized_to_pixels(x: f32, y: f32, virtual_desk: bool) -> (i32, i32) {
+        /// Maps a normalized coordinate the way the system does.
+        fn to_pixel(n: f32, size: i32) -> i32 {
+            let n = i64::from((n.clamp(0.0, 1.0) * 65535.0) as i32);
+            (n * i64::from(size) / 65536) as i32
+        }
+
+        let (left, top, width, height) = screen_bounds(virtual_desk);
+        (left + to_pixel(x, width), top + to_pixel(y, height))
+    }
+
+    /// Checks whether the given physical mouse button is currently held down.
+    ///
+    /// This function relies on the native `GetAsyncKeyState` function, which reports
+    /// the state of the physical buttons regardless of whether the user swapped them.
+    /// Use [`Mouse::is_logical_button_down`] to take that setting into account.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Button, Mouse};
+    ///
+    /// if Mouse::is_button_down(Button::Left) {
+    ///     println!("The left button is down");
+    /// }
+    /// ```
+    ///
+    /// [`Mouse::is_logical_button_down`]: struct.Mouse.html#method.is_logical_button_down
+    #[inline]
+    pub fn is_button_down(button: Button) -> bool {
+        button.to_vk().is_down()
+    }
+
+    /// Checks whether the button the user considers primary or secondary is currently
+    /// held down (see [`LogicalButton`]).
+    ///
+    /// [`LogicalButton`]: enum.LogicalButton.html
+    #[inline]
+    pub fn is_logical_button_down(button: LogicalButton) -> bool {
+        Self::is_button_down(button.to_physical())
+    }
+
     /// Sets the position of the mouse, in screen coordinates.
     ///
+    /// This function fails with `ERROR_CANCELLED` while sending is disabled (see
+    /// [`set_sending_enabled`]).
+    ///
     /// ## Example
     ///
     /// ```rust, ignore
@@ -41,7 +288,11 @@ impl Mouse {
     ///
     /// Mouse::set_position(50, 50).unwrap();
     /// ```
+    ///
+    /// [`set_sending_enabled`]: fn.set_sending_enabled.html
     pub fn set_position(x: i32, y: i32) -> Result<(), WindowsError> {
+        check_sending_enabled()?;
+
         unsafe {
             // Calling C code
             if winuser::SetCursorPos(x, y) == 0 {
@@ -52,87 +303,1309 @@ impl Mouse {
         }
     }
 
-    /// Synthesizes a vertical scroll event.
+    /// Sets the position of the mouse, in physical screen coordinates (see
+    /// [`Mouse::position_physical`]).
     ///
-    /// If the function fails to synthesize the input, no error is emited and the
-    /// function fails silently. If you wish to retreive an eventual error, use
-    /// `send_inputs` instead.
+    /// This function fails with `ERROR_CANCELLED` while sending is disabled (see
+    /// [`set_sending_enabled`]).
     ///
     /// ## Example
     ///
     /// ```rust, ignore
     /// use winput::Mouse;
     ///
-    /// Mouse::scroll(1.0).unwrap();
+    /// Mouse::set_position_physical(1920, 540).unwrap();
     /// ```
-    #[cfg(not(feature = "minimal"))]
-    pub fn scroll(amount: f32) {
-        let input = Input::from_wheel(amount, WheelDirection::Vertical);
-        send_inputs(&[input]);
+    ///
+    /// [`Mouse::position_physical`]: struct.Mouse.html#method.position_physical
+    /// [`set_sending_enabled`]: fn.set_sending_enabled.html
+    pub fn set_position_physical(x: i32, y: i32) -> Result<(), WindowsError> {
+        check_sending_enabled()?;
+
+        unsafe {
+            // Calling C code
+            if winuser::SetPhysicalCursorPos(x, y) == 0 {
+                Err(WindowsError::from_last_error())
+            } else {
+                Ok(())
+            }
+        }
     }
 
-    /// Synthesizes a horizontal scroll event.
+    /// Confines the cursor to the given rectangle, in screen coordinates, until the
+    /// returned guard is dropped.
     ///
-    /// If the function fails to synthesize the input, no error is emited and the
-    /// function fails silently. If you wish to retreive an eventual error, use
-    /// `send_inputs` instead.
+    /// The cursor is released when the guard is dropped, including when the thread
+    /// panics. Note that the system may also release the cursor on its own, for example
+    /// when the user switches to another application.
     ///
     /// ## Example
     ///
     /// ```rust, ignore
     /// use winput::Mouse;
+    /// use winput::window::Rect;
     ///
-    /// Mouse::scrollh(1.0).unwrap();
+    /// let rect = Rect { left: 0, top: 0, right: 800, bottom: 600 };
+    /// let _clip = Mouse::clip_to(rect).unwrap();
+    /// // The cursor cannot leave the rectangle until `_clip` is dropped.
     /// ```
-    #[cfg(not(feature = "minimal"))]
-    pub fn scrollh(amount: f32) {
-        let input = Input::from_wheel(amount, WheelDirection::Horizontal);
-        send_inputs(&[input]);
+    pub fn clip_to(rect: Rect) -> Result<ClipGuard, WindowsError> {
+        let rect = windef::RECT::from(rect);
+
+        // Calling C code
+        if unsafe { winuser::ClipCursor(&rect) } == 0 {
+            Err(WindowsError::from_last_error())
+        } else {
+            Ok(ClipGuard(()))
+        }
     }
 
-    /// Moves the mouse relatively to its current position, in screen coordinates.
+    /// Retrieves the rectangle the cursor is confined to, in screen coordinates. When
+    /// the cursor is not confined, the rectangle covers the whole virtual desktop.
+    pub fn clip_rect() -> Result<Rect, WindowsError> {
+        unsafe {
+            let mut rect: windef::RECT = std::mem::zeroed();
+
+            // Calling C code
+            if winuser::GetClipCursor(&mut rect) == 0 {
+                Err(WindowsError::from_last_error())
+            } else {
+                Ok(rect.into())
+            }
+        }
+    }
+
+    /// Hides the cursor until the returned guard is dropped.
     ///
-    /// If the function fails to synthesize the input, no error is emited and the
-    /// function fails silently. If you wish to retreive an eventual error, use
-    /// `send_inputs` instead.
+    /// This function relies on the native `ShowCursor` function, which maintains a
+    /// display counter for the calling thread: the cursor is hidden over the windows
+    /// of that thread while the counter is negative. The guard restores the counter
+    /// when dropped, which keeps the calls balanced. For this reason, the guard cannot
+    /// be sent to another thread.
     ///
     /// ## Example
     ///
     /// ```rust, ignore
     /// use winput::Mouse;
     ///
-    /// Mouse::move_relative(100, 50).unwrap();
+    /// let _hidden = Mouse::hide_cursor();
+    /// // Record the screen without the cursor...
     /// ```
-    #[cfg(not(feature = "minimal"))]
-    pub fn move_relative(dx: i32, dy: i32) {
-        let motion = MouseMotion::Relative { dx, dy };
-        let input = Input::from_motion(motion);
-        send_inputs(&[input]);
+    #[inline]
+    pub fn hide_cursor() -> CursorGuard {
+        CursorGuard::new(false)
     }
 
-    /// Moves the mouse using absolute normalized coordinates.
+    /// Shows the cursor until the returned guard is dropped.
     ///
-    /// If the function fails to synthesize the input, no error is emited and the
-    /// function fails silently. If you wish to retreive an eventual error, use
-    /// `send_inputs` instead.
+    /// This undoes the effect of a previous call to [`Mouse::hide_cursor`] for the
+    /// lifetime of the guard. See [`Mouse::hide_cursor`] for more information.
+    ///
+    /// [`Mouse::hide_cursor`]: struct.Mouse.html#method.hide_cursor
+    #[inline]
+    pub fn show_cursor() -> CursorGuard {
+        CursorGuard::new(true)
+    }
+
+    /// Checks whether the cursor is currently visible.
     ///
     /// ## Example
     ///
     /// ```rust, ignore
     /// use winput::Mouse;
     ///
-    /// // Move the mouse in the center of the main monitor.
-    /// Mouse::move_absolute(0.5, 0.5).unwrap();
+    /// println!("The cursor is visible: {}", Mouse::is_cursor_visible().unwrap());
     /// ```
-    #[cfg(not(feature = "minimal"))]
-    pub fn move_absolute(x: f32, y: f32) {
-        let motion = MouseMotion::Absolute {
-            x,
-            y,
-            virtual_desk: false,
+    pub fn is_cursor_visible() -> Result<bool, WindowsError> {
+        unsafe {
+            let mut info: winuser::CURSORINFO = std::mem::zeroed();
+            info.cbSize = std::mem::size_of::<winuser::CURSORINFO>() as u32;
+
+            // Calling C code
+            if winuser::GetCursorInfo(&mut info) == 0 {
+                Err(WindowsError::from_last_error())
+            } else {
+                Ok(info.flags & winuser::CURSOR_SHOWING != 0)
+            }
+        }
+    }
+
+    /// Captures the mouse the way games do for camera controls: the cursor is hidden and
+    /// locked in place, and the raw relative motions of the mouse are accumulated until
+    /// they are retrieved with [`RelativeCapture::delta`].
+    ///
+    /// The motions are collected by the message loop (see the [`message_loop`] module),
+    /// which is started if it is not active yet. The capture keeps the mice registered
+    /// with the message loop but does not queue any event.
+    ///
+    /// Only the motions of physical mice are accumulated: synthesized motions (such as
+    /// the ones sent with [`Mouse::move_relative`], or by remote control software) are
+    /// ignored, so that the delta reflects what the user did.
+    ///
+    /// The cursor is released and shown again when the capture is dropped.
+    ///
+    /// ## Returns
+    ///
+    /// This function returns [`MessageLoopError::AlreadyActive`] if another capture is
+    /// still alive.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use std::time::Duration;
+    /// use winput::Mouse;
+    ///
+    /// let mut capture = Mouse::capture_relative().unwrap();
+    ///
+    /// loop {
+    ///     std::thread::sleep(Duration::from_millis(16));
+    ///     let (dx, dy) = capture.delta();
+    ///     println!("The camera turned by ({}, {})", dx, dy);
+    /// }
+    /// ```
+    ///
+    /// [`RelativeCapture::delta`]: struct.RelativeCapture.html#method.delta
+    /// [`message_loop`]: message_loop/index.html
+    /// [`Mouse::move_relative`]: struct.Mouse.html#method.move_relative
+    /// [`MessageLoopError::AlreadyActive`]: message_loop/enum.MessageLoopError.html#variant.AlreadyActive
+    #[cfg(feature = "message_loop")]
+    pub fn capture_relative() -> Result<RelativeCapture, MessageLoopError> {
+        if message_loop::CAPTURING
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            return Err(MessageLoopError::AlreadyActive);
+        }
+
+        message_loop::CAPTURED_X.store(0, Ordering::SeqCst);
+        message_loop::CAPTURED_Y.store(0, Ordering::SeqCst);
+
+        // The capture is filled in step by step so that dropping it early releases
+        // whatever was already set up.
+        //
+        // The filter rejects every event: the subscriber only keeps the mice registered.
+        let options = Options::new().keyboard(false);
+        let receiver = match message_loop::subscribe(options, Some(Box::new(|_| false))) {
+            Ok(receiver) => receiver,
+            Err(err) => {
+                message_loop::CAPTURING.store(false, Ordering::SeqCst);
+                return Err(err);
+            }
         };
 
-        let input = Input::from_motion(motion);
-        send_inputs(&[input]);
+        let mut capture = RelativeCapture {
+            _receiver: receiver,
+            _clip: None,
+            _cursor: None,
+        };
+
+        let (x, y) = Self::position().map_err(MessageLoopError::OsError)?;
+        let rect = Rect {
+            left: x,
+            top: y,
+            right: x + 1,
+            bottom: y + 1,
+        };
+
+        capture._clip = Some(Self::clip_to(rect).map_err(MessageLoopError::OsError)?);
+        capture._cursor = Some(Self::hide_cursor());
+
+        Ok(capture)
+    }
+
+    /// Retrieves the speed of the pointer, between `1` (slowest) and `20` (fastest).
+    /// The default speed is `10`.
+    ///
+    /// The speed affects relative motions only (see [`MouseMotion::Relative`]).
+    ///
+    /// [`MouseMotion::Relative`]: enum.MouseMotion.html#variant.Relative
+    pub fn speed() -> Result<u32, WindowsError> {
+        let mut speed: u32 = 0;
+
+        // Calling C code
+        let result = unsafe {
+            winuser::SystemParametersInfoW(
+                winuser::SPI_GETMOUSESPEED,
+                0,
+                &mut speed as *mut u32 as _,
+                0,
+            )
+        };
+
+        if result == 0 {
+            Err(WindowsError::from_last_error())
+        } else {
+            Ok(speed)
+        }
+    }
+
+    /// Sets the speed of the pointer. The value is clamped between `1` (slowest) and
+    /// `20` (fastest).
+    ///
+    /// The setting is not saved in the user profile: it is reset when the user logs
+    /// off. See [`Mouse::save_settings`] to restore the previous speed automatically.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// Mouse::set_speed(10).unwrap();
+    /// ```
+    ///
+    /// [`Mouse::save_settings`]: struct.Mouse.html#method.save_settings
+    pub fn set_speed(speed: u32) -> Result<(), WindowsError> {
+        let speed = speed.clamp(1, 20);
+
+        // Calling C code
+        //
+        // The speed is passed as the value of the pointer.
+        let result = unsafe {
+            winuser::SystemParametersInfoW(winuser::SPI_SETMOUSESPEED, 0, speed as _, 0)
+        };
+
+        if result == 0 {
+            Err(WindowsError::from_last_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Retrieves the acceleration settings of the pointer.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// let acceleration = Mouse::acceleration().unwrap();
+    /// println!("Enhanced pointer precision: {}", acceleration.is_enabled());
+    /// ```
+    pub fn acceleration() -> Result<MouseAcceleration, WindowsError> {
+        let mut values = [0i32; 3];
+
+        // Calling C code
+        let result = unsafe {
+            winuser::SystemParametersInfoW(
+                winuser::SPI_GETMOUSE,
+                0,
+                values.as_mut_ptr() as _,
+                0,
+            )
+        };
+
+        if result == 0 {
+            Err(WindowsError::from_last_error())
+        } else {
+            Ok(MouseAcceleration {
+                threshold1: values[0],
+                threshold2: values[1],
+                level: values[2],
+            })
+        }
+    }
+
+    /// Sets the acceleration settings of the pointer.
+    ///
+    /// The setting is not saved in the user profile: it is reset when the user logs
+    /// off. See [`Mouse::save_settings`] to restore the previous settings
+    /// automatically.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Mouse, MouseAcceleration};
+    ///
+    /// // Relative motions are not accelerated anymore.
+    /// Mouse::set_acceleration(MouseAcceleration::DISABLED).unwrap();
+    /// ```
+    ///
+    /// [`Mouse::save_settings`]: struct.Mouse.html#method.save_settings
+    pub fn set_acceleration(acceleration: MouseAcceleration) -> Result<(), WindowsError> {
+        let mut values = [
+            acceleration.threshold1,
+            acceleration.threshold2,
+            acceleration.level,
+        ];
+
+        // Calling C code
+        let result = unsafe {
+            winuser::SystemParametersInfoW(
+                winuser::SPI_SETMOUSE,
+                0,
+                values.as_mut_ptr() as _,
+                0,
+            )
+        };
+
+        if result == 0 {
+            Err(WindowsError::from_last_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Saves the speed and the acceleration settings of the pointer, and returns a
+    /// guard that restores them when dropped.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Mouse, MouseAcceleration};
+    ///
+    /// let _settings = Mouse::save_settings().unwrap();
+    ///
+    /// // Make relative motions predictable.
+    /// Mouse::set_speed(10).unwrap();
+    /// Mouse::set_acceleration(MouseAcceleration::DISABLED).unwrap();
+    ///
+    /// Mouse::move_relative(100, 0);
+    /// ```
+    pub fn save_settings() -> Result<MouseSettingsGuard, WindowsError> {
+        Ok(MouseSettingsGuard {
+            speed: Self::speed()?,
+            acceleration: Self::acceleration()?,
+        })
+    }
+
+    /// Synthesizes a vertical scroll event.
+    ///
+    /// If the function fails to synthesize the input, no error is emited and the
+    /// function fails silently. If you wish to retreive an eventual error, use
+    /// `try_send_inputs` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// Mouse::scroll(1.0).unwrap();
+    /// ```
+    #[cfg(not(feature = "minimal"))]
+    pub fn scroll(amount: f32) {
+        let input = Input::from_wheel(amount, WheelDirection::Vertical);
+        let _ = try_send_inputs([input]);
+    }
+
+    /// Synthesizes a horizontal scroll event.
+    ///
+    /// If the function fails to synthesize the input, no error is emited and the
+    /// function fails silently. If you wish to retreive an eventual error, use
+    /// `try_send_inputs` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// Mouse::scrollh(1.0).unwrap();
+    /// ```
+    #[cfg(not(feature = "minimal"))]
+    pub fn scrollh(amount: f32) {
+        let input = Input::from_wheel(amount, WheelDirection::Horizontal);
+        let _ = try_send_inputs([input]);
+    }
+
+    /// Synthesizes a vertical scroll event that scrolls by the given number of lines,
+    /// according to the wheel settings of the user (see [`Input::from_wheel_lines`]).
+    /// Positive values scroll up.
+    ///
+    /// The function returns `false`, without sending anything, if the amount cannot be
+    /// expressed in lines because the user disabled wheel scrolling or configured the
+    /// wheel to scroll by pages (see [`Mouse::scroll_pages`]).
+    ///
+    /// If the function fails to synthesize the input, no error is emited and the
+    /// function fails silently. If you wish to retreive an eventual error, use
+    /// `try_send_inputs` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// // Scroll down by 3 lines.
+    /// Mouse::scroll_lines(-3.0);
+    /// ```
+    ///
+    /// [`Input::from_wheel_lines`]: struct.Input.html#method.from_wheel_lines
+    /// [`Mouse::scroll_pages`]: struct.Mouse.html#method.scroll_pages
+    #[cfg(not(feature = "minimal"))]
+    pub fn scroll_lines(lines: f32) -> bool {
+        Self::send_optional(Input::from_wheel_lines(lines, WheelDirection::Vertical))
+    }
+
+    /// Synthesizes a horizontal scroll event that scrolls by the given number of
+    /// characters, according to the wheel settings of the user. Positive values scroll
+    /// right.
+    ///
+    /// See [`Mouse::scroll_lines`] for more information.
+    ///
+    /// [`Mouse::scroll_lines`]: struct.Mouse.html#method.scroll_lines
+    #[cfg(not(feature = "minimal"))]
+    pub fn scrollh_chars(chars: f32) -> bool {
+        Self::send_optional(Input::from_wheel_lines(chars, WheelDirection::Horizontal))
+    }
+
+    /// Synthesizes a vertical scroll event that scrolls by the given number of pages.
+    /// Positive values scroll up.
+    ///
+    /// The function returns `false`, without sending anything, unless the user configured
+    /// the wheel to scroll by pages (see [`Input::from_wheel_pages`]).
+    ///
+    /// If the function fails to synthesize the input, no error is emited and the
+    /// function fails silently. If you wish to retreive an eventual error, use
+    /// `try_send_inputs` instead.
+    ///
+    /// [`Input::from_wheel_pages`]: struct.Input.html#method.from_wheel_pages
+    #[cfg(not(feature = "minimal"))]
+    pub fn scroll_pages(pages: f32) -> bool {
+        Self::send_optional(Input::from_wheel_pages(pages))
+    }
+
+    /// Scrolls vertically by the given number of notches over the given duration, the
+    /// way a precision touchpad does. Positive values scroll up.
+    ///
+    /// Instead of a single wheel event, the motion is split into small deltas of an
+    /// eighth of a notch that are spread evenly over `duration`. Applications that
+    /// support high-resolution scrolling animate those smoothly. The amount is rounded
+    /// to the nearest eighth of a notch. The calling thread is blocked until the
+    /// scrolling is complete.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use std::time::Duration;
+    /// use winput::Mouse;
+    ///
+    /// // Scroll down by 3 notches in half a second.
+    /// Mouse::scroll_smooth(-3.0, Duration::from_millis(500)).unwrap();
+    /// ```
+    #[cfg(not(feature = "minimal"))]
+    pub fn scroll_smooth(
+        total_notches: f32,
+        duration: Duration,
+    ) -> Result<(), WindowsError> {
+        /// The number of deltas that make up a notch.
+        const STEPS_PER_NOTCH: f32 = 8.0;
+
+        let steps = (total_notches.abs() * STEPS_PER_NOTCH).round();
+        if steps < 1.0 {
+            return Ok(());
+        }
+
+        let steps = steps.min(u32::MAX as f32) as u32;
+        let step = (1.0 / STEPS_PER_NOTCH).copysign(total_notches);
+        let inputs =
+            vec![Input::from_wheel(step, WheelDirection::Vertical); steps as usize];
+
+        let sent = send_inputs_with_pacing(&inputs, 1, duration / steps);
+        if sent as usize == inputs.len() {
+            Ok(())
+        } else {
+            Err(WindowsError::from_last_error())
+        }
+    }
+
+    /// Sends the given input if there is one, and returns whether there was one.
+    #[cfg(not(feature = "minimal"))]
+    fn send_optional(input: Option<Input>) -> bool {
+        match input {
+            Some(input) => {
+                let _ = try_send_inputs([input]);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the mouse to the given position, in screen coordinates, and synthesizes a
+    /// vertical scroll event. Positive values scroll up.
+    ///
+    /// Wheel events are delivered to the window under the cursor. The motion and the
+    /// scroll event are inserted into the input stream in a single batch, which means
+    /// that no other event can move the cursor away from the target in between.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// // Scroll the panel on the left of the screen down by 2 notches.
+    /// Mouse::scroll_at(100, 400, -2.0).unwrap();
+    /// ```
+    #[cfg(not(feature = "minimal"))]
+    pub fn scroll_at(x: i32, y: i32, amount: f32) -> Result<(), WindowsError> {
+        let motion = MouseMotion::AbsolutePixels {
+            x,
+            y,
+            virtual_desk: true,
+        };
+
+        try_send_inputs([
+            Input::from_motion(motion),
+            Input::from_wheel(amount, WheelDirection::Vertical),
+        ])?;
+
+        Ok(())
+    }
+
+    /// Scrolls the window that is under the cursor by posting a `WM_MOUSEWHEEL` message
+    /// directly to it.
+    ///
+    /// Unlike [`Mouse::scroll`], this function does not go through the global input
+    /// stream. This is useful for applications that only honor wheel messages that are
+    /// addressed to them.
+    ///
+    /// The state of the `Shift` and `Control` keys is forwarded with the message.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// Mouse::scroll_window_under_cursor(-2.0).unwrap();
+    /// ```
+    ///
+    /// [`Mouse::scroll`]: struct.Mouse.html#method.scroll
+    pub fn scroll_window_under_cursor(amount: f32) -> Result<(), WindowsError> {
+        check_sending_enabled()?;
+        let (x, y) = Self::position()?;
+
+        unsafe {
+            let point = windef::POINT { x, y };
+
+            // Calling C code
+            let hwnd = winuser::WindowFromPoint(point);
+            if hwnd.is_null() {
+                return Err(WindowsError::from_error_code(ERROR_INVALID_WINDOW_HANDLE));
+            }
+
+            let mut keys = 0;
+            if Vk::Shift.is_down() {
+                keys |= winuser::MK_SHIFT;
+            }
+            if Vk::Control.is_down() {
+                keys |= winuser::MK_CONTROL;
+            }
+
+            let delta = (amount * winuser::WHEEL_DELTA as f32) as i16;
+            let w_param = (delta as u16 as usize) << 16 | keys;
+            let l_param = (y as i16 as u16 as isize) << 16 | x as i16 as u16 as isize;
+
+            // Calling C code
+            let result =
+                winuser::PostMessageW(hwnd, winuser::WM_MOUSEWHEEL, w_param, l_param);
+
+            if result == 0 {
+                Err(WindowsError::from_last_error())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    /// Moves the mouse relatively to its current position, in screen coordinates.
+    ///
+    /// If the function fails to synthesize the input, no error is emited and the
+    /// function fails silently. If you wish to retreive an eventual error, use
+    /// `try_send_inputs` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// Mouse::move_relative(100, 50).unwrap();
+    /// ```
+    #[cfg(not(feature = "minimal"))]
+    pub fn move_relative(dx: i32, dy: i32) {
+        let motion = MouseMotion::Relative { dx, dy };
+        let input = Input::from_motion(motion);
+        let _ = try_send_inputs([input]);
+    }
+
+    /// Moves the mouse using absolute normalized coordinates.
+    ///
+    /// If the function fails to synthesize the input, no error is emited and the
+    /// function fails silently. If you wish to retreive an eventual error, use
+    /// `try_send_inputs` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// // Move the mouse in the center of the main monitor.
+    /// Mouse::move_absolute(0.5, 0.5).unwrap();
+    /// ```
+    #[cfg(not(feature = "minimal"))]
+    pub fn move_absolute(x: f32, y: f32) {
+        let motion = MouseMotion::Absolute {
+            x,
+            y,
+            virtual_desk: false,
+        };
+
+        let input = Input::from_motion(motion);
+        let _ = try_send_inputs([input]);
+    }
+
+    /// Moves the mouse to the given position, in screen coordinates, by synthesizing an
+    /// absolute motion.
+    ///
+    /// Unlike [`Mouse::set_position`], this function goes through the input stream,
+    /// which makes the motion visible to applications (such as games) that ignore the
+    /// position set by `SetCursorPos`. The position may be on any monitor, including
+    /// monitors placed left of or above the primary monitor (see
+    /// [`MouseMotion::AbsolutePixels`]).
+    ///
+    /// If the function fails to synthesize the input, no error is emited and the
+    /// function fails silently. If you wish to retreive an eventual error, use
+    /// `try_send_inputs` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// // A monitor placed left of the primary monitor.
+    /// Mouse::move_absolute_pixels(-800, 300);
+    /// ```
+    ///
+    /// [`Mouse::set_position`]: struct.Mouse.html#method.set_position
+    /// [`MouseMotion::AbsolutePixels`]: enum.MouseMotion.html#variant.AbsolutePixels
+    #[cfg(not(feature = "minimal"))]
+    pub fn move_absolute_pixels(x: i32, y: i32) {
+        let motion = MouseMotion::AbsolutePixels {
+            x,
+            y,
+            virtual_desk: true,
+        };
+
+        let _ = try_send_inputs([Input::from_motion(motion)]);
+    }
+
+    /// Retrieves the monitor the cursor is currently on.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// let monitor = Mouse::current_monitor().unwrap();
+    /// println!("The cursor is on {:?}", monitor.rect().unwrap());
+    /// ```
+    pub fn current_monitor() -> Result<Monitor, WindowsError> {
+        let (x, y) = Self::position()?;
+        Ok(Monitor::from_point(x, y))
+    }
+
+    /// Moves the mouse to the given position, in pixels relative to the upper-left corner
+    /// of the given monitor, by synthesizing an absolute motion (see
+    /// [`Mouse::move_absolute_pixels`]).
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    /// use winput::window::Monitor;
+    ///
+    /// // Move the mouse to the second monitor.
+    /// let monitors = Monitor::all().unwrap();
+    /// Mouse::move_to_monitor(monitors[1], 100, 100).unwrap();
+    /// ```
+    ///
+    /// [`Mouse::move_absolute_pixels`]: struct.Mouse.html#method.move_absolute_pixels
+    #[cfg(not(feature = "minimal"))]
+    pub fn move_to_monitor(monitor: Monitor, x: i32, y: i32) -> Result<(), WindowsError> {
+        let rect = monitor.rect()?;
+        let motion = MouseMotion::AbsolutePixels {
+            x: rect.left + x,
+            y: rect.top + y,
+            virtual_desk: true,
+        };
+
+        try_send_inputs([Input::from_motion(motion)])?;
+        Ok(())
+    }
+
+    /// Moves the mouse to the given position (in screen coordinates) over the given
+    /// duration, following the given [`Easing`].
+    ///
+    /// Intermediate absolute motions are synthesized roughly every 10 milliseconds,
+    /// which applications see as a continuous movement rather than a jump. The calling
+    /// thread is blocked until the movement is complete.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use std::time::Duration;
+    /// use winput::{Easing, Mouse};
+    ///
+    /// Mouse::move_smooth(800, 400, Duration::from_millis(500), Easing::EaseInOut)
+    ///     .unwrap();
+    /// ```
+    ///
+    /// [`Easing`]: enum.Easing.html
+    #[cfg(not(feature = "minimal"))]
+    pub fn move_smooth(
+        x: i32,
+        y: i32,
+        duration: Duration,
+        easing: Easing,
+    ) -> Result<(), WindowsError> {
+        const STEP_DELAY: Duration = Duration::from_millis(10);
+
+        let steps = (duration.as_millis() / STEP_DELAY.as_millis()).max(1);
+        let steps = u32::try_from(steps).unwrap_or(u32::MAX);
+
+        let inputs = MousePath::new(Self::position()?, (x, y))
+            .steps(steps)
+            .easing(easing)
+            .to_absolute_inputs();
+
+        let sent = send_inputs_with_pacing(&inputs, 1, duration / steps);
+        if sent as usize == inputs.len() {
+            Ok(())
+        } else {
+            Err(WindowsError::from_last_error())
+        }
+    }
+
+    /// Moves the mouse to the given position, in screen coordinates, and keeps it
+    /// hovering there for the given duration.
+    ///
+    /// Some applications only show tooltips or open menus after receiving mouse motions
+    /// over an element. While hovering, the cursor is moved back and forth by a single
+    /// pixel roughly every 50 milliseconds, which is well within the hover rectangle of
+    /// the system. The cursor ends up at the given position, and the calling thread is
+    /// blocked until the duration has elapsed.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use std::time::Duration;
+    /// use winput::Mouse;
+    ///
+    /// // Wait for the tooltip of the button to show up.
+    /// Mouse::hover(640, 360, Duration::from_secs(2)).unwrap();
+    /// ```
+    #[cfg(not(feature = "minimal"))]
+    pub fn hover(x: i32, y: i32, duration: Duration) -> Result<(), WindowsError> {
+        const STEP_DELAY: Duration = Duration::from_millis(50);
+
+        let motion = |x| {
+            Input::from_motion(MouseMotion::AbsolutePixels {
+                x,
+                y,
+                virtual_desk: true,
+            })
+        };
+
+        try_send_inputs([motion(x)])?;
+
+        let start = std::time::Instant::now();
+        let mut offset = 0;
+        while start.elapsed() + STEP_DELAY < duration {
+            std::thread::sleep(STEP_DELAY);
+            offset = 1 - offset;
+            try_send_inputs([motion(x + offset)])?;
+        }
+
+        std::thread::sleep(duration.checked_sub(start.elapsed()).unwrap_or_default());
+        try_send_inputs([motion(x)])?;
+        Ok(())
+    }
+
+    /// Synthesizes an event that presses the given mouse button.
+    ///
+    /// If the function fails to synthesize the input, no error is emited and the
+    /// function fails silently. If you wish to retreive an eventual error, use
+    /// `try_send_inputs` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Button, Mouse};
+    ///
+    /// Mouse::press(Button::Left);
+    /// ```
+    #[cfg(not(feature = "minimal"))]
+    pub fn press(button: Button) {
+        let _ = try_send_inputs([Input::from_button(button, Action::Press)]);
+    }
+
+    /// Synthesizes an event that releases the given mouse button.
+    ///
+    /// If the function fails to synthesize the input, no error is emited and the
+    /// function fails silently. If you wish to retreive an eventual error, use
+    /// `try_send_inputs` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Button, Mouse};
+    ///
+    /// Mouse::release(Button::Left);
+    /// ```
+    #[cfg(not(feature = "minimal"))]
+    pub fn release(button: Button) {
+        let _ = try_send_inputs([Input::from_button(button, Action::Release)]);
+    }
+
+    /// Synthesizes two events. One that presses the given mouse button, one that
+    /// releases it.
+    ///
+    /// If the function fails to synthesize the input, no error is emited and the
+    /// function fails silently. If you wish to retreive an eventual error, use
+    /// `try_send_inputs` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Button, Mouse};
+    ///
+    /// Mouse::click(Button::Right);
+    /// ```
+    #[cfg(not(feature = "minimal"))]
+    pub fn click(button: Button) {
+        let _ = try_send_inputs([
+            Input::from_button(button, Action::Press),
+            Input::from_button(button, Action::Release),
+        ]);
+    }
+
+    /// Checks whether the user swapped the meaning of the left and right mouse buttons
+    /// (usually a setting for left-handed users).
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// if Mouse::buttons_swapped() {
+    ///     println!("The right button is the primary button");
+    /// }
+    /// ```
+    pub fn buttons_swapped() -> bool {
+        // Calling C code
+        unsafe { winuser::GetSystemMetrics(winuser::SM_SWAPBUTTON) != 0 }
+    }
+
+    /// Synthesizes a click of the button the user considers primary or secondary.
+    ///
+    /// Synthesized events describe physical buttons, which the system swaps according
+    /// to the settings of the user. This function takes those settings into account
+    /// (see [`Mouse::buttons_swapped`]).
+    ///
+    /// If the function fails to synthesize the inputs, no error is emited and the
+    /// function fails silently. If you wish to retreive an eventual error, use
+    /// `try_send_inputs` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{LogicalButton, Mouse};
+    ///
+    /// // Open the context menu, whatever the mouse settings are.
+    /// Mouse::click_logical(LogicalButton::Secondary);
+    /// ```
+    ///
+    /// [`Mouse::buttons_swapped`]: struct.Mouse.html#method.buttons_swapped
+    #[cfg(not(feature = "minimal"))]
+    #[inline]
+    pub fn click_logical(button: LogicalButton) {
+        Self::click(button.to_physical());
+    }
+
+    /// Moves the mouse to the given position, in screen coordinates, and clicks the given
+    /// button.
+    ///
+    /// The motion and the click are inserted into the input stream in a single batch,
+    /// which means that no other event (such as a motion of the physical mouse) can be
+    /// interleaved between them.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Button, Mouse};
+    ///
+    /// Mouse::click_at(640, 360, Button::Left).unwrap();
+    /// ```
+    #[cfg(not(feature = "minimal"))]
+    pub fn click_at(x: i32, y: i32, button: Button) -> Result<(), WindowsError> {
+        let motion = MouseMotion::AbsolutePixels {
+            x,
+            y,
+            virtual_desk: true,
+        };
+
+        try_send_inputs([
+            Input::from_motion(motion),
+            Input::from_button(button, Action::Press),
+            Input::from_button(button, Action::Release),
+        ])?;
+
+        Ok(())
+    }
+
+    /// Synthesizes two clicks of the given mouse button, recognized as a double-click.
+    ///
+    /// The second click is sent after a short pause that is well within the
+    /// double-click time of the system (see [`Mouse::double_click_time`]), since some
+    /// applications ignore clicks that are received at the same time. The cursor is not
+    /// moved between the two clicks.
+    ///
+    /// If the function fails to synthesize the inputs, no error is emited and the
+    /// function fails silently. If you wish to retreive an eventual error, use
+    /// `try_send_inputs` instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Button, Mouse};
+    ///
+    /// Mouse::double_click(Button::Left);
+    /// ```
+    ///
+    /// [`Mouse::double_click_time`]: struct.Mouse.html#method.double_click_time
+    #[cfg(not(feature = "minimal"))]
+    pub fn double_click(button: Button) {
+        /// The longest pause between the two clicks.
+        const MAX_PAUSE: Duration = Duration::from_millis(50);
+
+        let pause = (Self::double_click_time() / 4).min(MAX_PAUSE);
+
+        Self::click(button);
+        std::thread::sleep(pause);
+        Self::click(button);
+    }
+
+    /// Retrieves the maximum time that may elapse between two clicks for the system to
+    /// consider them a double-click.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Mouse;
+    ///
+    /// println!("{:?}", Mouse::double_click_time());
+    /// ```
+    pub fn double_click_time() -> Duration {
+        // Calling C code
+        std::time::Duration::from_millis(u64::from(unsafe {
+            winuser::GetDoubleClickTime()
+        }))
+    }
+
+    /// Retrieves the size (`(width, height)`, in pixels) of the rectangle, centered on
+    /// the first click, within which the second click must happen for the system to
+    /// consider them a double-click.
+    pub fn double_click_area() -> (i32, i32) {
+        // Calling C code
+        unsafe {
+            (
+                winuser::GetSystemMetrics(winuser::SM_CXDOUBLECLK),
+                winuser::GetSystemMetrics(winuser::SM_CYDOUBLECLK),
+            )
+        }
+    }
+
+    /// Drags the mouse from `from` to `to` (in screen coordinates) while holding the
+    /// given button.
+    ///
+    /// The cursor is moved to `from`, the button is pressed, the cursor is moved through
+    /// intermediate positions to `to`, and the button is released. Every step is
+    /// synthesized as an input event, so applications receive them in order. The
+    /// button is released even if moving the cursor fails. See
+    /// [`Mouse::drag_with_modifiers`] to hold modifiers during the operation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Button, Mouse};
+    ///
+    /// // Move the item at (100, 100) to (400, 300).
+    /// Mouse::drag((100, 100), (400, 300), Button::Left).unwrap();
+    /// ```
+    ///
+    /// [`Mouse::drag_with_modifiers`]: struct.Mouse.html#method.drag_with_modifiers
+    #[cfg(not(feature = "minimal"))]
+    #[inline]
+    pub fn drag(
+        from: (i32, i32),
+        to: (i32, i32),
+        button: Button,
+    ) -> Result<(), WindowsError> {
+        Self::drag_with_modifiers(Modifiers::empty(), from, to, button)
+    }
+
+    /// Drags the mouse from `from` to `to` (in screen coordinates) while holding the
+    /// given button and modifiers.
+    ///
+    /// The modifiers are pressed before the button and released after it, which is the
+    /// order expected by most applications for operations such as `Ctrl`+drag (copy)
+    /// or `Shift`+drag (constrain). The cursor is moved through intermediate positions
+    /// so that applications which only react to gradual motion register the drag.
+    ///
+    /// Every pressed key and button is released even if moving the cursor fails.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Button, Modifiers, Mouse};
+    ///
+    /// // Copy the item at (100, 100) to (400, 300).
+    /// Mouse::drag_with_modifiers(Modifiers::CONTROL, (100, 100), (400, 300), Button::Left)
+    ///     .unwrap();
+    /// ```
+    #[cfg(not(feature = "minimal"))]
+    pub fn drag_with_modifiers(
+        modifiers: Modifiers,
+        from: (i32, i32),
+        to: (i32, i32),
+        button: Button,
+    ) -> Result<(), WindowsError> {
+        let mut inputs = vec![Input::from_motion(MouseMotion::AbsolutePixels {
+            x: from.0,
+            y: from.1,
+            virtual_desk: true,
+        })];
+        inputs.extend(modifiers.keys().map(|vk| Input::from_vk(vk, Action::Press)));
+        inputs.push(Input::from_button(button, Action::Press));
+
+        let result = try_send_inputs(&inputs)
+            .map_err(WindowsError::from)
+            .and_then(|_| move_gradually(from, to));
+
+        let mut inputs = vec![Input::from_button(button, Action::Release)];
+        inputs.extend(
+            modifiers
+                .keys()
+                .rev()
+                .map(|vk| Input::from_vk(vk, Action::Release)),
+        );
+        let released = try_send_inputs(&inputs);
+
+        result?;
+        released?;
+        Ok(())
+    }
+}
+
+/// A mouse button, as seen by the user rather than by the hardware.
+///
+/// The primary button is the left button, unless the user swapped the buttons (see
+/// [`Mouse::buttons_swapped`]).
+///
+/// [`Mouse::buttons_swapped`]: struct.Mouse.html#method.buttons_swapped
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LogicalButton {
+    /// The button used to select and activate items.
+    Primary,
+    /// The button used to open context menus.
+    Secondary,
+}
+
+impl LogicalButton {
+    /// Returns the physical [`Button`] that currently acts as this button.
+    ///
+    /// [`Button`]: enum.Button.html
+    pub fn to_physical(self) -> Button {
+        match (self, Mouse::buttons_swapped()) {
+            (LogicalButton::Primary, false) | (LogicalButton::Secondary, true) => {
+                Button::Left
+            }
+            (LogicalButton::Primary, true) | (LogicalButton::Secondary, false) => {
+                Button::Right
+            }
+        }
+    }
+}
+
+/// The acceleration settings of the pointer (see [`Mouse::acceleration`]).
+///
+/// When `level` is not zero (the "Enhance pointer precision" setting), relative
+/// motions are accelerated: their distance is doubled when it exceeds `threshold1`
+/// and, if `level` is `2`, doubled again when it exceeds `threshold2`.
+///
+/// [`Mouse::acceleration`]: struct.Mouse.html#method.acceleration
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MouseAcceleration {
+    /// The first threshold, in pixels.
+    pub threshold1: i32,
+    /// The second threshold, in pixels.
+    pub threshold2: i32,
+    /// The level of acceleration: `0` (disabled), `1` or `2`.
+    pub level: i32,
+}
+
+impl MouseAcceleration {
+    /// Disabled acceleration: relative motions move the cursor by the given distance,
+    /// scaled by the speed of the pointer.
+    pub const DISABLED: Self = Self {
+        threshold1: 0,
+        threshold2: 0,
+        level: 0,
+    };
+
+    /// The default settings of Windows, with "Enhance pointer precision" turned on.
+    pub const DEFAULT: Self = Self {
+        threshold1: 6,
+        threshold2: 10,
+        level: 1,
+    };
+
+    /// Checks whether relative motions are accelerated.
+    #[inline(always)]
+    pub fn is_enabled(&self) -> bool {
+        self.level != 0
+    }
+}
+
+/// A guard that restores the speed and the acceleration settings of the pointer when
+/// dropped (see [`Mouse::save_settings`]).
+///
+/// [`Mouse::save_settings`]: struct.Mouse.html#method.save_settings
+#[derive(Debug)]
+#[must_use = "the settings are restored as soon as the guard is dropped"]
+pub struct MouseSettingsGuard {
+    speed: u32,
+    acceleration: MouseAcceleration,
+}
+
+impl Drop for MouseSettingsGuard {
+    fn drop(&mut self) {
+        // Errors cannot be reported from here.
+        let _ = Mouse::set_speed(self.speed);
+        let _ = Mouse::set_acceleration(self.acceleration);
+    }
+}
+
+/// A guard that moves the mouse back to a saved position when dropped (see
+/// [`Mouse::save_position`]).
+///
+/// [`Mouse::save_position`]: struct.Mouse.html#method.save_position
+#[derive(Debug)]
+#[must_use = "the position is restored as soon as the guard is dropped"]
+pub struct PositionGuard {
+    x: i32,
+    y: i32,
+}
+
+impl PositionGuard {
+    /// Returns the saved position, in screen coordinates.
+    #[inline(always)]
+    pub fn position(&self) -> (i32, i32) {
+        (self.x, self.y)
+    }
+}
+
+impl Drop for PositionGuard {
+    fn drop(&mut self) {
+        // Errors cannot be reported from here.
+        let _ = Mouse::set_position(self.x, self.y);
+    }
+}
+
+/// A guard that confines the cursor to a rectangle until it is dropped (see
+/// [`Mouse::clip_to`]).
+///
+/// [`Mouse::clip_to`]: struct.Mouse.html#method.clip_to
+#[derive(Debug)]
+#[must_use = "the cursor is released as soon as the guard is dropped"]
+pub struct ClipGuard(());
+
+impl Drop for ClipGuard {
+    fn drop(&mut self) {
+        // Calling C code
+        unsafe { winuser::ClipCursor(std::ptr::null()) };
+    }
+}
+
+/// A capture of the relative motions of the mouse (see [`Mouse::capture_relative`]).
+///
+/// [`Mouse::capture_relative`]: struct.Mouse.html#method.capture_relative
+#[cfg(feature = "message_loop")]
+#[derive(Debug)]
+#[must_use = "the mouse is released as soon as the capture is dropped"]
+pub struct RelativeCapture {
+    _receiver: EventReceiver,
+    _clip: Option<ClipGuard>,
+    _cursor: Option<CursorGuard>,
+}
+
+#[cfg(feature = "message_loop")]
+impl RelativeCapture {
+    /// Returns the sum of the relative motions of the mouse since the capture started
+    /// or since the last call to this function, in raw mouse units.
+    ///
+    /// Raw motions are not subject to the mouse speed settings of the user.
+    pub fn delta(&mut self) -> (i32, i32) {
+        (
+            message_loop::CAPTURED_X.swap(0, Ordering::SeqCst),
+            message_loop::CAPTURED_Y.swap(0, Ordering::SeqCst),
+        )
+    }
+}
+
+#[cfg(feature = "message_loop")]
+impl Drop for RelativeCapture {
+    fn drop(&mut self) {
+        message_loop::CAPTURING.store(false, Ordering::SeqCst);
+    }
+}
+
+/// A guard that shows or hides the cursor until it is dropped (see
+/// [`Mouse::hide_cursor`] and [`Mouse::show_cursor`]).
+///
+/// [`Mouse::hide_cursor`]: struct.Mouse.html#method.hide_cursor
+/// [`Mouse::show_cursor`]: struct.Mouse.html#method.show_cursor
+#[derive(Debug)]
+#[must_use = "the cursor visibility is restored as soon as the guard is dropped"]
+pub struct CursorGuard {
+    shown: bool,
+    // The display counter of `ShowCursor` belongs to the calling thread.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl CursorGuard {
+    /// Increments (`show`) or decrements the display counter of the cursor.
+    fn new(show: bool) -> Self {
+        // Calling C code
+        unsafe { winuser::ShowCursor(show as _) };
+
+        Self {
+            shown: show,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+impl Drop for CursorGuard {
+    fn drop(&mut self) {
+        // Calling C code
+        unsafe { winuser::ShowCursor(!self.shown as _) };
+    }
+}
+
+/// Moves the cursor from `from` to `to` through evenly spaced intermediate positions.
+///
+/// The positions are synthesized as absolute motions, which means that they are
+/// inserted into the same input stream as the surrounding button and key events.
+#[cfg(not(feature = "minimal"))]
+fn move_gradually(from: (i32, i32), to: (i32, i32)) -> Result<(), WindowsError> {
+    const STEP_DELAY: Duration = Duration::from_millis(5);
+
+    let inputs = MousePath::new(from, to).to_absolute_inputs();
+
+    std::thread::sleep(STEP_DELAY);
+    let sent = send_inputs_with_pacing(&inputs, 1, STEP_DELAY);
+    if sent as usize == inputs.len() {
+        Ok(())
+    } else {
+        Err(WindowsError::from_last_error())
     }
 }
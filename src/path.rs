@@ -0,0 +1,230 @@
+use crate::input::{Input, MouseMotion};
+
+/// Describes how the speed of the cursor varies along a [`MousePath`].
+///
+/// [`MousePath`]: struct.MousePath.html
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Easing {
+    /// The cursor moves at a constant speed.
+    Linear,
+    /// The cursor starts slowly and accelerates.
+    EaseIn,
+    /// The cursor starts quickly and decelerates.
+    EaseOut,
+    /// The cursor accelerates then decelerates.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Maps the given progress, between `0.0` and `1.0`, to the eased progress.
+    fn apply(self, t: f32) -> f32 {
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => t * (2.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    -1.0 + (4.0 - 2.0 * t) * t
+                }
+            }
+        }
+    }
+}
+
+impl Default for Easing {
+    #[inline(always)]
+    fn default() -> Self {
+        Easing::Linear
+    }
+}
+
+/// A path followed by the mouse cursor, from a start position to an end position.
+///
+/// Many applications only react to gradual motion: a path produces the intermediate
+/// mouse movements, optionally along a curve and with an [`Easing`].
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::{Easing, MousePath};
+///
+/// let inputs = MousePath::new((100, 100), (800, 400))
+///     .steps(50)
+///     .curve((300, 600))
+///     .easing(Easing::EaseInOut)
+///     .to_absolute_inputs();
+///
+/// winput::send_inputs_with_pacing(&inputs, 1, std::time::Duration::from_millis(5));
+/// ```
+///
+/// [`Easing`]: enum.Easing.html
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct MousePath {
+    from: (i32, i32),
+    to: (i32, i32),
+    control: Option<(i32, i32)>,
+    steps: u32,
+    easing: Easing,
+}
+
+impl MousePath {
+    /// Creates a straight [`MousePath`] from `from` to `to`, in screen coordinates.
+    ///
+    /// The path is made of 20 steps and the cursor moves at a constant speed.
+    ///
+    /// [`MousePath`]: struct.MousePath.html
+    #[inline]
+    pub fn new(from: (i32, i32), to: (i32, i32)) -> Self {
+        Self {
+            from,
+            to,
+            control: None,
+            steps: 20,
+            easing: Easing::Linear,
+        }
+    }
+
+    /// Sets the number of intermediate positions of the path. The last position is
+    /// always the end of the path. A value of `0` is treated as `1`.
+    #[inline]
+    pub fn steps(mut self, steps: u32) -> Self {
+        self.steps = steps.max(1);
+        self
+    }
+
+    /// Makes the path follow a quadratic Bézier curve that bends towards the given
+    /// control point.
+    #[inline]
+    pub fn curve(mut self, control: (i32, i32)) -> Self {
+        self.control = Some(control);
+        self
+    }
+
+    /// Sets the [`Easing`] of the path.
+    ///
+    /// [`Easing`]: enum.Easing.html
+    #[inline]
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the positions of the cursor along the path, excluding the start position
+    /// and including the end position.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::MousePath;
+    ///
+    /// // [(50, 25), (100, 50)]
+    /// let points = MousePath::new((0, 0), (100, 50)).steps(2).points();
+    /// ```
+    pub fn points(&self) -> Vec<(i32, i32)> {
+        let (x0, y0) = (self.from.0 as f32, self.from.1 as f32);
+        let (x1, y1) = (self.to.0 as f32, self.to.1 as f32);
+
+        (1..=self.steps)
+            .map(|i| {
+                let t = self.easing.apply(i as f32 / self.steps as f32);
+
+                let (x, y) = match self.control {
+                    Some((cx, cy)) => {
+                        let (cx, cy) = (cx as f32, cy as f32);
+                        let u = 1.0 - t;
+                        (
+                            u * u * x0 + 2.0 * u * t * cx + t * t * x1,
+                            u * u * y0 + 2.0 * u * t * cy + t * t * y1,
+                        )
+                    }
+                    None => (x0 + (x1 - x0) * t, y0 + (y1 - y0) * t),
+                };
+
+                (x.round() as i32, y.round() as i32)
+            })
+            .collect()
+    }
+
+    /// Creates the [`Input`]s that move the cursor along the path using relative
+    /// motions.
+    ///
+    /// Relative motions are subject to the mouse speed and acceleration settings of the
+    /// user (see [`MouseMotion::Relative`]), which means that the cursor may not end up
+    /// exactly at the end of the path.
+    ///
+    /// [`Input`]: struct.Input.html
+    /// [`MouseMotion::Relative`]: enum.MouseMotion.html#variant.Relative
+    pub fn to_relative_inputs(&self) -> Vec<Input> {
+        let mut last = self.from;
+
+        self.points()
+            .into_iter()
+            .map(|(x, y)| {
+                let motion = MouseMotion::Relative {
+                    dx: x - last.0,
+                    dy: y - last.1,
+                };
+                last = (x, y);
+                Input::from_motion(motion)
+            })
+            .collect()
+    }
+
+    /// Creates the [`Input`]s that move the cursor along the path using absolute
+    /// motions (see [`MouseMotion::AbsolutePixels`]). The path may span several
+    /// monitors.
+    ///
+    /// [`Input`]: struct.Input.html
+    /// [`MouseMotion::AbsolutePixels`]: enum.MouseMotion.html#variant.AbsolutePixels
+    pub fn to_absolute_inputs(&self) -> Vec<Input> {
+        self.points()
+            .into_iter()
+            .map(|(x, y)| {
+                Input::from_motion(MouseMotion::AbsolutePixels {
+                    x,
+                    y,
+                    virtual_desk: true,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn straight_path() {
+        let points = MousePath::new((0, 0), (100, 50)).steps(2).points();
+        assert_eq!(points, [(50, 25), (100, 50)]);
+    }
+
+    #[test]
+    fn path_has_at_least_one_step() {
+        let points = MousePath::new((10, 10), (20, 30)).steps(0).points();
+        assert_eq!(points, [(20, 30)]);
+    }
+
+    #[test]
+    fn path_always_ends_at_the_destination() {
+        for &easing in &[
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            let points = MousePath::new((-40, 15), (300, -120))
+                .curve((500, 500))
+                .easing(easing)
+                .steps(7)
+                .points();
+
+            assert_eq!(points.len(), 7);
+            assert_eq!(points.last(), Some(&(300, -120)));
+        }
+    }
+}
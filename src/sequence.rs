@@ -0,0 +1,447 @@
+use crate::error::WindowsError;
+use crate::input::{try_send_inputs, Action, Button, Input, MouseMotion, WheelDirection};
+use crate::keylike::Keylike;
+use crate::vk::Vk;
+
+use std::thread;
+use std::time::Duration;
+
+/// A step of an [`InputSequence`].
+///
+/// [`InputSequence`]: struct.InputSequence.html
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum Step {
+    /// An input to send.
+    Input(Input),
+    /// A pause between two inputs.
+    Wait(Duration),
+}
+
+/// A sequence of inputs and pauses, built by chaining method calls.
+///
+/// The inputs between two pauses are sent in a single batch, which means that the
+/// system inserts them into the input stream without interleaving them with other
+/// events.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use std::time::Duration;
+/// use winput::{Button, InputSequence, Vk};
+///
+/// InputSequence::new()
+///     .press(Vk::Control)
+///     .tap(Vk::C)
+///     .release(Vk::Control)
+///     .wait(Duration::from_millis(100))
+///     .click(Button::Left)
+///     .send()
+///     .unwrap();
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct InputSequence {
+    steps: Vec<Step>,
+}
+
+impl InputSequence {
+    /// Creates a new empty [`InputSequence`].
+    ///
+    /// [`InputSequence`]: struct.InputSequence.html
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    /// Appends the given [`Input`] to the sequence.
+    ///
+    /// [`Input`]: struct.Input.html
+    #[inline]
+    pub fn input(mut self, input: Input) -> Self {
+        self.steps.push(Step::Input(input));
+        self
+    }
+
+    /// Appends the given [`Input`]s to the sequence.
+    ///
+    /// [`Input`]: struct.Input.html
+    #[inline]
+    pub fn inputs(mut self, inputs: impl IntoIterator<Item = Input>) -> Self {
+        self.steps.extend(inputs.into_iter().map(Step::Input));
+        self
+    }
+
+    /// Appends an event that presses the given key.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if `key` was not a valid key (see [`Keylike`]).
+    ///
+    /// [`Keylike`]: trait.Keylike.html
+    #[inline]
+    pub fn press<K: Keylike>(self, key: K) -> Self {
+        let mut inputs = Vec::new();
+        key.produce_inputs(Action::Press, &mut inputs);
+        self.inputs(inputs)
+    }
+
+    /// Appends an event that releases the given key.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if `key` was not a valid key (see [`Keylike`]).
+    ///
+    /// [`Keylike`]: trait.Keylike.html
+    #[inline]
+    pub fn release<K: Keylike>(self, key: K) -> Self {
+        let mut inputs = Vec::new();
+        key.produce_inputs(Action::Release, &mut inputs);
+        self.inputs(inputs)
+    }
+
+    /// Appends two events: one that presses the given key and one that releases it.
+    ///
+    /// ## Panics
+    ///
+    /// This function panics if `key` was not a valid key (see [`Keylike`]).
+    ///
+    /// [`Keylike`]: trait.Keylike.html
+    #[inline]
+    pub fn tap<K: Keylike>(self, key: K) -> Self {
+        self.press(key).release(key)
+    }
+
+    /// Appends the events that press the given keys in order, then release them in
+    /// reverse order. This is how keyboard shortcuts such as `Ctrl+Shift+Esc` are
+    /// typed.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{InputSequence, Vk};
+    ///
+    /// // Presses Control, presses C, releases C, then releases Control.
+    /// InputSequence::new()
+    ///     .chord(&[Vk::Control, Vk::C])
+    ///     .send()
+    ///     .unwrap();
+    /// ```
+    pub fn chord(mut self, keys: &[Vk]) -> Self {
+        for &vk in keys {
+            self = self.press(vk);
+        }
+        for &vk in keys.iter().rev() {
+            self = self.release(vk);
+        }
+
+        self
+    }
+
+    /// Appends the events that type the given string.
+    ///
+    /// Characters above `0x0000ffff` are sent as two UTF-16 code units.
+    pub fn text(mut self, s: &str) -> Self {
+        for c in s.chars() {
+            self = self
+                .inputs(Input::from_char_utf16(c, Action::Press))
+                .inputs(Input::from_char_utf16(c, Action::Release));
+        }
+
+        self
+    }
+
+    /// Appends two events: one that presses the given mouse button and one that
+    /// releases it.
+    #[inline]
+    pub fn click(self, button: Button) -> Self {
+        self.tap(button)
+    }
+
+    /// Appends an event that moves the mouse according to the given [`MouseMotion`].
+    ///
+    /// [`MouseMotion`]: enum.MouseMotion.html
+    #[inline]
+    pub fn motion(self, motion: MouseMotion) -> Self {
+        self.input(Input::from_motion(motion))
+    }
+
+    /// Appends an event that moves the mouse relatively to its current position, in
+    /// pixels.
+    #[inline]
+    pub fn move_relative(self, dx: i32, dy: i32) -> Self {
+        self.motion(MouseMotion::Relative { dx, dy })
+    }
+
+    /// Appends an event that moves the mouse to the given normalized coordinates (see
+    /// [`MouseMotion::Absolute`]).
+    ///
+    /// [`MouseMotion::Absolute`]: enum.MouseMotion.html#variant.Absolute
+    #[inline]
+    pub fn move_absolute(self, x: f32, y: f32) -> Self {
+        self.motion(MouseMotion::Absolute {
+            x,
+            y,
+            virtual_desk: false,
+        })
+    }
+
+    /// Appends an event that rotates the vertical mouse wheel by the given amount (see
+    /// [`Input::from_wheel`]).
+    ///
+    /// [`Input::from_wheel`]: struct.Input.html#method.from_wheel
+    #[inline]
+    pub fn scroll(self, amount: f32) -> Self {
+        self.input(Input::from_wheel(amount, WheelDirection::Vertical))
+    }
+
+    /// Appends a literal of the [`input_seq!`] macro.
+    ///
+    /// [`input_seq!`]: macro.input_seq.html
+    #[doc(hidden)]
+    #[inline(always)]
+    pub fn literal<L: SequenceLiteral>(self, literal: L) -> Self {
+        literal.append_to(self)
+    }
+
+    /// Appends a pause of the given duration.
+    #[inline]
+    pub fn wait(mut self, duration: Duration) -> Self {
+        self.steps.push(Step::Wait(duration));
+        self
+    }
+
+    /// Returns the number of inputs in this sequence (pauses are not counted).
+    pub fn len(&self) -> usize {
+        self.steps
+            .iter()
+            .filter(|step| matches!(step, Step::Input(_)))
+            .count()
+    }
+
+    /// Checks whether this sequence contains no inputs.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the inputs of this sequence, without the pauses.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use std::time::Duration;
+    /// use winput::{InputSequence, Vk};
+    ///
+    /// let inputs = InputSequence::new()
+    ///     .tap(Vk::A)
+    ///     .wait(Duration::from_millis(100))
+    ///     .tap(Vk::B)
+    ///     .to_inputs();
+    ///
+    /// // The four inputs are sent at once.
+    /// winput::send_inputs(&inputs);
+    /// ```
+    pub fn to_inputs(&self) -> Vec<Input> {
+        self.steps
+            .iter()
+            .filter_map(|step| match step {
+                Step::Input(input) => Some(*input),
+                Step::Wait(_) => None,
+            })
+            .collect()
+    }
+
+    /// Sends this sequence.
+    ///
+    /// The inputs between two pauses are sent in a single batch. The calling thread
+    /// sleeps during the pauses.
+    ///
+    /// ## Returns
+    ///
+    /// On success, the number of inputs that were sent is returned. If an input is
+    /// rejected by the system, the function stops and returns the error reported by the
+    /// system (see [`try_send_inputs`]).
+    ///
+    /// [`try_send_inputs`]: fn.try_send_inputs.html
+    pub fn send(&self) -> Result<u32, WindowsError> {
+        /// Sends the inputs of the batch, if there are any.
+        fn flush(batch: &mut Vec<Input>) -> Result<u32, WindowsError> {
+            if batch.is_empty() {
+                return Ok(0);
+            }
+
+            let count = try_send_inputs(&batch[..])?;
+            batch.clear();
+            Ok(count)
+        }
+
+        let mut count = 0;
+        let mut batch = Vec::new();
+
+        for step in &self.steps {
+            match step {
+                Step::Input(input) => batch.push(*input),
+                Step::Wait(duration) => {
+                    count += flush(&mut batch)?;
+                    thread::sleep(*duration);
+                }
+            }
+        }
+
+        count += flush(&mut batch)?;
+        Ok(count)
+    }
+}
+
+/// A literal that can be used in the [`input_seq!`] macro: a string is typed and a
+/// character is tapped.
+///
+/// [`input_seq!`]: macro.input_seq.html
+#[doc(hidden)]
+pub trait SequenceLiteral {
+    fn append_to(self, sequence: InputSequence) -> InputSequence;
+}
+
+impl SequenceLiteral for &str {
+    #[inline(always)]
+    fn append_to(self, sequence: InputSequence) -> InputSequence {
+        sequence.text(self)
+    }
+}
+
+impl SequenceLiteral for char {
+    #[inline(always)]
+    fn append_to(self, sequence: InputSequence) -> InputSequence {
+        sequence.tap(self)
+    }
+}
+
+/// Creates a `Vec<Input>` from a comma-separated list of steps.
+///
+/// Each step is one of:
+///
+/// * A list of [`Vk`] variants separated by `+` (such as `Control + Shift + Esc`): the
+///   keys are pressed in order then released in reverse order (see
+///   [`InputSequence::chord`]). A single key is simply tapped.
+/// * A string literal: the string is typed (see [`InputSequence::text`]).
+/// * A character literal: the character is tapped.
+/// * `press Key` or `release Key`: the [`Vk`] is only pressed or released.
+/// * `click Button`: the [`Button`] is clicked.
+///
+/// Key and button names are checked at compile time.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::input_seq;
+///
+/// let inputs = input_seq![Control + C, "hello", Enter, click Left];
+/// winput::send_inputs(&inputs);
+/// ```
+///
+/// [`Vk`]: enum.Vk.html
+/// [`Button`]: enum.Button.html
+/// [`InputSequence::chord`]: struct.InputSequence.html#method.chord
+/// [`InputSequence::text`]: struct.InputSequence.html#method.text
+#[macro_export]
+macro_rules! input_seq {
+    (@steps $seq:expr;) => {
+        $seq
+    };
+    (@steps $seq:expr; click $button:ident $(, $($rest:tt)*)?) => {
+        $crate::input_seq!(@steps $seq.click($crate::Button::$button); $($($rest)*)?)
+    };
+    (@steps $seq:expr; press $vk:ident $(, $($rest:tt)*)?) => {
+        $crate::input_seq!(@steps $seq.press($crate::Vk::$vk); $($($rest)*)?)
+    };
+    (@steps $seq:expr; release $vk:ident $(, $($rest:tt)*)?) => {
+        $crate::input_seq!(@steps $seq.release($crate::Vk::$vk); $($($rest)*)?)
+    };
+    (@steps $seq:expr; $literal:literal $(, $($rest:tt)*)?) => {
+        $crate::input_seq!(@steps $seq.literal($literal); $($($rest)*)?)
+    };
+    (@steps $seq:expr; $vk:ident $(+ $vks:ident)* $(, $($rest:tt)*)?) => {
+        $crate::input_seq!(
+            @steps $seq.chord(&[$crate::Vk::$vk $(, $crate::Vk::$vks)*]);
+            $($($rest)*)?
+        )
+    };
+    ($($steps:tt)*) => {
+        $crate::input_seq!(@steps $crate::InputSequence::new(); $($steps)*).to_inputs()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chord_releases_in_reverse_order() {
+        let inputs = InputSequence::new()
+            .chord(&[Vk::Control, Vk::Shift, Vk::Escape])
+            .to_inputs();
+
+        assert_eq!(
+            inputs,
+            [
+                Input::from_vk(Vk::Control, Action::Press),
+                Input::from_vk(Vk::Shift, Action::Press),
+                Input::from_vk(Vk::Escape, Action::Press),
+                Input::from_vk(Vk::Escape, Action::Release),
+                Input::from_vk(Vk::Shift, Action::Release),
+                Input::from_vk(Vk::Control, Action::Release),
+            ]
+        );
+    }
+
+    #[test]
+    fn to_inputs_skips_pauses() {
+        let sequence = InputSequence::new()
+            .tap(Vk::A)
+            .wait(Duration::from_millis(100))
+            .tap(Vk::B);
+
+        assert_eq!(sequence.len(), 4);
+        assert_eq!(
+            sequence.to_inputs(),
+            [
+                Input::from_vk(Vk::A, Action::Press),
+                Input::from_vk(Vk::A, Action::Release),
+                Input::from_vk(Vk::B, Action::Press),
+                Input::from_vk(Vk::B, Action::Release),
+            ]
+        );
+    }
+
+    #[test]
+    fn macro_chords_and_literals() {
+        let expected = InputSequence::new()
+            .chord(&[Vk::Control, Vk::C])
+            .text("hello")
+            .tap('!')
+            .tap(Vk::Enter)
+            .to_inputs();
+
+        assert_eq!(input_seq![Control + C, "hello", '!', Enter], expected);
+    }
+
+    #[test]
+    fn macro_press_release_and_click() {
+        let expected = InputSequence::new()
+            .press(Vk::Shift)
+            .click(Button::Left)
+            .release(Vk::Shift)
+            .to_inputs();
+
+        assert_eq!(input_seq![press Shift, click Left, release Shift], expected);
+    }
+
+    #[test]
+    fn macro_trailing_comma() {
+        assert_eq!(
+            input_seq![A, "b", click Right,],
+            input_seq![A, "b", click Right]
+        );
+        assert_eq!(input_seq![press A,], input_seq![press A]);
+        assert!(input_seq![].is_empty());
+    }
+}
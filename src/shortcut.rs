@@ -0,0 +1,222 @@
+use crate::modifiers::Modifiers;
+use crate::vk::Vk;
+
+#[cfg(feature = "message_loop")]
+use crate::input::Action;
+#[cfg(feature = "message_loop")]
+use crate::message_loop::Event;
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// The names of the modifiers, in the order they are written in a shortcut. Each entry
+/// contains the generic modifier followed by its left and right variants.
+const MODIFIER_NAMES: [[(Modifiers, &str); 3]; 4] = [
+    [
+        (Modifiers::CONTROL, "Ctrl"),
+        (Modifiers::LEFT_CONTROL, "LCtrl"),
+        (Modifiers::RIGHT_CONTROL, "RCtrl"),
+    ],
+    [
+        (Modifiers::ALT, "Alt"),
+        (Modifiers::LEFT_ALT, "LAlt"),
+        (Modifiers::RIGHT_ALT, "RAlt"),
+    ],
+    [
+        (Modifiers::SHIFT, "Shift"),
+        (Modifiers::LEFT_SHIFT, "LShift"),
+        (Modifiers::RIGHT_SHIFT, "RShift"),
+    ],
+    [
+        (Modifiers::WIN, "Win"),
+        (Modifiers::LEFT_WIN, "LWin"),
+        (Modifiers::RIGHT_WIN, "RWin"),
+    ],
+];
+
+/// A keyboard shortcut: a key pressed while a set of modifiers is held down.
+///
+/// Shortcuts can be parsed from and formatted to strings such as `"Ctrl+Shift+P"`,
+/// which makes them easy to store in configuration files.
+///
+/// ## Example
+///
+/// ```rust
+/// use winput::{Modifiers, Shortcut, Vk};
+///
+/// let shortcut: Shortcut = "ctrl+shift+p".parse().unwrap();
+///
+/// assert_eq!(shortcut.modifiers, Modifiers::CONTROL | Modifiers::SHIFT);
+/// assert_eq!(shortcut.key, Vk::P);
+/// assert_eq!(shortcut.to_string(), "Ctrl+Shift+P");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Shortcut {
+    /// The modifiers that must be held down.
+    pub modifiers: Modifiers,
+    /// The key that triggers the shortcut.
+    pub key: Vk,
+}
+
+impl Shortcut {
+    /// Creates a new [`Shortcut`].
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    #[inline(always)]
+    pub const fn new(modifiers: Modifiers, key: Vk) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Checks whether the given modifiers, held down when the key is pressed, trigger
+    /// this shortcut.
+    ///
+    /// Generic modifiers of the shortcut are matched by either of their sides, while
+    /// sided modifiers must be held on the same side. Any additional modifier
+    /// prevents the shortcut from being triggered.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::{Modifiers, Shortcut, Vk};
+    ///
+    /// let shortcut = Shortcut::new(Modifiers::CONTROL, Vk::S);
+    ///
+    /// assert!(shortcut.is_triggered_by(Modifiers::RIGHT_CONTROL));
+    /// assert!(!shortcut.is_triggered_by(Modifiers::CONTROL | Modifiers::SHIFT));
+    /// ```
+    pub fn is_triggered_by(&self, mut held: Modifiers) -> bool {
+        // When the key of the shortcut is itself a modifier, it is held down as well.
+        if let Some(key) = Modifiers::from_vk(self.key) {
+            held.remove(key);
+        }
+
+        held.to_generic() == self.modifiers.to_generic() && held.contains(self.modifiers)
+    }
+
+    /// Checks whether the given [`Event`] triggers this shortcut.
+    ///
+    /// The event must be the press of the key of the shortcut. Modifiers are checked
+    /// using their physical state (see [`Modifiers::current_async`]) at the time this
+    /// function is called, which means it should be called as soon as the event is
+    /// received.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Shortcut;
+    /// use winput::message_loop;
+    ///
+    /// let shortcut: Shortcut = "Ctrl+Alt+Q".parse().unwrap();
+    /// let receiver = message_loop::start().unwrap();
+    ///
+    /// while let Ok(event) = receiver.next_event() {
+    ///     if shortcut.matches(&event) {
+    ///         break;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Event`]: message_loop/enum.Event.html
+    /// [`Modifiers::current_async`]: struct.Modifiers.html#method.current_async
+    #[cfg(feature = "message_loop")]
+    pub fn matches(&self, event: &Event) -> bool {
+        match *event {
+            Event::Keyboard {
+                vk,
+                action: Action::Press,
+                ..
+            } if vk == self.key => self.is_triggered_by(Modifiers::current_async()),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Shortcut {
+    /// Formats this shortcut as a list of modifiers followed by the key, separated by
+    /// `+` (for example "Ctrl+Shift+P"). The result can be parsed back using the
+    /// `FromStr` implementation of [`Shortcut`].
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for names in MODIFIER_NAMES.iter() {
+            let (generic, generic_name) = names[0];
+            let mut has_side = false;
+            for &(m, name) in names[1..].iter() {
+                if self.modifiers.bits() & m.bits() & !generic.bits() != 0 {
+                    write!(f, "{}+", name)?;
+                    has_side = true;
+                }
+            }
+
+            if !has_side && self.modifiers.contains(generic) {
+                write!(f, "{}+", generic_name)?;
+            }
+        }
+
+        write!(f, "{}", self.key)
+    }
+}
+
+/// An error that can be produced when parsing a [`Shortcut`] from a string.
+///
+/// [`Shortcut`]: struct.Shortcut.html
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseShortcutError {
+    shortcut: String,
+}
+
+impl fmt::Display for ParseShortcutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid shortcut: \"{}\"", self.shortcut)
+    }
+}
+
+impl Error for ParseShortcutError {}
+
+/// Parses the name of a modifier, as written in a shortcut.
+fn parse_modifier(name: &str) -> Option<Modifiers> {
+    let name = name.trim();
+
+    MODIFIER_NAMES
+        .iter()
+        .flat_map(|names| names.iter())
+        .find(|(_, n)| n.eq_ignore_ascii_case(name))
+        .map(|&(m, _)| m)
+        .or_else(|| {
+            // Long names such as "Control" or "LeftShift".
+            name.parse::<Vk>().ok().and_then(Modifiers::from_vk)
+        })
+}
+
+impl FromStr for Shortcut {
+    type Err = ParseShortcutError;
+
+    /// Parses a shortcut from a list of modifiers followed by a key, separated by `+`.
+    /// Modifiers and keys are parsed case-insensitively, and keys accept any name
+    /// supported by the `FromStr` implementation of [`Vk`].
+    ///
+    /// [`Vk`]: enum.Vk.html
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut modifiers = Modifiers::empty();
+        let mut rest = s;
+
+        // The key itself may contain a `+` (such as "Num+"), so modifiers are consumed
+        // one by one until the remaining string is not prefixed by a modifier.
+        while let Some(index) = rest.find('+').filter(|&i| i > 0) {
+            match parse_modifier(&rest[..index]) {
+                Some(m) => modifiers |= m,
+                None => break,
+            }
+            rest = &rest[index + 1..];
+        }
+
+        match rest.trim().parse::<Vk>() {
+            Ok(key) => Ok(Self { modifiers, key }),
+            Err(_) => Err(ParseShortcutError {
+                shortcut: s.to_string(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,352 @@
+//! The UI Automation interfaces used by the crate, which are not exposed by `winapi`.
+//!
+//! Only the methods up to the last one the crate calls are declared: the remaining
+//! entries of the virtual tables are never accessed. The interfaces the crate does not
+//! use are declared as `IUnknown`.
+
+#![allow(non_snake_case)]
+
+use std::ops::Deref;
+use std::ptr::{self, NonNull};
+
+use winapi::ctypes::{c_int, c_void};
+use winapi::shared::guiddef::REFIID;
+use winapi::shared::minwindef::BOOL;
+use winapi::shared::windef::{HWND, POINT};
+use winapi::shared::winerror;
+use winapi::shared::wtypes::BSTR;
+use winapi::shared::wtypesbase::CLSCTX_INPROC_SERVER;
+use winapi::um::oaidl::{SAFEARRAY, VARIANT};
+use winapi::um::unknwnbase::{IUnknown, IUnknownVtbl};
+use winapi::um::winnt::HRESULT;
+use winapi::um::{combaseapi, objbase, oleauto};
+use winapi::{Class, Interface, RIDL};
+
+use crate::error::WindowsError;
+
+/// The identifier of the `TextPattern` control pattern.
+pub const UIA_TEXT_PATTERN_ID: c_int = 10014;
+/// The identifier of the `TextPattern2` control pattern.
+pub const UIA_TEXT_PATTERN_2_ID: c_int = 10024;
+/// The `TextUnit_Character` text unit.
+pub const TEXT_UNIT_CHARACTER: c_int = 0;
+
+RIDL! {#[uuid(0xff48dba4, 0x60ef, 0x4201, 0xaa, 0x87, 0x54, 0x10, 0x3e, 0xef, 0x59, 0x4e)]
+class CUIAutomation;}
+
+RIDL! {#[uuid(0x30cbe57d, 0xd9d0, 0x452a, 0xab, 0x13, 0x7a, 0xc5, 0xac, 0x48, 0x25, 0xee)]
+interface IUIAutomation(IUIAutomationVtbl): IUnknown(IUnknownVtbl) {
+    fn CompareElements(
+        el1: *mut IUIAutomationElement,
+        el2: *mut IUIAutomationElement,
+        areSame: *mut BOOL,
+    ) -> HRESULT,
+    fn CompareRuntimeIds(
+        runtimeId1: *mut SAFEARRAY,
+        runtimeId2: *mut SAFEARRAY,
+        areSame: *mut BOOL,
+    ) -> HRESULT,
+    fn GetRootElement(
+        root: *mut *mut IUIAutomationElement,
+    ) -> HRESULT,
+    fn ElementFromHandle(
+        hwnd: HWND,
+        element: *mut *mut IUIAutomationElement,
+    ) -> HRESULT,
+    fn ElementFromPoint(
+        pt: POINT,
+        element: *mut *mut IUIAutomationElement,
+    ) -> HRESULT,
+    fn GetFocusedElement(
+        element: *mut *mut IUIAutomationElement,
+    ) -> HRESULT,
+}}
+
+RIDL! {#[uuid(0xd22108aa, 0x8ac5, 0x49a5, 0x83, 0x7b, 0x37, 0xbb, 0xb3, 0xd7, 0x59, 0x1e)]
+interface IUIAutomationElement(IUIAutomationElementVtbl): IUnknown(IUnknownVtbl) {
+    fn SetFocus() -> HRESULT,
+    fn GetRuntimeId(
+        runtimeId: *mut *mut SAFEARRAY,
+    ) -> HRESULT,
+    fn FindFirst(
+        scope: c_int,
+        condition: *mut IUnknown,
+        found: *mut *mut IUIAutomationElement,
+    ) -> HRESULT,
+    fn FindAll(
+        scope: c_int,
+        condition: *mut IUnknown,
+        found: *mut *mut IUnknown,
+    ) -> HRESULT,
+    fn FindFirstBuildCache(
+        scope: c_int,
+        condition: *mut IUnknown,
+        cacheRequest: *mut IUnknown,
+        found: *mut *mut IUIAutomationElement,
+    ) -> HRESULT,
+    fn FindAllBuildCache(
+        scope: c_int,
+        condition: *mut IUnknown,
+        cacheRequest: *mut IUnknown,
+        found: *mut *mut IUnknown,
+    ) -> HRESULT,
+    fn BuildUpdatedCache(
+        cacheRequest: *mut IUnknown,
+        updatedElement: *mut *mut IUIAutomationElement,
+    ) -> HRESULT,
+    fn GetCurrentPropertyValue(
+        propertyId: c_int,
+        retVal: *mut VARIANT,
+    ) -> HRESULT,
+    fn GetCurrentPropertyValueEx(
+        propertyId: c_int,
+        ignoreDefaultValue: BOOL,
+        retVal: *mut VARIANT,
+    ) -> HRESULT,
+    fn GetCachedPropertyValue(
+        propertyId: c_int,
+        retVal: *mut VARIANT,
+    ) -> HRESULT,
+    fn GetCachedPropertyValueEx(
+        propertyId: c_int,
+        ignoreDefaultValue: BOOL,
+        retVal: *mut VARIANT,
+    ) -> HRESULT,
+    fn GetCurrentPatternAs(
+        patternId: c_int,
+        riid: REFIID,
+        patternObject: *mut *mut c_void,
+    ) -> HRESULT,
+}}
+
+RIDL! {#[uuid(0x32eba289, 0x3583, 0x42c9, 0x9c, 0x59, 0x3b, 0x6d, 0x9a, 0x1e, 0x9b, 0x6a)]
+interface IUIAutomationTextPattern(IUIAutomationTextPatternVtbl): IUnknown(IUnknownVtbl) {
+    fn RangeFromPoint(
+        pt: POINT,
+        range: *mut *mut IUIAutomationTextRange,
+    ) -> HRESULT,
+    fn RangeFromChild(
+        child: *mut IUIAutomationElement,
+        range: *mut *mut IUIAutomationTextRange,
+    ) -> HRESULT,
+    fn GetSelection(
+        ranges: *mut *mut IUIAutomationTextRangeArray,
+    ) -> HRESULT,
+    fn GetVisibleRanges(
+        ranges: *mut *mut IUIAutomationTextRangeArray,
+    ) -> HRESULT,
+    fn get_DocumentRange(
+        range: *mut *mut IUIAutomationTextRange,
+    ) -> HRESULT,
+    fn get_SupportedTextSelection(
+        supportedTextSelection: *mut c_int,
+    ) -> HRESULT,
+}}
+
+RIDL! {#[uuid(0x506a921a, 0xfcc9, 0x409f, 0xb2, 0x3b, 0x37, 0xeb, 0x74, 0x10, 0x68, 0x72)]
+interface IUIAutomationTextPattern2(IUIAutomationTextPattern2Vtbl):
+    IUIAutomationTextPattern(IUIAutomationTextPatternVtbl) {
+    fn RangeFromAnnotation(
+        annotation: *mut IUIAutomationElement,
+        range: *mut *mut IUIAutomationTextRange,
+    ) -> HRESULT,
+    fn GetCaretRange(
+        isActive: *mut BOOL,
+        range: *mut *mut IUIAutomationTextRange,
+    ) -> HRESULT,
+}}
+
+RIDL! {#[uuid(0xce4ae76a, 0xe717, 0x4c98, 0x81, 0xea, 0x47, 0x37, 0x1d, 0x02, 0x8e, 0xb6)]
+interface IUIAutomationTextRangeArray(IUIAutomationTextRangeArrayVtbl):
+    IUnknown(IUnknownVtbl) {
+    fn get_Length(
+        length: *mut c_int,
+    ) -> HRESULT,
+    fn GetElement(
+        index: c_int,
+        element: *mut *mut IUIAutomationTextRange,
+    ) -> HRESULT,
+}}
+
+RIDL! {#[uuid(0xa543cc6a, 0xf4ae, 0x494b, 0x82, 0x39, 0xc8, 0x14, 0x48, 0x11, 0x87, 0xa8)]
+interface IUIAutomationTextRange(IUIAutomationTextRangeVtbl): IUnknown(IUnknownVtbl) {
+    fn Clone(
+        clonedRange: *mut *mut IUIAutomationTextRange,
+    ) -> HRESULT,
+    fn Compare(
+        range: *mut IUIAutomationTextRange,
+        areSame: *mut BOOL,
+    ) -> HRESULT,
+    fn CompareEndpoints(
+        srcEndPoint: c_int,
+        range: *mut IUIAutomationTextRange,
+        targetEndPoint: c_int,
+        compValue: *mut c_int,
+    ) -> HRESULT,
+    fn ExpandToEnclosingUnit(
+        textUnit: c_int,
+    ) -> HRESULT,
+    fn FindAttribute(
+        attr: c_int,
+        val: VARIANT,
+        backward: BOOL,
+        found: *mut *mut IUIAutomationTextRange,
+    ) -> HRESULT,
+    fn FindText(
+        text: BSTR,
+        backward: BOOL,
+        ignoreCase: BOOL,
+        found: *mut *mut IUIAutomationTextRange,
+    ) -> HRESULT,
+    fn GetAttributeValue(
+        attr: c_int,
+        value: *mut VARIANT,
+    ) -> HRESULT,
+    fn GetBoundingRectangles(
+        boundingRects: *mut *mut SAFEARRAY,
+    ) -> HRESULT,
+}}
+
+/// An owned reference to a COM object, released when dropped.
+pub struct Com<T>(NonNull<T>);
+
+impl<T> Com<T> {
+    /// Takes ownership of the reference written by `f` into its argument.
+    ///
+    /// `None` is returned if `f` fails or does not provide an object.
+    pub unsafe fn from_out(f: impl FnOnce(*mut *mut T) -> HRESULT) -> Option<Self> {
+        let mut object = ptr::null_mut();
+        if winerror::FAILED(f(&mut object)) {
+            return None;
+        }
+
+        NonNull::new(object).map(Self)
+    }
+}
+
+impl<T> Deref for Com<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        unsafe { self.0.as_ref() }
+    }
+}
+
+impl<T> Drop for Com<T> {
+    fn drop(&mut self) {
+        // Every COM interface starts with the methods of `IUnknown`.
+        unsafe { (*(self.0.as_ptr() as *mut IUnknown)).Release() };
+    }
+}
+
+/// Initializes COM on the calling thread until it is dropped.
+pub struct Apartment {
+    initialized: bool,
+}
+
+impl Apartment {
+    /// Enters the multithreaded apartment. A thread that already belongs to a
+    /// single-threaded apartment keeps it.
+    pub fn enter() -> Result<Self, WindowsError> {
+        // Calling C code
+        let result = unsafe {
+            combaseapi::CoInitializeEx(ptr::null_mut(), objbase::COINIT_MULTITHREADED)
+        };
+
+        match result {
+            winerror::RPC_E_CHANGED_MODE => Ok(Self { initialized: false }),
+            _ if winerror::SUCCEEDED(result) => Ok(Self { initialized: true }),
+            _ => Err(WindowsError::from_error_code(result as u32)),
+        }
+    }
+}
+
+impl Drop for Apartment {
+    fn drop(&mut self) {
+        if self.initialized {
+            // Calling C code
+            unsafe { combaseapi::CoUninitialize() };
+        }
+    }
+}
+
+/// Creates the UI Automation client.
+pub fn automation(_apartment: &Apartment) -> Result<Com<IUIAutomation>, WindowsError> {
+    let mut result = 0;
+
+    // Calling C code
+    let automation = unsafe {
+        Com::from_out(|object| {
+            result = combaseapi::CoCreateInstance(
+                &CUIAutomation::uuidof(),
+                ptr::null_mut(),
+                CLSCTX_INPROC_SERVER,
+                &IUIAutomation::uuidof(),
+                object as *mut *mut c_void,
+            );
+            result
+        })
+    };
+
+    automation.ok_or_else(|| WindowsError::from_error_code(result as u32))
+}
+
+/// Retrieves the control pattern identified by `pattern_id` of the given element.
+pub fn pattern<T: Interface>(
+    element: &IUIAutomationElement,
+    pattern_id: c_int,
+) -> Option<Com<T>> {
+    // Calling C code
+    unsafe {
+        Com::from_out(|object| {
+            element.GetCurrentPatternAs(
+                pattern_id,
+                &T::uuidof(),
+                object as *mut *mut c_void,
+            )
+        })
+    }
+}
+
+/// Retrieves the bounding rectangles of the lines of the given text range, as
+/// `[left, top, width, height]` in screen coordinates.
+pub fn bounding_rectangles(range: &IUIAutomationTextRange) -> Vec<[f64; 4]> {
+    unsafe {
+        let mut array = ptr::null_mut();
+
+        // Calling C code
+        if winerror::FAILED(range.GetBoundingRectangles(&mut array)) || array.is_null() {
+            return Vec::new();
+        }
+
+        let mut lower = 0;
+        let mut upper = -1;
+        let mut data: *mut c_void = ptr::null_mut();
+
+        // Calling C code
+        let rectangles =
+            if winerror::SUCCEEDED(oleauto::SafeArrayGetLBound(array, 1, &mut lower))
+                && winerror::SUCCEEDED(oleauto::SafeArrayGetUBound(array, 1, &mut upper))
+                && winerror::SUCCEEDED(oleauto::SafeArrayAccessData(array, &mut data))
+            {
+                let len = (upper - lower + 1).max(0) as usize;
+                let values = std::slice::from_raw_parts(data as *const f64, len);
+                let rectangles = values
+                    .chunks_exact(4)
+                    .map(|r| [r[0], r[1], r[2], r[3]])
+                    .collect();
+
+                // Calling C code
+                oleauto::SafeArrayUnaccessData(array);
+                rectangles
+            } else {
+                Vec::new()
+            };
+
+        // Calling C code
+        oleauto::SafeArrayDestroy(array);
+
+        rectangles
+    }
+}
@@ -1,3 +1,11 @@
+use crate::layout::KeyboardLayout;
+use crate::modifiers::Modifiers;
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
 /// A list of all available *Virtual-Key Codes*.
 ///
 /// The official definition can be found [here][vk_link].
@@ -423,6 +431,38 @@ pub enum Vk {
     ///
     /// **VK_F24** = 0x87,
     F24 = 0x87,
+    /// Navigation View key
+    ///
+    /// **VK_NAVIGATION_VIEW** = 0x88
+    NavigationView = 0x88,
+    /// Navigation Menu key
+    ///
+    /// **VK_NAVIGATION_MENU** = 0x89
+    NavigationMenu = 0x89,
+    /// Navigation Up key
+    ///
+    /// **VK_NAVIGATION_UP** = 0x8a
+    NavigationUp = 0x8a,
+    /// Navigation Down key
+    ///
+    /// **VK_NAVIGATION_DOWN** = 0x8b
+    NavigationDown = 0x8b,
+    /// Navigation Left key
+    ///
+    /// **VK_NAVIGATION_LEFT** = 0x8c
+    NavigationLeft = 0x8c,
+    /// Navigation Right key
+    ///
+    /// **VK_NAVIGATION_RIGHT** = 0x8d
+    NavigationRight = 0x8d,
+    /// Navigation Accept key
+    ///
+    /// **VK_NAVIGATION_ACCEPT** = 0x8e
+    NavigationAccept = 0x8e,
+    /// Navigation Cancel key
+    ///
+    /// **VK_NAVIGATION_CANCEL** = 0x8f
+    NavigationCancel = 0x8f,
     /// NUM LOCK key
     ///
     /// **VK_NUMLOCK** = 0x90
@@ -431,6 +471,26 @@ pub enum Vk {
     ///
     /// **VK_SCROLL** = 0x91
     Scroll = 0x91,
+    /// `=` key on the numeric keypad (NEC PC-9800) & Dictionary key (Fujitsu/OASYS)
+    ///
+    /// **VK_OEM_NEC_EQUAL** = **VK_OEM_FJ_JISHO** = 0x92
+    OemNecEqual = 0x92,
+    /// Unregister word key (Fujitsu/OASYS)
+    ///
+    /// **VK_OEM_FJ_MASSHOU** = 0x93
+    OemFjMasshou = 0x93,
+    /// Register word key (Fujitsu/OASYS)
+    ///
+    /// **VK_OEM_FJ_TOUROKU** = 0x94
+    OemFjTouroku = 0x94,
+    /// Left OYAYUBI key (Fujitsu/OASYS)
+    ///
+    /// **VK_OEM_FJ_LOYA** = 0x95
+    OemFjLoya = 0x95,
+    /// Right OYAYUBI key (Fujitsu/OASYS)
+    ///
+    /// **VK_OEM_FJ_ROYA** = 0x96
+    OemFjRoya = 0x96,
     /// Left SHIFT key
     ///
     /// **VK_LSHIFT** = 0xa0
@@ -558,6 +618,110 @@ pub enum Vk {
     ///
     /// **VK_OEM_3** = 0xc0
     Oem3 = 0xc0,
+    /// `/?` key on Brazilian (ABNT) keyboards
+    ///
+    /// **VK_ABNT_C1** = 0xc1
+    AbntC1 = 0xc1,
+    /// Numeric keypad `.` key on Brazilian (ABNT) keyboards
+    ///
+    /// **VK_ABNT_C2** = 0xc2
+    AbntC2 = 0xc2,
+    /// Gamepad A button
+    ///
+    /// **VK_GAMEPAD_A** = 0xc3
+    GamepadA = 0xc3,
+    /// Gamepad B button
+    ///
+    /// **VK_GAMEPAD_B** = 0xc4
+    GamepadB = 0xc4,
+    /// Gamepad X button
+    ///
+    /// **VK_GAMEPAD_X** = 0xc5
+    GamepadX = 0xc5,
+    /// Gamepad Y button
+    ///
+    /// **VK_GAMEPAD_Y** = 0xc6
+    GamepadY = 0xc6,
+    /// Gamepad Right Shoulder button
+    ///
+    /// **VK_GAMEPAD_RIGHT_SHOULDER** = 0xc7
+    GamepadRightShoulder = 0xc7,
+    /// Gamepad Left Shoulder button
+    ///
+    /// **VK_GAMEPAD_LEFT_SHOULDER** = 0xc8
+    GamepadLeftShoulder = 0xc8,
+    /// Gamepad Left Trigger
+    ///
+    /// **VK_GAMEPAD_LEFT_TRIGGER** = 0xc9
+    GamepadLeftTrigger = 0xc9,
+    /// Gamepad Right Trigger
+    ///
+    /// **VK_GAMEPAD_RIGHT_TRIGGER** = 0xca
+    GamepadRightTrigger = 0xca,
+    /// Gamepad D-pad Up
+    ///
+    /// **VK_GAMEPAD_DPAD_UP** = 0xcb
+    GamepadDPadUp = 0xcb,
+    /// Gamepad D-pad Down
+    ///
+    /// **VK_GAMEPAD_DPAD_DOWN** = 0xcc
+    GamepadDPadDown = 0xcc,
+    /// Gamepad D-pad Left
+    ///
+    /// **VK_GAMEPAD_DPAD_LEFT** = 0xcd
+    GamepadDPadLeft = 0xcd,
+    /// Gamepad D-pad Right
+    ///
+    /// **VK_GAMEPAD_DPAD_RIGHT** = 0xce
+    GamepadDPadRight = 0xce,
+    /// Gamepad Menu button
+    ///
+    /// **VK_GAMEPAD_MENU** = 0xcf
+    GamepadMenu = 0xcf,
+    /// Gamepad View button
+    ///
+    /// **VK_GAMEPAD_VIEW** = 0xd0
+    GamepadView = 0xd0,
+    /// Gamepad Left Thumbstick button
+    ///
+    /// **VK_GAMEPAD_LEFT_THUMBSTICK_BUTTON** = 0xd1
+    GamepadLeftThumbstickButton = 0xd1,
+    /// Gamepad Right Thumbstick button
+    ///
+    /// **VK_GAMEPAD_RIGHT_THUMBSTICK_BUTTON** = 0xd2
+    GamepadRightThumbstickButton = 0xd2,
+    /// Gamepad Left Thumbstick up
+    ///
+    /// **VK_GAMEPAD_LEFT_THUMBSTICK_UP** = 0xd3
+    GamepadLeftThumbstickUp = 0xd3,
+    /// Gamepad Left Thumbstick down
+    ///
+    /// **VK_GAMEPAD_LEFT_THUMBSTICK_DOWN** = 0xd4
+    GamepadLeftThumbstickDown = 0xd4,
+    /// Gamepad Left Thumbstick right
+    ///
+    /// **VK_GAMEPAD_LEFT_THUMBSTICK_RIGHT** = 0xd5
+    GamepadLeftThumbstickRight = 0xd5,
+    /// Gamepad Left Thumbstick left
+    ///
+    /// **VK_GAMEPAD_LEFT_THUMBSTICK_LEFT** = 0xd6
+    GamepadLeftThumbstickLeft = 0xd6,
+    /// Gamepad Right Thumbstick up
+    ///
+    /// **VK_GAMEPAD_RIGHT_THUMBSTICK_UP** = 0xd7
+    GamepadRightThumbstickUp = 0xd7,
+    /// Gamepad Right Thumbstick down
+    ///
+    /// **VK_GAMEPAD_RIGHT_THUMBSTICK_DOWN** = 0xd8
+    GamepadRightThumbstickDown = 0xd8,
+    /// Gamepad Right Thumbstick right
+    ///
+    /// **VK_GAMEPAD_RIGHT_THUMBSTICK_RIGHT** = 0xd9
+    GamepadRightThumbstickRight = 0xd9,
+    /// Gamepad Right Thumbstick left
+    ///
+    /// **VK_GAMEPAD_RIGHT_THUMBSTICK_LEFT** = 0xda
+    GamepadRightThumbstickLeft = 0xda,
     /// Used for miscellaneous characters; it can vary by keyboard. For the US standard
     /// keyboard, the `[{` key.
     ///
@@ -580,16 +744,88 @@ pub enum Vk {
     Oem7 = 0xde,
     /// Used for miscellaneous characters; it can vary by keyboard.
     ///
-    /// **VK_OEM_8** = 0xdd
+    /// **VK_OEM_8** = 0xdf
     Oem8 = 0xdf,
+    /// 'AX' key on Japanese AX keyboards
+    ///
+    /// **VK_OEM_AX** = 0xe1
+    OemAx = 0xe1,
     /// Either the angle bracket key or the backslash key on the RT 102-key keyboard.
     ///
     /// **VK_OEM_102** = 0xe2
     Oem102 = 0xe2,
+    /// Help key on ICO keyboards
+    ///
+    /// **VK_ICO_HELP** = 0xe3
+    IcoHelp = 0xe3,
+    /// 00 key on ICO keyboards
+    ///
+    /// **VK_ICO_00** = 0xe4
+    Ico00 = 0xe4,
     /// IME PROCESS key
     ///
     /// **VK_PROCESSKEY** = 0xe5
     ImeProcess = 0xe5,
+    /// Clear key on ICO keyboards
+    ///
+    /// **VK_ICO_CLEAR** = 0xe6
+    IcoClear = 0xe6,
+    /// Used to pass Unicode characters as if they were keystrokes. The Unicode character is the high word of the scan code
+    ///
+    /// **VK_PACKET** = 0xe7
+    Packet = 0xe7,
+    /// Reset key (Nokia/Ericsson)
+    ///
+    /// **VK_OEM_RESET** = 0xe9
+    OemReset = 0xe9,
+    /// Jump key (Nokia/Ericsson)
+    ///
+    /// **VK_OEM_JUMP** = 0xea
+    OemJump = 0xea,
+    /// PA1 key (Nokia/Ericsson)
+    ///
+    /// **VK_OEM_PA1** = 0xeb
+    OemPa1 = 0xeb,
+    /// PA2 key (Nokia/Ericsson)
+    ///
+    /// **VK_OEM_PA2** = 0xec
+    OemPa2 = 0xec,
+    /// PA3 key (Nokia/Ericsson)
+    ///
+    /// **VK_OEM_PA3** = 0xed
+    OemPa3 = 0xed,
+    /// WSCTRL key (Nokia/Ericsson)
+    ///
+    /// **VK_OEM_WSCTRL** = 0xee
+    OemWsCtrl = 0xee,
+    /// CUSEL key (Nokia/Ericsson)
+    ///
+    /// **VK_OEM_CUSEL** = 0xef
+    OemCuSel = 0xef,
+    /// ATTN key (Nokia/Ericsson)
+    ///
+    /// **VK_OEM_ATTN** = 0xf0
+    OemAttn = 0xf0,
+    /// FINISH key (Nokia/Ericsson)
+    ///
+    /// **VK_OEM_FINISH** = 0xf1
+    OemFinish = 0xf1,
+    /// COPY key (Nokia/Ericsson)
+    ///
+    /// **VK_OEM_COPY** = 0xf2
+    OemCopy = 0xf2,
+    /// AUTO key (Nokia/Ericsson)
+    ///
+    /// **VK_OEM_AUTO** = 0xf3
+    OemAuto = 0xf3,
+    /// ENLW key (Nokia/Ericsson)
+    ///
+    /// **VK_OEM_ENLW** = 0xf4
+    OemEnlw = 0xf4,
+    /// BACKTAB key (Nokia/Ericsson)
+    ///
+    /// **VK_OEM_BACKTAB** = 0xf5
+    OemBackTab = 0xf5,
     /// Attn key
     ///
     /// **VK_ATTN** = 0xf6
@@ -614,6 +850,10 @@ pub enum Vk {
     ///
     /// **VK_ZOOM** = 0xfb
     Zoom = 0xfb,
+    /// Reserved
+    ///
+    /// **VK_NONAME** = 0xfc
+    NoName = 0xfc,
     /// PA1 key
     ///
     /// **VK_PA1** = 0xfd
@@ -624,6 +864,43 @@ pub enum Vk {
     OemClear = 0xfe,
 }
 
+/// A group of Virtual-Key Codes, as returned by [`Vk::class`].
+///
+/// [`Vk::class`]: enum.Vk.html#method.class
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyClass {
+    /// The letter keys, from `A` to `Z`.
+    Letter,
+    /// The digit keys of the main keyboard, from `0` to `9`.
+    Digit,
+    /// The function keys, from `F1` to `F24`.
+    Function,
+    /// The PAGE UP, PAGE DOWN, HOME, END and arrow keys, as well as the UI navigation
+    /// keys.
+    Navigation,
+    /// The keys used to edit text, such as ENTER, TAB, BACKSPACE or DELETE.
+    Editing,
+    /// The SHIFT, CTRL, ALT and Windows keys.
+    Modifier,
+    /// The CAPS LOCK, NUM LOCK and SCROLL LOCK keys.
+    Lock,
+    /// The browser, volume, media and application launch keys.
+    Media,
+    /// The keys of the numeric keypad.
+    Numpad,
+    /// The keys whose meaning depends on the keyboard layout or manufacturer.
+    Oem,
+    /// The keys used to control Input Method Editors.
+    Ime,
+    /// The gamepad keys.
+    Gamepad,
+    /// The mouse buttons.
+    Mouse,
+    /// Any other key.
+    Other,
+}
+
 macro_rules! from_vk_for_num {
     ($($t:ty)+) => {
         $(
@@ -639,7 +916,448 @@ macro_rules! from_vk_for_num {
 
 from_vk_for_num!(u8 u16 u32 u64 u128 i8 i16 i32 i64 i128);
 
+/// An error that can be produced when converting a number into a [`Vk`].
+///
+/// [`Vk`]: enum.Vk.html
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TryFromVkError {
+    value: u32,
+}
+
+impl TryFromVkError {
+    /// Returns the number that failed to be converted.
+    #[inline(always)]
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+impl fmt::Display for TryFromVkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid Virtual-Key Code: {:#x}", self.value)
+    }
+}
+
+impl Error for TryFromVkError {}
+
+macro_rules! try_from_num_for_vk {
+    ($($t:ty)+) => {
+        $(
+            impl TryFrom<$t> for Vk {
+                type Error = TryFromVkError;
+
+                #[inline]
+                fn try_from(n: $t) -> Result<Self, Self::Error> {
+                    u8::try_from(n)
+                        .ok()
+                        .and_then(Vk::from_u8_checked)
+                        .ok_or(TryFromVkError { value: n as u32 })
+                }
+            }
+        )+
+    };
+}
+
+try_from_num_for_vk!(u8 u16 u32);
+
+/// The DirectInput keyboard constants (`DIK_*`) of the keys that have one.
+///
+/// DirectInput constants identify physical keys by their position on a US keyboard, and
+/// extended keys are identified by their scan code with the high bit set.
+const DIK_CODES: [(Vk, u8); 134] = [
+    (Vk::Escape, 0x01),
+    (Vk::_1, 0x02),
+    (Vk::_2, 0x03),
+    (Vk::_3, 0x04),
+    (Vk::_4, 0x05),
+    (Vk::_5, 0x06),
+    (Vk::_6, 0x07),
+    (Vk::_7, 0x08),
+    (Vk::_8, 0x09),
+    (Vk::_9, 0x0a),
+    (Vk::_0, 0x0b),
+    (Vk::Minus, 0x0c),
+    (Vk::Plus, 0x0d),
+    (Vk::Backspace, 0x0e),
+    (Vk::Tab, 0x0f),
+    (Vk::Q, 0x10),
+    (Vk::W, 0x11),
+    (Vk::E, 0x12),
+    (Vk::R, 0x13),
+    (Vk::T, 0x14),
+    (Vk::Y, 0x15),
+    (Vk::U, 0x16),
+    (Vk::I, 0x17),
+    (Vk::O, 0x18),
+    (Vk::P, 0x19),
+    (Vk::Oem4, 0x1a),
+    (Vk::Oem6, 0x1b),
+    (Vk::Enter, 0x1c),
+    (Vk::LeftControl, 0x1d),
+    (Vk::A, 0x1e),
+    (Vk::S, 0x1f),
+    (Vk::D, 0x20),
+    (Vk::F, 0x21),
+    (Vk::G, 0x22),
+    (Vk::H, 0x23),
+    (Vk::J, 0x24),
+    (Vk::K, 0x25),
+    (Vk::L, 0x26),
+    (Vk::Oem1, 0x27),
+    (Vk::Oem7, 0x28),
+    (Vk::Oem3, 0x29),
+    (Vk::LeftShift, 0x2a),
+    (Vk::Oem5, 0x2b),
+    (Vk::Z, 0x2c),
+    (Vk::X, 0x2d),
+    (Vk::C, 0x2e),
+    (Vk::V, 0x2f),
+    (Vk::B, 0x30),
+    (Vk::N, 0x31),
+    (Vk::M, 0x32),
+    (Vk::Comma, 0x33),
+    (Vk::Period, 0x34),
+    (Vk::Oem2, 0x35),
+    (Vk::RightShift, 0x36),
+    (Vk::Multiply, 0x37),
+    (Vk::LeftMenu, 0x38),
+    (Vk::Space, 0x39),
+    (Vk::CapsLock, 0x3a),
+    (Vk::F1, 0x3b),
+    (Vk::F2, 0x3c),
+    (Vk::F3, 0x3d),
+    (Vk::F4, 0x3e),
+    (Vk::F5, 0x3f),
+    (Vk::F6, 0x40),
+    (Vk::F7, 0x41),
+    (Vk::F8, 0x42),
+    (Vk::F9, 0x43),
+    (Vk::F10, 0x44),
+    (Vk::Numlock, 0x45),
+    (Vk::Scroll, 0x46),
+    (Vk::Numpad7, 0x47),
+    (Vk::Numpad8, 0x48),
+    (Vk::Numpad9, 0x49),
+    (Vk::Subtract, 0x4a),
+    (Vk::Numpad4, 0x4b),
+    (Vk::Numpad5, 0x4c),
+    (Vk::Numpad6, 0x4d),
+    (Vk::Add, 0x4e),
+    (Vk::Numpad1, 0x4f),
+    (Vk::Numpad2, 0x50),
+    (Vk::Numpad3, 0x51),
+    (Vk::Numpad0, 0x52),
+    (Vk::Decimal, 0x53),
+    (Vk::Oem102, 0x56),
+    (Vk::F11, 0x57),
+    (Vk::F12, 0x58),
+    (Vk::F13, 0x64),
+    (Vk::F14, 0x65),
+    (Vk::F15, 0x66),
+    (Vk::Kana, 0x70),
+    (Vk::AbntC1, 0x73),
+    (Vk::Convert, 0x79),
+    (Vk::NonConvert, 0x7b),
+    (Vk::AbntC2, 0x7e),
+    (Vk::OemNecEqual, 0x8d),
+    (Vk::PrevTrack, 0x90),
+    (Vk::Kanji, 0x94),
+    (Vk::NextTrack, 0x99),
+    (Vk::RightControl, 0x9d),
+    (Vk::VolumeMute, 0xa0),
+    (Vk::StartApp2, 0xa1),
+    (Vk::MediaPlayPause, 0xa2),
+    (Vk::MediaStop, 0xa4),
+    (Vk::VolumeDown, 0xae),
+    (Vk::VolumeUp, 0xb0),
+    (Vk::BrowserHome, 0xb2),
+    (Vk::Separator, 0xb3),
+    (Vk::Divide, 0xb5),
+    (Vk::PrintScreen, 0xb7),
+    (Vk::RightMenu, 0xb8),
+    (Vk::Pause, 0xc5),
+    (Vk::Home, 0xc7),
+    (Vk::UpArrow, 0xc8),
+    (Vk::PageUp, 0xc9),
+    (Vk::LeftArrow, 0xcb),
+    (Vk::RightArrow, 0xcd),
+    (Vk::End, 0xcf),
+    (Vk::DownArrow, 0xd0),
+    (Vk::PageDown, 0xd1),
+    (Vk::Insert, 0xd2),
+    (Vk::Delete, 0xd3),
+    (Vk::LeftWin, 0xdb),
+    (Vk::RightWin, 0xdc),
+    (Vk::Apps, 0xdd),
+    (Vk::Sleep, 0xdf),
+    (Vk::BrowserSearch, 0xe5),
+    (Vk::BrowserFavorites, 0xe6),
+    (Vk::BrowserRefresh, 0xe7),
+    (Vk::BrowserStop, 0xe8),
+    (Vk::BrowserForward, 0xe9),
+    (Vk::BrowserBack, 0xea),
+    (Vk::StartApp1, 0xeb),
+    (Vk::StartMail, 0xec),
+    (Vk::SelectMedia, 0xed),
+];
+
 impl Vk {
+    /// Every Virtual-Key Code, sorted by value.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Vk;
+    ///
+    /// assert!(Vk::ALL.contains(&Vk::Enter));
+    /// ```
+    pub const ALL: &'static [Vk] = &[
+        Vk::MouseLeft,
+        Vk::MouseRight,
+        Vk::Cancel,
+        Vk::MouseMiddle,
+        Vk::MouseX1,
+        Vk::MouseX2,
+        Vk::Backspace,
+        Vk::Tab,
+        Vk::Clear,
+        Vk::Enter,
+        Vk::Shift,
+        Vk::Control,
+        Vk::Alt,
+        Vk::Pause,
+        Vk::CapsLock,
+        Vk::Kana,
+        Vk::ImeOn,
+        Vk::Junja,
+        Vk::Final,
+        Vk::Kanji,
+        Vk::ImeOff,
+        Vk::Escape,
+        Vk::Convert,
+        Vk::NonConvert,
+        Vk::Accept,
+        Vk::ModeChange,
+        Vk::Space,
+        Vk::PageUp,
+        Vk::PageDown,
+        Vk::End,
+        Vk::Home,
+        Vk::LeftArrow,
+        Vk::UpArrow,
+        Vk::RightArrow,
+        Vk::DownArrow,
+        Vk::Select,
+        Vk::Print,
+        Vk::Execute,
+        Vk::PrintScreen,
+        Vk::Insert,
+        Vk::Delete,
+        Vk::Help,
+        Vk::_0,
+        Vk::_1,
+        Vk::_2,
+        Vk::_3,
+        Vk::_4,
+        Vk::_5,
+        Vk::_6,
+        Vk::_7,
+        Vk::_8,
+        Vk::_9,
+        Vk::A,
+        Vk::B,
+        Vk::C,
+        Vk::D,
+        Vk::E,
+        Vk::F,
+        Vk::G,
+        Vk::H,
+        Vk::I,
+        Vk::J,
+        Vk::K,
+        Vk::L,
+        Vk::M,
+        Vk::N,
+        Vk::O,
+        Vk::P,
+        Vk::Q,
+        Vk::R,
+        Vk::S,
+        Vk::T,
+        Vk::U,
+        Vk::V,
+        Vk::W,
+        Vk::X,
+        Vk::Y,
+        Vk::Z,
+        Vk::LeftWin,
+        Vk::RightWin,
+        Vk::Apps,
+        Vk::Sleep,
+        Vk::Numpad0,
+        Vk::Numpad1,
+        Vk::Numpad2,
+        Vk::Numpad3,
+        Vk::Numpad4,
+        Vk::Numpad5,
+        Vk::Numpad6,
+        Vk::Numpad7,
+        Vk::Numpad8,
+        Vk::Numpad9,
+        Vk::Multiply,
+        Vk::Add,
+        Vk::Separator,
+        Vk::Subtract,
+        Vk::Decimal,
+        Vk::Divide,
+        Vk::F1,
+        Vk::F2,
+        Vk::F3,
+        Vk::F4,
+        Vk::F5,
+        Vk::F6,
+        Vk::F7,
+        Vk::F8,
+        Vk::F9,
+        Vk::F10,
+        Vk::F11,
+        Vk::F12,
+        Vk::F13,
+        Vk::F14,
+        Vk::F15,
+        Vk::F16,
+        Vk::F17,
+        Vk::F18,
+        Vk::F19,
+        Vk::F20,
+        Vk::F21,
+        Vk::F22,
+        Vk::F23,
+        Vk::F24,
+        Vk::NavigationView,
+        Vk::NavigationMenu,
+        Vk::NavigationUp,
+        Vk::NavigationDown,
+        Vk::NavigationLeft,
+        Vk::NavigationRight,
+        Vk::NavigationAccept,
+        Vk::NavigationCancel,
+        Vk::Numlock,
+        Vk::Scroll,
+        Vk::OemNecEqual,
+        Vk::OemFjMasshou,
+        Vk::OemFjTouroku,
+        Vk::OemFjLoya,
+        Vk::OemFjRoya,
+        Vk::LeftShift,
+        Vk::RightShift,
+        Vk::LeftControl,
+        Vk::RightControl,
+        Vk::LeftMenu,
+        Vk::RightMenu,
+        Vk::BrowserBack,
+        Vk::BrowserForward,
+        Vk::BrowserRefresh,
+        Vk::BrowserStop,
+        Vk::BrowserSearch,
+        Vk::BrowserFavorites,
+        Vk::BrowserHome,
+        Vk::VolumeMute,
+        Vk::VolumeDown,
+        Vk::VolumeUp,
+        Vk::NextTrack,
+        Vk::PrevTrack,
+        Vk::MediaStop,
+        Vk::MediaPlayPause,
+        Vk::StartMail,
+        Vk::SelectMedia,
+        Vk::StartApp1,
+        Vk::StartApp2,
+        Vk::Oem1,
+        Vk::Plus,
+        Vk::Comma,
+        Vk::Minus,
+        Vk::Period,
+        Vk::Oem2,
+        Vk::Oem3,
+        Vk::AbntC1,
+        Vk::AbntC2,
+        Vk::GamepadA,
+        Vk::GamepadB,
+        Vk::GamepadX,
+        Vk::GamepadY,
+        Vk::GamepadRightShoulder,
+        Vk::GamepadLeftShoulder,
+        Vk::GamepadLeftTrigger,
+        Vk::GamepadRightTrigger,
+        Vk::GamepadDPadUp,
+        Vk::GamepadDPadDown,
+        Vk::GamepadDPadLeft,
+        Vk::GamepadDPadRight,
+        Vk::GamepadMenu,
+        Vk::GamepadView,
+        Vk::GamepadLeftThumbstickButton,
+        Vk::GamepadRightThumbstickButton,
+        Vk::GamepadLeftThumbstickUp,
+        Vk::GamepadLeftThumbstickDown,
+        Vk::GamepadLeftThumbstickRight,
+        Vk::GamepadLeftThumbstickLeft,
+        Vk::GamepadRightThumbstickUp,
+        Vk::GamepadRightThumbstickDown,
+        Vk::GamepadRightThumbstickRight,
+        Vk::GamepadRightThumbstickLeft,
+        Vk::Oem4,
+        Vk::Oem5,
+        Vk::Oem6,
+        Vk::Oem7,
+        Vk::Oem8,
+        Vk::OemAx,
+        Vk::Oem102,
+        Vk::IcoHelp,
+        Vk::Ico00,
+        Vk::ImeProcess,
+        Vk::IcoClear,
+        Vk::Packet,
+        Vk::OemReset,
+        Vk::OemJump,
+        Vk::OemPa1,
+        Vk::OemPa2,
+        Vk::OemPa3,
+        Vk::OemWsCtrl,
+        Vk::OemCuSel,
+        Vk::OemAttn,
+        Vk::OemFinish,
+        Vk::OemCopy,
+        Vk::OemAuto,
+        Vk::OemEnlw,
+        Vk::OemBackTab,
+        Vk::Attn,
+        Vk::CrSel,
+        Vk::ExSel,
+        Vk::EraseEof,
+        Vk::Play,
+        Vk::Zoom,
+        Vk::NoName,
+        Vk::Pa1,
+        Vk::OemClear,
+    ];
+
+    /// Returns an iterator over every Virtual-Key Code, sorted by value.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Vk;
+    ///
+    /// for vk in Vk::all().filter(|vk| vk.is_function_key()) {
+    ///     println!("{}", vk.name());
+    /// }
+    /// ```
+    #[inline]
+    pub fn all() -> impl Iterator<Item = Vk> {
+        Self::ALL.iter().copied()
+    }
+
     /// Creates a Virtual-Key Code from the given `u8`.
     ///
     /// ## Safety
@@ -647,6 +1365,9 @@ impl Vk {
     /// This function is safe as long as the given number `n` is a valid Virtual-Key Code.
     /// Providing a invalid number is *undefined behaviour*.
     ///
+    /// The `TryFrom<u8>` implementation of [`Vk`] can be used to perform a checked
+    /// conversion instead.
+    ///
     /// ## Example
     ///
     /// ```rust
@@ -668,6 +1389,8 @@ impl Vk {
     /// let vk = unsafe { Vk::from_u8(n) };
     /// assert_eq!(vk, Vk::Escape);
     /// ```
+    ///
+    /// [`Vk`]: enum.Vk.html
     #[inline(always)]
     pub unsafe fn from_u8(n: u8) -> Self {
         // SAFETY: The caller must ensure that the given `u8` represents a valid
@@ -675,6 +1398,170 @@ impl Vk {
         std::mem::transmute(n)
     }
 
+    /// Checks whether the given number is a Virtual-Key Code known by [`Vk`].
+    ///
+    /// This function does not require any initialization and can be evaluated at
+    /// compile time.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Vk;
+    ///
+    /// const IS_ENTER_VALID: bool = Vk::is_valid(0x0d);
+    ///
+    /// assert!(IS_ENTER_VALID);
+    /// assert!(!Vk::is_valid(0x07));
+    /// ```
+    ///
+    /// [`Vk`]: enum.Vk.html
+    pub const fn is_valid(n: u8) -> bool {
+        matches!(
+            n,
+            0x01..=0x06
+                | 0x08..=0x09
+                | 0x0c..=0x0d
+                | 0x10..=0x39
+                | 0x41..=0x5d
+                | 0x5f..=0x96
+                | 0xa0..=0xb7
+                | 0xba..=0xdf
+                | 0xe1..=0xe7
+                | 0xe9..=0xfe
+        )
+    }
+
+    /// Creates a Virtual-Key Code from the given `u8`, returning `None` if it is not a
+    /// valid Virtual-Key Code.
+    pub(crate) fn from_u8_checked(n: u8) -> Option<Self> {
+        if Self::is_valid(n) {
+            // SAFETY: `n` is a valid Virtual-Key Code.
+            Some(unsafe { Self::from_u8(n) })
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves the Virtual-Key Code and the modifiers that must be pressed to type the
+    /// given character using the keyboard layout of the calling thread.
+    ///
+    /// `None` is returned if the character cannot be typed with a single keystroke on
+    /// the current layout.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Modifiers, Vk};
+    ///
+    /// // On a US keyboard layout.
+    /// assert_eq!(Vk::from_char('A'), Some((Vk::A, Modifiers::SHIFT)));
+    /// assert_eq!(Vk::from_char('1'), Some((Vk::_1, Modifiers::empty())));
+    /// ```
+    pub fn from_char(c: char) -> Option<(Self, Modifiers)> {
+        use winapi::um::winuser::VkKeyScanW;
+
+        let c = u16::try_from(c as u32).ok()?;
+
+        // Calling C code
+        Self::from_key_scan(unsafe { VkKeyScanW(c) })
+    }
+
+    /// Retrieves the Virtual-Key Code and the modifiers that must be pressed to type the
+    /// given character using the given keyboard layout.
+    ///
+    /// `None` is returned if the character cannot be typed with a single keystroke on
+    /// the given layout.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{KeyboardLayout, Vk};
+    ///
+    /// let layout = KeyboardLayout::foreground();
+    /// println!("{:?}", Vk::from_char_with_layout('@', layout));
+    /// ```
+    pub fn from_char_with_layout(
+        c: char,
+        layout: KeyboardLayout,
+    ) -> Option<(Self, Modifiers)> {
+        use winapi::um::winuser::VkKeyScanExW;
+
+        let c = u16::try_from(c as u32).ok()?;
+
+        // Calling C code
+        Self::from_key_scan(unsafe { VkKeyScanExW(c, layout.as_raw()) })
+    }
+
+    /// Translates this Virtual-Key Code into the character it produces when pressed
+    /// with the given modifiers, using the keyboard layout of the calling thread.
+    ///
+    /// If the key is a dead key, the character of the dead key itself is returned (for
+    /// example `'´'`). `None` is returned if the key does not produce exactly one
+    /// character.
+    ///
+    /// The keyboard state of the system is not modified by this function.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::{Modifiers, Vk};
+    ///
+    /// // On a US keyboard layout.
+    /// assert_eq!(Vk::A.to_char(Modifiers::empty()), Some('a'));
+    /// assert_eq!(Vk::_1.to_char(Modifiers::SHIFT), Some('!'));
+    /// ```
+    #[inline]
+    pub fn to_char(self, modifiers: Modifiers) -> Option<char> {
+        self.to_char_with_layout(modifiers, KeyboardLayout::current())
+    }
+
+    /// Translates this Virtual-Key Code into the character it produces when pressed
+    /// with the given modifiers, using the given keyboard layout.
+    ///
+    /// See [`Vk::to_char`] for more information.
+    ///
+    /// [`Vk::to_char`]: enum.Vk.html#method.to_char
+    pub fn to_char_with_layout(
+        self,
+        modifiers: Modifiers,
+        layout: KeyboardLayout,
+    ) -> Option<char> {
+        let (len, buffer) = layout.to_unicode(self, modifiers, false);
+
+        // A negative value is returned for dead keys. The character of the dead key is
+        // still written to the buffer.
+        let len = if len < 0 { 1 } else { len as usize };
+
+        let mut chars = std::char::decode_utf16(buffer[..len].iter().copied());
+        match (chars.next(), chars.next()) {
+            (Some(Ok(c)), None) => Some(c),
+            _ => None,
+        }
+    }
+
+    /// Decodes the value returned by the `VkKeyScan` family of functions.
+    fn from_key_scan(result: i16) -> Option<(Self, Modifiers)> {
+        if result == -1 {
+            return None;
+        }
+
+        let vk = Self::from_u8_checked(result as u8)?;
+        let state = (result >> 8) as u8;
+
+        let mut modifiers = Modifiers::empty();
+        if state & 0x01 != 0 {
+            modifiers |= Modifiers::SHIFT;
+        }
+        if state & 0x02 != 0 {
+            modifiers |= Modifiers::CONTROL;
+        }
+        if state & 0x04 != 0 {
+            modifiers |= Modifiers::ALT;
+        }
+
+        Some((vk, modifiers))
+    }
+
     /// Converts this Virtual-Key Code into a `u8`.
     ///
     /// ## Example
@@ -690,8 +1577,789 @@ impl Vk {
         self.into()
     }
 
+    /// Checks if this Virtual-Key Code is a modifier key (SHIFT, CTRL, ALT or one of the
+    /// Windows keys), sided or not.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Vk;
+    ///
+    /// assert!(Vk::LeftShift.is_modifier());
+    /// assert!(!Vk::A.is_modifier());
+    /// ```
+    #[inline]
+    pub const fn is_modifier(self) -> bool {
+        matches!(
+            self,
+            Vk::Shift
+                | Vk::Control
+                | Vk::Alt
+                | Vk::LeftShift
+                | Vk::RightShift
+                | Vk::LeftControl
+                | Vk::RightControl
+                | Vk::LeftMenu
+                | Vk::RightMenu
+                | Vk::LeftWin
+                | Vk::RightWin
+        )
+    }
+
+    /// Checks if this Virtual-Key Code is one of the function keys (`F1` to `F24`).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Vk;
+    ///
+    /// assert!(Vk::F5.is_function_key());
+    /// assert!(!Vk::_5.is_function_key());
+    /// ```
+    #[inline]
+    pub const fn is_function_key(self) -> bool {
+        matches!(self as u8, 0x70..=0x87)
+    }
+
+    /// Checks if this Virtual-Key Code is one of the keys of the numeric keypad (digits
+    /// and operators).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Vk;
+    ///
+    /// assert!(Vk::Numpad5.is_numpad());
+    /// assert!(Vk::Add.is_numpad());
+    /// assert!(!Vk::_5.is_numpad());
+    /// ```
+    #[inline]
+    pub const fn is_numpad(self) -> bool {
+        matches!(self as u8, 0x60..=0x6f)
+    }
+
+    /// Checks if this Virtual-Key Code is one of the keys found on multimedia
+    /// keyboards (browser, volume, media and application launch keys).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Vk;
+    ///
+    /// assert!(Vk::VolumeUp.is_media());
+    /// assert!(Vk::MediaPlayPause.is_media());
+    /// assert!(!Vk::Play.is_media());
+    /// ```
+    #[inline]
+    pub const fn is_media(self) -> bool {
+        matches!(self as u8, 0xa6..=0xb7)
+    }
+
+    /// Checks if this Virtual-Key Code is one of the gamepad keys.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Vk;
+    ///
+    /// assert!(Vk::GamepadA.is_gamepad());
+    /// assert!(!Vk::A.is_gamepad());
+    /// ```
+    #[inline]
+    pub const fn is_gamepad(self) -> bool {
+        matches!(self as u8, 0xc3..=0xda)
+    }
+
+    /// Checks if this Virtual-Key Code refers to a mouse button.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Vk;
+    ///
+    /// assert!(Vk::MouseLeft.is_mouse_button());
+    /// assert!(!Vk::Cancel.is_mouse_button());
+    /// ```
+    #[inline]
+    pub const fn is_mouse_button(self) -> bool {
+        matches!(
+            self,
+            Vk::MouseLeft | Vk::MouseRight | Vk::MouseMiddle | Vk::MouseX1 | Vk::MouseX2
+        )
+    }
+
+    /// Returns the [`KeyClass`] this Virtual-Key Code belongs to.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::{KeyClass, Vk};
+    ///
+    /// assert_eq!(Vk::A.class(), KeyClass::Letter);
+    /// assert_eq!(Vk::LeftShift.class(), KeyClass::Modifier);
+    /// assert_eq!(Vk::Home.class(), KeyClass::Navigation);
+    /// ```
+    ///
+    /// [`KeyClass`]: enum.KeyClass.html
+    pub fn class(self) -> KeyClass {
+        if self.is_mouse_button() {
+            return KeyClass::Mouse;
+        }
+        if self.is_modifier() {
+            return KeyClass::Modifier;
+        }
+        if self.is_function_key() {
+            return KeyClass::Function;
+        }
+        if self.is_numpad() {
+            return KeyClass::Numpad;
+        }
+        if self.is_media() {
+            return KeyClass::Media;
+        }
+        if self.is_gamepad() {
+            return KeyClass::Gamepad;
+        }
+
+        match self as u8 {
+            b'A'..=b'Z' => KeyClass::Letter,
+            b'0'..=b'9' => KeyClass::Digit,
+            // PAGE UP, PAGE DOWN, END, HOME and the arrows
+            0x21..=0x28 => KeyClass::Navigation,
+            // UI navigation keys
+            0x88..=0x8f => KeyClass::Navigation,
+            _ => match self {
+                Vk::Backspace
+                | Vk::Tab
+                | Vk::Clear
+                | Vk::Enter
+                | Vk::Space
+                | Vk::Insert
+                | Vk::Delete => KeyClass::Editing,
+                Vk::CapsLock | Vk::Numlock | Vk::Scroll => KeyClass::Lock,
+                Vk::Kana
+                | Vk::ImeOn
+                | Vk::Junja
+                | Vk::Final
+                | Vk::Kanji
+                | Vk::ImeOff
+                | Vk::Convert
+                | Vk::NonConvert
+                | Vk::Accept
+                | Vk::ModeChange
+                | Vk::ImeProcess => KeyClass::Ime,
+                Vk::Oem1
+                | Vk::Plus
+                | Vk::Comma
+                | Vk::Minus
+                | Vk::Period
+                | Vk::Oem2
+                | Vk::Oem3
+                | Vk::Oem4
+                | Vk::Oem5
+                | Vk::Oem6
+                | Vk::Oem7
+                | Vk::Oem8
+                | Vk::Oem102
+                | Vk::AbntC1
+                | Vk::AbntC2
+                | Vk::OemNecEqual
+                | Vk::OemFjMasshou
+                | Vk::OemFjTouroku
+                | Vk::OemFjLoya
+                | Vk::OemFjRoya
+                | Vk::OemAx
+                | Vk::IcoHelp
+                | Vk::Ico00
+                | Vk::IcoClear
+                | Vk::OemReset
+                | Vk::OemJump
+                | Vk::OemPa1
+                | Vk::OemPa2
+                | Vk::OemPa3
+                | Vk::OemWsCtrl
+                | Vk::OemCuSel
+                | Vk::OemAttn
+                | Vk::OemFinish
+                | Vk::OemCopy
+                | Vk::OemAuto
+                | Vk::OemEnlw
+                | Vk::OemBackTab
+                | Vk::OemClear => KeyClass::Oem,
+                _ => KeyClass::Other,
+            },
+        }
+    }
+
+    /// Converts a sided modifier key into its generic counterpart. For example,
+    /// `Vk::LeftShift` and `Vk::RightShift` are both converted into `Vk::Shift`.
+    ///
+    /// Other keys (including the Windows keys, which have no generic counterpart) are
+    /// returned unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Vk;
+    ///
+    /// assert_eq!(Vk::RightControl.to_generic(), Vk::Control);
+    /// assert_eq!(Vk::A.to_generic(), Vk::A);
+    /// ```
+    #[inline]
+    pub const fn to_generic(self) -> Self {
+        match self {
+            Vk::LeftShift | Vk::RightShift => Vk::Shift,
+            Vk::LeftControl | Vk::RightControl => Vk::Control,
+            Vk::LeftMenu | Vk::RightMenu => Vk::Alt,
+            _ => self,
+        }
+    }
+
+    /// Returns the left variant of this modifier key. For example, both `Vk::Shift` and
+    /// `Vk::RightShift` are converted into `Vk::LeftShift`.
+    ///
+    /// `None` is returned if this key is not a modifier.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Vk;
+    ///
+    /// assert_eq!(Vk::Alt.left_variant(), Some(Vk::LeftMenu));
+    /// assert_eq!(Vk::A.left_variant(), None);
+    /// ```
+    #[inline]
+    pub const fn left_variant(self) -> Option<Self> {
+        match self {
+            Vk::Shift | Vk::LeftShift | Vk::RightShift => Some(Vk::LeftShift),
+            Vk::Control | Vk::LeftControl | Vk::RightControl => Some(Vk::LeftControl),
+            Vk::Alt | Vk::LeftMenu | Vk::RightMenu => Some(Vk::LeftMenu),
+            Vk::LeftWin | Vk::RightWin => Some(Vk::LeftWin),
+            _ => None,
+        }
+    }
+
+    /// Returns the right variant of this modifier key. For example, both `Vk::Shift` and
+    /// `Vk::LeftShift` are converted into `Vk::RightShift`.
+    ///
+    /// `None` is returned if this key is not a modifier.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Vk;
+    ///
+    /// assert_eq!(Vk::Control.right_variant(), Some(Vk::RightControl));
+    /// assert_eq!(Vk::A.right_variant(), None);
+    /// ```
+    #[inline]
+    pub const fn right_variant(self) -> Option<Self> {
+        match self {
+            Vk::Shift | Vk::LeftShift | Vk::RightShift => Some(Vk::RightShift),
+            Vk::Control | Vk::LeftControl | Vk::RightControl => Some(Vk::RightControl),
+            Vk::Alt | Vk::LeftMenu | Vk::RightMenu => Some(Vk::RightMenu),
+            Vk::LeftWin | Vk::RightWin => Some(Vk::RightWin),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of this Virtual-Key Code.
+    ///
+    /// The returned name is the name of the variant, except for the digit keys whose
+    /// name is the digit itself. This name can be parsed back into a [`Vk`] using the
+    /// `FromStr` implementation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Vk;
+    ///
+    /// assert_eq!(Vk::LeftControl.name(), "LeftControl");
+    /// assert_eq!(Vk::_5.name(), "5");
+    /// assert_eq!("F5".parse::<Vk>(), Ok(Vk::F5));
+    /// ```
+    ///
+    /// [`Vk`]: enum.Vk.html
+    pub fn name(self) -> &'static str {
+        match self {
+            Vk::MouseLeft => "MouseLeft",
+            Vk::MouseRight => "MouseRight",
+            Vk::Cancel => "Cancel",
+            Vk::MouseMiddle => "MouseMiddle",
+            Vk::MouseX1 => "MouseX1",
+            Vk::MouseX2 => "MouseX2",
+            Vk::Backspace => "Backspace",
+            Vk::Tab => "Tab",
+            Vk::Clear => "Clear",
+            Vk::Enter => "Enter",
+            Vk::Shift => "Shift",
+            Vk::Control => "Control",
+            Vk::Alt => "Alt",
+            Vk::Pause => "Pause",
+            Vk::CapsLock => "CapsLock",
+            Vk::Kana => "Kana",
+            Vk::ImeOn => "ImeOn",
+            Vk::Junja => "Junja",
+            Vk::Final => "Final",
+            Vk::Kanji => "Kanji",
+            Vk::ImeOff => "ImeOff",
+            Vk::Escape => "Escape",
+            Vk::Convert => "Convert",
+            Vk::NonConvert => "NonConvert",
+            Vk::Accept => "Accept",
+            Vk::ModeChange => "ModeChange",
+            Vk::Space => "Space",
+            Vk::PageUp => "PageUp",
+            Vk::PageDown => "PageDown",
+            Vk::End => "End",
+            Vk::Home => "Home",
+            Vk::LeftArrow => "LeftArrow",
+            Vk::UpArrow => "UpArrow",
+            Vk::RightArrow => "RightArrow",
+            Vk::DownArrow => "DownArrow",
+            Vk::Select => "Select",
+            Vk::Print => "Print",
+            Vk::Execute => "Execute",
+            Vk::PrintScreen => "PrintScreen",
+            Vk::Insert => "Insert",
+            Vk::Delete => "Delete",
+            Vk::Help => "Help",
+            Vk::_0 => "0",
+            Vk::_1 => "1",
+            Vk::_2 => "2",
+            Vk::_3 => "3",
+            Vk::_4 => "4",
+            Vk::_5 => "5",
+            Vk::_6 => "6",
+            Vk::_7 => "7",
+            Vk::_8 => "8",
+            Vk::_9 => "9",
+            Vk::A => "A",
+            Vk::B => "B",
+            Vk::C => "C",
+            Vk::D => "D",
+            Vk::E => "E",
+            Vk::F => "F",
+            Vk::G => "G",
+            Vk::H => "H",
+            Vk::I => "I",
+            Vk::J => "J",
+            Vk::K => "K",
+            Vk::L => "L",
+            Vk::M => "M",
+            Vk::N => "N",
+            Vk::O => "O",
+            Vk::P => "P",
+            Vk::Q => "Q",
+            Vk::R => "R",
+            Vk::S => "S",
+            Vk::T => "T",
+            Vk::U => "U",
+            Vk::V => "V",
+            Vk::W => "W",
+            Vk::X => "X",
+            Vk::Y => "Y",
+            Vk::Z => "Z",
+            Vk::LeftWin => "LeftWin",
+            Vk::RightWin => "RightWin",
+            Vk::Apps => "Apps",
+            Vk::Sleep => "Sleep",
+            Vk::Numpad0 => "Numpad0",
+            Vk::Numpad1 => "Numpad1",
+            Vk::Numpad2 => "Numpad2",
+            Vk::Numpad3 => "Numpad3",
+            Vk::Numpad4 => "Numpad4",
+            Vk::Numpad5 => "Numpad5",
+            Vk::Numpad6 => "Numpad6",
+            Vk::Numpad7 => "Numpad7",
+            Vk::Numpad8 => "Numpad8",
+            Vk::Numpad9 => "Numpad9",
+            Vk::Multiply => "Multiply",
+            Vk::Add => "Add",
+            Vk::Separator => "Separator",
+            Vk::Subtract => "Subtract",
+            Vk::Decimal => "Decimal",
+            Vk::Divide => "Divide",
+            Vk::F1 => "F1",
+            Vk::F2 => "F2",
+            Vk::F3 => "F3",
+            Vk::F4 => "F4",
+            Vk::F5 => "F5",
+            Vk::F6 => "F6",
+            Vk::F7 => "F7",
+            Vk::F8 => "F8",
+            Vk::F9 => "F9",
+            Vk::F10 => "F10",
+            Vk::F11 => "F11",
+            Vk::F12 => "F12",
+            Vk::F13 => "F13",
+            Vk::F14 => "F14",
+            Vk::F15 => "F15",
+            Vk::F16 => "F16",
+            Vk::F17 => "F17",
+            Vk::F18 => "F18",
+            Vk::F19 => "F19",
+            Vk::F20 => "F20",
+            Vk::F21 => "F21",
+            Vk::F22 => "F22",
+            Vk::F23 => "F23",
+            Vk::F24 => "F24",
+            Vk::NavigationView => "NavigationView",
+            Vk::NavigationMenu => "NavigationMenu",
+            Vk::NavigationUp => "NavigationUp",
+            Vk::NavigationDown => "NavigationDown",
+            Vk::NavigationLeft => "NavigationLeft",
+            Vk::NavigationRight => "NavigationRight",
+            Vk::NavigationAccept => "NavigationAccept",
+            Vk::NavigationCancel => "NavigationCancel",
+            Vk::Numlock => "Numlock",
+            Vk::Scroll => "Scroll",
+            Vk::OemNecEqual => "OemNecEqual",
+            Vk::OemFjMasshou => "OemFjMasshou",
+            Vk::OemFjTouroku => "OemFjTouroku",
+            Vk::OemFjLoya => "OemFjLoya",
+            Vk::OemFjRoya => "OemFjRoya",
+            Vk::LeftShift => "LeftShift",
+            Vk::RightShift => "RightShift",
+            Vk::LeftControl => "LeftControl",
+            Vk::RightControl => "RightControl",
+            Vk::LeftMenu => "LeftMenu",
+            Vk::RightMenu => "RightMenu",
+            Vk::BrowserBack => "BrowserBack",
+            Vk::BrowserForward => "BrowserForward",
+            Vk::BrowserRefresh => "BrowserRefresh",
+            Vk::BrowserStop => "BrowserStop",
+            Vk::BrowserSearch => "BrowserSearch",
+            Vk::BrowserFavorites => "BrowserFavorites",
+            Vk::BrowserHome => "BrowserHome",
+            Vk::VolumeMute => "VolumeMute",
+            Vk::VolumeDown => "VolumeDown",
+            Vk::VolumeUp => "VolumeUp",
+            Vk::NextTrack => "NextTrack",
+            Vk::PrevTrack => "PrevTrack",
+            Vk::MediaStop => "MediaStop",
+            Vk::MediaPlayPause => "MediaPlayPause",
+            Vk::StartMail => "StartMail",
+            Vk::SelectMedia => "SelectMedia",
+            Vk::StartApp1 => "StartApp1",
+            Vk::StartApp2 => "StartApp2",
+            Vk::Oem1 => "Oem1",
+            Vk::Plus => "Plus",
+            Vk::Comma => "Comma",
+            Vk::Minus => "Minus",
+            Vk::Period => "Period",
+            Vk::Oem2 => "Oem2",
+            Vk::Oem3 => "Oem3",
+            Vk::AbntC1 => "AbntC1",
+            Vk::AbntC2 => "AbntC2",
+            Vk::GamepadA => "GamepadA",
+            Vk::GamepadB => "GamepadB",
+            Vk::GamepadX => "GamepadX",
+            Vk::GamepadY => "GamepadY",
+            Vk::GamepadRightShoulder => "GamepadRightShoulder",
+            Vk::GamepadLeftShoulder => "GamepadLeftShoulder",
+            Vk::GamepadLeftTrigger => "GamepadLeftTrigger",
+            Vk::GamepadRightTrigger => "GamepadRightTrigger",
+            Vk::GamepadDPadUp => "GamepadDPadUp",
+            Vk::GamepadDPadDown => "GamepadDPadDown",
+            Vk::GamepadDPadLeft => "GamepadDPadLeft",
+            Vk::GamepadDPadRight => "GamepadDPadRight",
+            Vk::GamepadMenu => "GamepadMenu",
+            Vk::GamepadView => "GamepadView",
+            Vk::GamepadLeftThumbstickButton => "GamepadLeftThumbstickButton",
+            Vk::GamepadRightThumbstickButton => "GamepadRightThumbstickButton",
+            Vk::GamepadLeftThumbstickUp => "GamepadLeftThumbstickUp",
+            Vk::GamepadLeftThumbstickDown => "GamepadLeftThumbstickDown",
+            Vk::GamepadLeftThumbstickRight => "GamepadLeftThumbstickRight",
+            Vk::GamepadLeftThumbstickLeft => "GamepadLeftThumbstickLeft",
+            Vk::GamepadRightThumbstickUp => "GamepadRightThumbstickUp",
+            Vk::GamepadRightThumbstickDown => "GamepadRightThumbstickDown",
+            Vk::GamepadRightThumbstickRight => "GamepadRightThumbstickRight",
+            Vk::GamepadRightThumbstickLeft => "GamepadRightThumbstickLeft",
+            Vk::Oem4 => "Oem4",
+            Vk::Oem5 => "Oem5",
+            Vk::Oem6 => "Oem6",
+            Vk::Oem7 => "Oem7",
+            Vk::Oem8 => "Oem8",
+            Vk::OemAx => "OemAx",
+            Vk::Oem102 => "Oem102",
+            Vk::IcoHelp => "IcoHelp",
+            Vk::Ico00 => "Ico00",
+            Vk::ImeProcess => "ImeProcess",
+            Vk::IcoClear => "IcoClear",
+            Vk::Packet => "Packet",
+            Vk::OemReset => "OemReset",
+            Vk::OemJump => "OemJump",
+            Vk::OemPa1 => "OemPa1",
+            Vk::OemPa2 => "OemPa2",
+            Vk::OemPa3 => "OemPa3",
+            Vk::OemWsCtrl => "OemWsCtrl",
+            Vk::OemCuSel => "OemCuSel",
+            Vk::OemAttn => "OemAttn",
+            Vk::OemFinish => "OemFinish",
+            Vk::OemCopy => "OemCopy",
+            Vk::OemAuto => "OemAuto",
+            Vk::OemEnlw => "OemEnlw",
+            Vk::OemBackTab => "OemBackTab",
+            Vk::Attn => "Attn",
+            Vk::CrSel => "CrSel",
+            Vk::ExSel => "ExSel",
+            Vk::EraseEof => "EraseEof",
+            Vk::Play => "Play",
+            Vk::Zoom => "Zoom",
+            Vk::NoName => "NoName",
+            Vk::Pa1 => "Pa1",
+            Vk::OemClear => "OemClear",
+        }
+    }
+
+    /// Retrieves the name of this key as printed on the user's keyboard, according to
+    /// the current keyboard layout. For example, `Vk::Delete` is named "Entf" on German
+    /// layouts.
+    ///
+    /// `None` is returned if the key cannot be mapped to a scan code or has no name in
+    /// the current layout.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Vk;
+    ///
+    /// println!("Press {} to quit", Vk::Escape.localized_name().unwrap());
+    /// ```
+    pub fn localized_name(self) -> Option<String> {
+        use winapi::um::winuser::{MapVirtualKeyW, MAPVK_VK_TO_VSC};
+
+        // Calling C code
+        let scan_code = unsafe { MapVirtualKeyW(self.into(), MAPVK_VK_TO_VSC) };
+
+        if scan_code == 0 {
+            None
+        } else {
+            Self::scan_code_name(scan_code as u16, self.is_extended())
+        }
+    }
+
+    /// Retrieves the name of the key associated with the given scan code, according to
+    /// the current keyboard layout. `extended` indicates whether the scan code is
+    /// prefixed by `E0`.
+    ///
+    /// `None` is returned if the key has no name in the current layout.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Vk;
+    ///
+    /// // The scan code of the `Enter` key of the numeric keypad.
+    /// println!("{:?}", Vk::scan_code_name(0x1c, true));
+    /// ```
+    pub fn scan_code_name(scan_code: u16, extended: bool) -> Option<String> {
+        use winapi::um::winuser::GetKeyNameTextW;
+
+        let mut l_param = (scan_code as i32 & 0xff) << 16;
+        if extended {
+            l_param |= 1 << 24;
+        }
+
+        let mut buffer = [0u16; 64];
+
+        // Calling C code
+        //
+        // The function returns the number of `u16` characters that were written.
+        let len =
+            unsafe { GetKeyNameTextW(l_param, buffer.as_mut_ptr(), buffer.len() as _) };
+
+        if len <= 0 {
+            None
+        } else {
+            String::from_utf16(&buffer[..len as usize]).ok()
+        }
+    }
+
+    /// Converts this Virtual-Key Code into a scan code, using the keyboard layout of the
+    /// calling thread.
+    ///
+    /// Extended scan codes are returned with their prefix in the high byte: for example
+    /// `Vk::RightControl` is converted into `0xe01d`. `None` is returned if the key
+    /// has no scan code in the current layout.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Vk;
+    ///
+    /// assert_eq!(Vk::Escape.to_scan_code(), Some(0x01));
+    /// assert_eq!(Vk::RightControl.to_scan_code(), Some(0xe01d));
+    /// ```
+    #[inline]
+    pub fn to_scan_code(self) -> Option<u16> {
+        self.to_scan_code_with_layout(KeyboardLayout::current())
+    }
+
+    /// Converts this Virtual-Key Code into a scan code, using the given keyboard layout.
+    ///
+    /// See [`Vk::to_scan_code`] for more information.
+    ///
+    /// [`Vk::to_scan_code`]: enum.Vk.html#method.to_scan_code
+    pub fn to_scan_code_with_layout(self, layout: KeyboardLayout) -> Option<u16> {
+        use winapi::um::winuser::{MapVirtualKeyExW, MAPVK_VK_TO_VSC_EX};
+
+        // Calling C code
+        let scan_code =
+            unsafe { MapVirtualKeyExW(self.into(), MAPVK_VK_TO_VSC_EX, layout.as_raw()) };
+
+        if scan_code == 0 {
+            None
+        } else {
+            Some(scan_code as u16)
+        }
+    }
+
+    /// Converts a scan code into a Virtual-Key Code, using the keyboard layout of the
+    /// calling thread.
+    ///
+    /// Extended scan codes must have their prefix in the high byte (`0xe0` or `0xe1`).
+    /// Sided modifier keys are resolved: `0x2a` is converted into `Vk::LeftShift` and
+    /// `0x36` into `Vk::RightShift`. `None` is returned if the scan code is not mapped
+    /// to any known Virtual-Key Code.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Vk;
+    ///
+    /// assert_eq!(Vk::from_scan_code(0x01), Some(Vk::Escape));
+    /// assert_eq!(Vk::from_scan_code(0xe01d), Some(Vk::RightControl));
+    /// ```
+    #[inline]
+    pub fn from_scan_code(scan_code: u16) -> Option<Self> {
+        Self::from_scan_code_with_layout(scan_code, KeyboardLayout::current())
+    }
+
+    /// Converts a scan code into a Virtual-Key Code, using the given keyboard layout.
+    ///
+    /// See [`Vk::from_scan_code`] for more information.
+    ///
+    /// [`Vk::from_scan_code`]: enum.Vk.html#method.from_scan_code
+    pub fn from_scan_code_with_layout(
+        scan_code: u16,
+        layout: KeyboardLayout,
+    ) -> Option<Self> {
+        use winapi::um::winuser::{MapVirtualKeyExW, MAPVK_VSC_TO_VK_EX};
+
+        // Calling C code
+        let vk = unsafe {
+            MapVirtualKeyExW(scan_code as u32, MAPVK_VSC_TO_VK_EX, layout.as_raw())
+        };
+
+        Self::try_from(vk).ok()
+    }
+
+    /// Checks whether the scan code of this key is prefixed by `E0`.
+    pub(crate) fn is_extended(self) -> bool {
+        matches!(
+            self,
+            Vk::Cancel
+                | Vk::PageUp
+                | Vk::PageDown
+                | Vk::End
+                | Vk::Home
+                | Vk::LeftArrow
+                | Vk::UpArrow
+                | Vk::RightArrow
+                | Vk::DownArrow
+                | Vk::PrintScreen
+                | Vk::Insert
+                | Vk::Delete
+                | Vk::LeftWin
+                | Vk::RightWin
+                | Vk::Apps
+                | Vk::Sleep
+                | Vk::Divide
+                | Vk::Numlock
+                | Vk::RightControl
+                | Vk::RightMenu
+                | Vk::BrowserBack
+                | Vk::BrowserForward
+                | Vk::BrowserRefresh
+                | Vk::BrowserStop
+                | Vk::BrowserSearch
+                | Vk::BrowserFavorites
+                | Vk::BrowserHome
+                | Vk::VolumeMute
+                | Vk::VolumeDown
+                | Vk::VolumeUp
+                | Vk::NextTrack
+                | Vk::PrevTrack
+                | Vk::MediaStop
+                | Vk::MediaPlayPause
+                | Vk::StartMail
+                | Vk::SelectMedia
+                | Vk::StartApp1
+                | Vk::StartApp2
+        )
+    }
+
+    /// Converts this Virtual-Key Code into the matching DirectInput keyboard constant
+    /// (`DIK_*`).
+    ///
+    /// DirectInput constants describe the position of a key on a US keyboard, not the
+    /// character it produces, and do not depend on the active keyboard layout. Generic
+    /// modifier keys are converted into their left variant. `None` is returned if the
+    /// key has no DirectInput constant.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Vk;
+    ///
+    /// assert_eq!(Vk::Escape.to_dik(), Some(0x01)); // DIK_ESCAPE
+    /// assert_eq!(Vk::Shift.to_dik(), Some(0x2a)); // DIK_LSHIFT
+    /// assert_eq!(Vk::UpArrow.to_dik(), Some(0xc8)); // DIK_UP
+    /// ```
+    pub fn to_dik(self) -> Option<u8> {
+        let vk = match self {
+            Vk::Shift | Vk::Control | Vk::Alt => self.left_variant()?,
+            _ => self,
+        };
+
+        DIK_CODES
+            .iter()
+            .find(|&&(v, _)| v == vk)
+            .map(|&(_, dik)| dik)
+    }
+
+    /// Converts a DirectInput keyboard constant (`DIK_*`) into the matching Virtual-Key
+    /// Code.
+    ///
+    /// See [`Vk::to_dik`] for more information. `None` is returned if the constant does
+    /// not match any Virtual-Key Code.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use winput::Vk;
+    ///
+    /// assert_eq!(Vk::from_dik(0x1e), Some(Vk::A)); // DIK_A
+    /// assert_eq!(Vk::from_dik(0x9d), Some(Vk::RightControl)); // DIK_RCONTROL
+    /// ```
+    ///
+    /// [`Vk::to_dik`]: enum.Vk.html#method.to_dik
+    pub fn from_dik(dik: u8) -> Option<Self> {
+        DIK_CODES
+            .iter()
+            .find(|&&(_, d)| d == dik)
+            .map(|&(vk, _)| vk)
+    }
+
     /// Checks if this Virtual-Key Code is currently being pressed.
     ///
+    /// This function uses the native `GetAsyncKeyState` function: the physical state of
+    /// the key is returned, regardless of the messages processed by the calling thread.
+    /// See [`Vk::is_down_sync`] to retrieve the state of the key as seen by the
+    /// calling thread.
+    ///
     /// ## Example
     ///
     /// ```rust, ignore
@@ -703,6 +2371,8 @@ impl Vk {
     ///     println!("The Z key is not down :(");
     /// }
     /// ```
+    ///
+    /// [`Vk::is_down_sync`]: enum.Vk.html#method.is_down_sync
     pub fn is_down(self) -> bool {
         use winapi::um::winuser::GetAsyncKeyState;
 
@@ -713,6 +2383,34 @@ impl Vk {
         state & MASK == MASK
     }
 
+    /// Checks if this Virtual-Key Code was being pressed when the calling thread
+    /// received the last input message it processed.
+    ///
+    /// This function uses the native `GetKeyState` function. Unlike [`Vk::is_down`], the
+    /// returned state is synchronized with the message queue of the calling thread,
+    /// which is what window procedures usually want when handling input messages.
+    ///
+    /// ## Example
+    ///
+    /// ```rust, ignore
+    /// use winput::Vk;
+    ///
+    /// if Vk::Shift.is_down_sync() {
+    ///     println!("This message was received while SHIFT was down");
+    /// }
+    /// ```
+    ///
+    /// [`Vk::is_down`]: enum.Vk.html#method.is_down
+    pub fn is_down_sync(self) -> bool {
+        use winapi::um::winuser::GetKeyState;
+
+        const MASK: u16 = 0x8000;
+
+        // Calling C code
+        let state = unsafe { GetKeyState(self.into()) } as u16;
+        state & MASK == MASK
+    }
+
     /// Checks if the given key is currently toggled.
     ///
     /// For example, the `Vk::CapsLock` can be either on or off (appart from being
@@ -739,3 +2437,355 @@ impl Vk {
         state & MASK == MASK
     }
 }
+
+impl fmt::Display for Vk {
+    /// Formats this Virtual-Key Code using a short, human-readable name (for example
+    /// "Ctrl" or "PageUp"). The result can be parsed back using the `FromStr`
+    /// implementation of [`Vk`].
+    ///
+    /// [`Vk`]: enum.Vk.html
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Vk::Cancel => "Break",
+            Vk::Escape => "Esc",
+            Vk::Control => "Ctrl",
+            Vk::LeftControl => "LCtrl",
+            Vk::RightControl => "RCtrl",
+            Vk::LeftShift => "LShift",
+            Vk::RightShift => "RShift",
+            Vk::LeftMenu => "LAlt",
+            Vk::RightMenu => "RAlt",
+            Vk::LeftWin => "LWin",
+            Vk::RightWin => "RWin",
+            Vk::Apps => "Menu",
+            Vk::Insert => "Ins",
+            Vk::Delete => "Del",
+            Vk::PrintScreen => "PrtSc",
+            Vk::Numlock => "NumLock",
+            Vk::Scroll => "ScrollLock",
+            Vk::LeftArrow => "Left",
+            Vk::UpArrow => "Up",
+            Vk::RightArrow => "Right",
+            Vk::DownArrow => "Down",
+            Vk::Numpad0 => "Num0",
+            Vk::Numpad1 => "Num1",
+            Vk::Numpad2 => "Num2",
+            Vk::Numpad3 => "Num3",
+            Vk::Numpad4 => "Num4",
+            Vk::Numpad5 => "Num5",
+            Vk::Numpad6 => "Num6",
+            Vk::Numpad7 => "Num7",
+            Vk::Numpad8 => "Num8",
+            Vk::Numpad9 => "Num9",
+            Vk::Multiply => "Num*",
+            Vk::Add => "Num+",
+            Vk::Subtract => "Num-",
+            Vk::Decimal => "Num.",
+            Vk::Divide => "Num/",
+            _ => self.name(),
+        };
+
+        f.pad(name)
+    }
+}
+
+/// An error that can be produced when parsing a [`Vk`] from a string.
+///
+/// [`Vk`]: enum.Vk.html
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseVkError {
+    name: String,
+}
+
+impl fmt::Display for ParseVkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown Virtual-Key Code name: \"{}\"", self.name)
+    }
+}
+
+impl Error for ParseVkError {}
+
+impl FromStr for Vk {
+    type Err = ParseVkError;
+
+    /// Parses a Virtual-Key Code from its name, as returned by [`Vk::name`], or from its
+    /// short name, as produced by its `Display` implementation. The comparison is
+    /// case-insensitive.
+    ///
+    /// [`Vk::name`]: enum.Vk.html#method.name
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let vk = match s.to_ascii_lowercase().as_str() {
+            "mouseleft" => Vk::MouseLeft,
+            "mouseright" => Vk::MouseRight,
+            "cancel" => Vk::Cancel,
+            "mousemiddle" => Vk::MouseMiddle,
+            "mousex1" => Vk::MouseX1,
+            "mousex2" => Vk::MouseX2,
+            "backspace" => Vk::Backspace,
+            "tab" => Vk::Tab,
+            "clear" => Vk::Clear,
+            "enter" => Vk::Enter,
+            "shift" => Vk::Shift,
+            "control" => Vk::Control,
+            "alt" => Vk::Alt,
+            "pause" => Vk::Pause,
+            "capslock" => Vk::CapsLock,
+            "kana" => Vk::Kana,
+            "imeon" => Vk::ImeOn,
+            "junja" => Vk::Junja,
+            "final" => Vk::Final,
+            "kanji" => Vk::Kanji,
+            "imeoff" => Vk::ImeOff,
+            "escape" => Vk::Escape,
+            "convert" => Vk::Convert,
+            "nonconvert" => Vk::NonConvert,
+            "accept" => Vk::Accept,
+            "modechange" => Vk::ModeChange,
+            "space" => Vk::Space,
+            "pageup" => Vk::PageUp,
+            "pagedown" => Vk::PageDown,
+            "end" => Vk::End,
+            "home" => Vk::Home,
+            "leftarrow" => Vk::LeftArrow,
+            "uparrow" => Vk::UpArrow,
+            "rightarrow" => Vk::RightArrow,
+            "downarrow" => Vk::DownArrow,
+            "select" => Vk::Select,
+            "print" => Vk::Print,
+            "execute" => Vk::Execute,
+            "printscreen" => Vk::PrintScreen,
+            "insert" => Vk::Insert,
+            "delete" => Vk::Delete,
+            "help" => Vk::Help,
+            "0" => Vk::_0,
+            "1" => Vk::_1,
+            "2" => Vk::_2,
+            "3" => Vk::_3,
+            "4" => Vk::_4,
+            "5" => Vk::_5,
+            "6" => Vk::_6,
+            "7" => Vk::_7,
+            "8" => Vk::_8,
+            "9" => Vk::_9,
+            "a" => Vk::A,
+            "b" => Vk::B,
+            "c" => Vk::C,
+            "d" => Vk::D,
+            "e" => Vk::E,
+            "f" => Vk::F,
+            "g" => Vk::G,
+            "h" => Vk::H,
+            "i" => Vk::I,
+            "j" => Vk::J,
+            "k" => Vk::K,
+            "l" => Vk::L,
+            "m" => Vk::M,
+            "n" => Vk::N,
+            "o" => Vk::O,
+            "p" => Vk::P,
+            "q" => Vk::Q,
+            "r" => Vk::R,
+            "s" => Vk::S,
+            "t" => Vk::T,
+            "u" => Vk::U,
+            "v" => Vk::V,
+            "w" => Vk::W,
+            "x" => Vk::X,
+            "y" => Vk::Y,
+            "z" => Vk::Z,
+            "leftwin" => Vk::LeftWin,
+            "rightwin" => Vk::RightWin,
+            "apps" => Vk::Apps,
+            "sleep" => Vk::Sleep,
+            "numpad0" => Vk::Numpad0,
+            "numpad1" => Vk::Numpad1,
+            "numpad2" => Vk::Numpad2,
+            "numpad3" => Vk::Numpad3,
+            "numpad4" => Vk::Numpad4,
+            "numpad5" => Vk::Numpad5,
+            "numpad6" => Vk::Numpad6,
+            "numpad7" => Vk::Numpad7,
+            "numpad8" => Vk::Numpad8,
+            "numpad9" => Vk::Numpad9,
+            "multiply" => Vk::Multiply,
+            "add" => Vk::Add,
+            "separator" => Vk::Separator,
+            "subtract" => Vk::Subtract,
+            "decimal" => Vk::Decimal,
+            "divide" => Vk::Divide,
+            "f1" => Vk::F1,
+            "f2" => Vk::F2,
+            "f3" => Vk::F3,
+            "f4" => Vk::F4,
+            "f5" => Vk::F5,
+            "f6" => Vk::F6,
+            "f7" => Vk::F7,
+            "f8" => Vk::F8,
+            "f9" => Vk::F9,
+            "f10" => Vk::F10,
+            "f11" => Vk::F11,
+            "f12" => Vk::F12,
+            "f13" => Vk::F13,
+            "f14" => Vk::F14,
+            "f15" => Vk::F15,
+            "f16" => Vk::F16,
+            "f17" => Vk::F17,
+            "f18" => Vk::F18,
+            "f19" => Vk::F19,
+            "f20" => Vk::F20,
+            "f21" => Vk::F21,
+            "f22" => Vk::F22,
+            "f23" => Vk::F23,
+            "f24" => Vk::F24,
+            "navigationview" => Vk::NavigationView,
+            "navigationmenu" => Vk::NavigationMenu,
+            "navigationup" => Vk::NavigationUp,
+            "navigationdown" => Vk::NavigationDown,
+            "navigationleft" => Vk::NavigationLeft,
+            "navigationright" => Vk::NavigationRight,
+            "navigationaccept" => Vk::NavigationAccept,
+            "navigationcancel" => Vk::NavigationCancel,
+            "numlock" => Vk::Numlock,
+            "scroll" => Vk::Scroll,
+            "oemnecequal" => Vk::OemNecEqual,
+            "oemfjmasshou" => Vk::OemFjMasshou,
+            "oemfjtouroku" => Vk::OemFjTouroku,
+            "oemfjloya" => Vk::OemFjLoya,
+            "oemfjroya" => Vk::OemFjRoya,
+            "leftshift" => Vk::LeftShift,
+            "rightshift" => Vk::RightShift,
+            "leftcontrol" => Vk::LeftControl,
+            "rightcontrol" => Vk::RightControl,
+            "leftmenu" => Vk::LeftMenu,
+            "rightmenu" => Vk::RightMenu,
+            "browserback" => Vk::BrowserBack,
+            "browserforward" => Vk::BrowserForward,
+            "browserrefresh" => Vk::BrowserRefresh,
+            "browserstop" => Vk::BrowserStop,
+            "browsersearch" => Vk::BrowserSearch,
+            "browserfavorites" => Vk::BrowserFavorites,
+            "browserhome" => Vk::BrowserHome,
+            "volumemute" => Vk::VolumeMute,
+            "volumedown" => Vk::VolumeDown,
+            "volumeup" => Vk::VolumeUp,
+            "nexttrack" => Vk::NextTrack,
+            "prevtrack" => Vk::PrevTrack,
+            "mediastop" => Vk::MediaStop,
+            "mediaplaypause" => Vk::MediaPlayPause,
+            "startmail" => Vk::StartMail,
+            "selectmedia" => Vk::SelectMedia,
+            "startapp1" => Vk::StartApp1,
+            "startapp2" => Vk::StartApp2,
+            "oem1" => Vk::Oem1,
+            "plus" => Vk::Plus,
+            "comma" => Vk::Comma,
+            "minus" => Vk::Minus,
+            "period" => Vk::Period,
+            "oem2" => Vk::Oem2,
+            "oem3" => Vk::Oem3,
+            "abntc1" => Vk::AbntC1,
+            "abntc2" => Vk::AbntC2,
+            "gamepada" => Vk::GamepadA,
+            "gamepadb" => Vk::GamepadB,
+            "gamepadx" => Vk::GamepadX,
+            "gamepady" => Vk::GamepadY,
+            "gamepadrightshoulder" => Vk::GamepadRightShoulder,
+            "gamepadleftshoulder" => Vk::GamepadLeftShoulder,
+            "gamepadlefttrigger" => Vk::GamepadLeftTrigger,
+            "gamepadrighttrigger" => Vk::GamepadRightTrigger,
+            "gamepaddpadup" => Vk::GamepadDPadUp,
+            "gamepaddpaddown" => Vk::GamepadDPadDown,
+            "gamepaddpadleft" => Vk::GamepadDPadLeft,
+            "gamepaddpadright" => Vk::GamepadDPadRight,
+            "gamepadmenu" => Vk::GamepadMenu,
+            "gamepadview" => Vk::GamepadView,
+            "gamepadleftthumbstickbutton" => Vk::GamepadLeftThumbstickButton,
+            "gamepadrightthumbstickbutton" => Vk::GamepadRightThumbstickButton,
+            "gamepadleftthumbstickup" => Vk::GamepadLeftThumbstickUp,
+            "gamepadleftthumbstickdown" => Vk::GamepadLeftThumbstickDown,
+            "gamepadleftthumbstickright" => Vk::GamepadLeftThumbstickRight,
+            "gamepadleftthumbstickleft" => Vk::GamepadLeftThumbstickLeft,
+            "gamepadrightthumbstickup" => Vk::GamepadRightThumbstickUp,
+            "gamepadrightthumbstickdown" => Vk::GamepadRightThumbstickDown,
+            "gamepadrightthumbstickright" => Vk::GamepadRightThumbstickRight,
+            "gamepadrightthumbstickleft" => Vk::GamepadRightThumbstickLeft,
+            "oem4" => Vk::Oem4,
+            "oem5" => Vk::Oem5,
+            "oem6" => Vk::Oem6,
+            "oem7" => Vk::Oem7,
+            "oem8" => Vk::Oem8,
+            "oemax" => Vk::OemAx,
+            "oem102" => Vk::Oem102,
+            "icohelp" => Vk::IcoHelp,
+            "ico00" => Vk::Ico00,
+            "imeprocess" => Vk::ImeProcess,
+            "icoclear" => Vk::IcoClear,
+            "packet" => Vk::Packet,
+            "oemreset" => Vk::OemReset,
+            "oemjump" => Vk::OemJump,
+            "oempa1" => Vk::OemPa1,
+            "oempa2" => Vk::OemPa2,
+            "oempa3" => Vk::OemPa3,
+            "oemwsctrl" => Vk::OemWsCtrl,
+            "oemcusel" => Vk::OemCuSel,
+            "oemattn" => Vk::OemAttn,
+            "oemfinish" => Vk::OemFinish,
+            "oemcopy" => Vk::OemCopy,
+            "oemauto" => Vk::OemAuto,
+            "oemenlw" => Vk::OemEnlw,
+            "oembacktab" => Vk::OemBackTab,
+            "attn" => Vk::Attn,
+            "crsel" => Vk::CrSel,
+            "exsel" => Vk::ExSel,
+            "eraseeof" => Vk::EraseEof,
+            "play" => Vk::Play,
+            "zoom" => Vk::Zoom,
+            "noname" => Vk::NoName,
+            "pa1" => Vk::Pa1,
+            "oemclear" => Vk::OemClear,
+            // Short names used by the `Display` implementation.
+            "break" => Vk::Cancel,
+            "esc" => Vk::Escape,
+            "ctrl" => Vk::Control,
+            "lctrl" => Vk::LeftControl,
+            "rctrl" => Vk::RightControl,
+            "lshift" => Vk::LeftShift,
+            "rshift" => Vk::RightShift,
+            "lalt" => Vk::LeftMenu,
+            "ralt" => Vk::RightMenu,
+            "lwin" => Vk::LeftWin,
+            "rwin" => Vk::RightWin,
+            "menu" => Vk::Apps,
+            "ins" => Vk::Insert,
+            "del" => Vk::Delete,
+            "prtsc" => Vk::PrintScreen,
+            "scrolllock" => Vk::Scroll,
+            "left" => Vk::LeftArrow,
+            "up" => Vk::UpArrow,
+            "right" => Vk::RightArrow,
+            "down" => Vk::DownArrow,
+            "num0" => Vk::Numpad0,
+            "num1" => Vk::Numpad1,
+            "num2" => Vk::Numpad2,
+            "num3" => Vk::Numpad3,
+            "num4" => Vk::Numpad4,
+            "num5" => Vk::Numpad5,
+            "num6" => Vk::Numpad6,
+            "num7" => Vk::Numpad7,
+            "num8" => Vk::Numpad8,
+            "num9" => Vk::Numpad9,
+            "num*" => Vk::Multiply,
+            "num+" => Vk::Add,
+            "num-" => Vk::Subtract,
+            "num." => Vk::Decimal,
+            "num/" => Vk::Divide,
+            _ => {
+                return Err(ParseVkError {
+                    name: s.to_string(),
+                })
+            }
+        };
+
+        Ok(vk)
+    }
+}
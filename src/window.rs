@@ -0,0 +1,327 @@
+//! The `window` module provides functions to query information about the windows
+//! the user is currently interacting with, and about the monitors they are displayed
+//! on.
+//!
+//! ## Examples
+//!
+//! ```rust, ignore
+//! use winput::window;
+//!
+//! if let Some(caret) = window::caret_position().unwrap() {
+//!     println!("The user is typing at ({}, {})", caret.left, caret.top);
+//! }
+//! ```
+
+use std::mem;
+use std::ptr;
+
+use winapi::shared::minwindef::{BOOL, LPARAM, TRUE};
+use winapi::shared::windef;
+use winapi::um::winuser;
+
+use crate::error::WindowsError;
+use crate::uia;
+
+/// A rectangle, in screen coordinates.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect {
+    /// The x coordinate of the upper-left corner of the rectangle.
+    pub left: i32,
+    /// The y coordinate of the upper-left corner of the rectangle.
+    pub top: i32,
+    /// The x coordinate of the lower-right corner of the rectangle.
+    pub right: i32,
+    /// The y coordinate of the lower-right corner of the rectangle.
+    pub bottom: i32,
+}
+
+impl Rect {
+    /// Computes the width of the rectangle.
+    #[inline(always)]
+    pub fn width(&self) -> i32 {
+        self.right - self.left
+    }
+
+    /// Computes the height of the rectangle.
+    #[inline(always)]
+    pub fn height(&self) -> i32 {
+        self.bottom - self.top
+    }
+}
+
+impl From<windef::RECT> for Rect {
+    #[inline(always)]
+    fn from(rect: windef::RECT) -> Self {
+        Self {
+            left: rect.left,
+            top: rect.top,
+            right: rect.right,
+            bottom: rect.bottom,
+        }
+    }
+}
+
+impl From<Rect> for windef::RECT {
+    #[inline(always)]
+    fn from(rect: Rect) -> Self {
+        Self {
+            left: rect.left,
+            top: rect.top,
+            right: rect.right,
+            bottom: rect.bottom,
+        }
+    }
+}
+
+/// Retrieves the caret rectangle of the control that currently has the keyboard focus
+/// in the foreground window, in screen coordinates.
+///
+/// Controls that draw their own caret instead of using the system caret (this is
+/// often the case of web browsers, WPF and UWP applications) are queried through UI
+/// Automation: the caret is located using the `TextPattern2` control pattern of the
+/// focused element or, when it is not supported, the selection of its `TextPattern`.
+/// The rectangle of such a caret has no width.
+///
+/// ## Returns
+///
+/// This function returns `None` if the foreground window does not currently display a
+/// caret, or if the control that has the keyboard focus does not expose one through
+/// UI Automation.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::window;
+///
+/// match window::caret_position().unwrap() {
+///     Some(rect) => println!("The caret is at {:?}", rect),
+///     None => println!("No caret is currently displayed"),
+/// }
+/// ```
+pub fn caret_position() -> Result<Option<Rect>, WindowsError> {
+    unsafe {
+        let mut info: winuser::GUITHREADINFO = mem::zeroed();
+        info.cbSize = mem::size_of::<winuser::GUITHREADINFO>() as _;
+
+        // Calling C code
+        //
+        // A thread id of `0` means that we want the information of the foreground
+        // thread.
+        if winuser::GetGUIThreadInfo(0, &mut info) == 0 {
+            return Err(WindowsError::from_last_error());
+        }
+
+        if info.hwndCaret.is_null() {
+            return automation_caret_position();
+        }
+
+        // The rectangle is expressed in the client coordinates of `hwndCaret`.
+        let mut corners = [
+            windef::POINT {
+                x: info.rcCaret.left,
+                y: info.rcCaret.top,
+            },
+            windef::POINT {
+                x: info.rcCaret.right,
+                y: info.rcCaret.bottom,
+            },
+        ];
+
+        for corner in corners.iter_mut() {
+            // Calling C code
+            if winuser::ClientToScreen(info.hwndCaret, corner) == 0 {
+                return Err(WindowsError::from_last_error());
+            }
+        }
+
+        Ok(Some(Rect {
+            left: corners[0].x,
+            top: corners[0].y,
+            right: corners[1].x,
+            bottom: corners[1].y,
+        }))
+    }
+}
+
+/// Locates the caret of the focused element using UI Automation.
+fn automation_caret_position() -> Result<Option<Rect>, WindowsError> {
+    let apartment = uia::Apartment::enter()?;
+    let automation = uia::automation(&apartment)?;
+
+    unsafe {
+        // Calling C code
+        let element = match uia::Com::from_out(|e| automation.GetFocusedElement(e)) {
+            Some(element) => element,
+            None => return Ok(None),
+        };
+
+        let range = if let Some(pattern) = uia::pattern::<uia::IUIAutomationTextPattern2>(
+            &element,
+            uia::UIA_TEXT_PATTERN_2_ID,
+        ) {
+            let mut is_active = 0;
+
+            // Calling C code
+            uia::Com::from_out(|r| pattern.GetCaretRange(&mut is_active, r))
+        } else if let Some(pattern) = uia::pattern::<uia::IUIAutomationTextPattern>(
+            &element,
+            uia::UIA_TEXT_PATTERN_ID,
+        ) {
+            // Calling C code
+            //
+            // The caret is assumed to be at the start of the first selected range.
+            uia::Com::from_out(|r| pattern.GetSelection(r))
+                .and_then(|ranges| uia::Com::from_out(|r| ranges.GetElement(0, r)))
+        } else {
+            None
+        };
+
+        let range = match range {
+            Some(range) => range,
+            None => return Ok(None),
+        };
+
+        let mut rectangles = uia::bounding_rectangles(&range);
+        if rectangles.is_empty() {
+            // An empty range usually has no bounding rectangle: the caret is located
+            // using the character that follows it.
+
+            // Calling C code
+            range.ExpandToEnclosingUnit(uia::TEXT_UNIT_CHARACTER);
+            rectangles = uia::bounding_rectangles(&range);
+        }
+
+        Ok(rectangles.first().map(|&[left, top, _, height]| {
+            let left = left.round() as i32;
+            Rect {
+                left,
+                top: top.round() as i32,
+                right: left,
+                bottom: (top + height).round() as i32,
+            }
+        }))
+    }
+}
+
+/// A display monitor.
+///
+/// ## Example
+///
+/// ```rust, ignore
+/// use winput::window::Monitor;
+///
+/// for monitor in Monitor::all().unwrap() {
+///     println!("{:?}", monitor.rect().unwrap());
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Monitor(windef::HMONITOR);
+
+// SAFETY: A `HMONITOR` is an identifier that is valid for the whole system. It does not
+// point to any memory owned by the process.
+unsafe impl Send for Monitor {}
+unsafe impl Sync for Monitor {}
+
+impl Monitor {
+    /// Retrieves every display monitor, in the order they are enumerated by the system.
+    pub fn all() -> Result<Vec<Self>, WindowsError> {
+        unsafe extern "system" fn callback(
+            monitor: windef::HMONITOR,
+            _: windef::HDC,
+            _: windef::LPRECT,
+            data: LPARAM,
+        ) -> BOOL {
+            // SAFETY: `data` is the pointer to the vector given to `EnumDisplayMonitors`.
+            let monitors = &mut *(data as *mut Vec<Monitor>);
+            monitors.push(Monitor(monitor));
+            TRUE
+        }
+
+        let mut monitors: Vec<Self> = Vec::new();
+
+        // Calling C code
+        let result = unsafe {
+            winuser::EnumDisplayMonitors(
+                ptr::null_mut(),
+                ptr::null(),
+                Some(callback),
+                &mut monitors as *mut Vec<Self> as LPARAM,
+            )
+        };
+
+        if result == 0 {
+            Err(WindowsError::from_last_error())
+        } else {
+            Ok(monitors)
+        }
+    }
+
+    /// Retrieves the primary monitor.
+    #[inline]
+    pub fn primary() -> Self {
+        Self::from_point_or(0, 0, winuser::MONITOR_DEFAULTTOPRIMARY)
+    }
+
+    /// Retrieves the monitor that contains the given point, in screen coordinates, or
+    /// the nearest monitor if the point is outside of every monitor.
+    #[inline]
+    pub fn from_point(x: i32, y: i32) -> Self {
+        Self::from_point_or(x, y, winuser::MONITOR_DEFAULTTONEAREST)
+    }
+
+    /// Calls the native `MonitorFromPoint` function with the given flags.
+    fn from_point_or(x: i32, y: i32, flags: u32) -> Self {
+        // Calling C code
+        Self(unsafe { winuser::MonitorFromPoint(windef::POINT { x, y }, flags) })
+    }
+
+    /// Creates a [`Monitor`] from a raw `HMONITOR` handle.
+    ///
+    /// [`Monitor`]: struct.Monitor.html
+    #[inline(always)]
+    pub fn from_raw(handle: windef::HMONITOR) -> Self {
+        Self(handle)
+    }
+
+    /// Returns the raw `HMONITOR` handle of this monitor.
+    #[inline(always)]
+    pub fn as_raw(self) -> windef::HMONITOR {
+        self.0
+    }
+
+    /// Calls the native `GetMonitorInfoW` function.
+    fn info(self) -> Result<winuser::MONITORINFO, WindowsError> {
+        unsafe {
+            let mut info: winuser::MONITORINFO = mem::zeroed();
+            info.cbSize = mem::size_of::<winuser::MONITORINFO>() as _;
+
+            // Calling C code
+            if winuser::GetMonitorInfoW(self.0, &mut info) == 0 {
+                Err(WindowsError::from_last_error())
+            } else {
+                Ok(info)
+            }
+        }
+    }
+
+    /// Retrieves the rectangle of this monitor, in screen coordinates.
+    #[inline]
+    pub fn rect(self) -> Result<Rect, WindowsError> {
+        self.info().map(|info| info.rcMonitor.into())
+    }
+
+    /// Retrieves the work area of this monitor (the rectangle of the monitor that is not
+    /// covered by the taskbar and other docked toolbars), in screen coordinates.
+    #[inline]
+    pub fn work_area(self) -> Result<Rect, WindowsError> {
+        self.info().map(|info| info.rcWork.into())
+    }
+
+    /// Checks whether this monitor is the primary monitor.
+    #[inline]
+    pub fn is_primary(self) -> Result<bool, WindowsError> {
+        self.info()
+            .map(|info| info.dwFlags & winuser::MONITORINFOF_PRIMARY != 0)
+    }
+}